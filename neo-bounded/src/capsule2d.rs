@@ -0,0 +1,21 @@
+use neo_primitive::d2::capsule::Capsule2D;
+
+use crate::traits::NeoBounded2D;
+
+impl NeoBounded2D for Capsule2D {
+    fn min_x(&self) -> f32 {
+        self.axis.src.x.min(self.axis.dst.x) - self.radius
+    }
+
+    fn min_y(&self) -> f32 {
+        self.axis.src.y.min(self.axis.dst.y) - self.radius
+    }
+
+    fn max_x(&self) -> f32 {
+        self.axis.src.x.max(self.axis.dst.x) + self.radius
+    }
+
+    fn max_y(&self) -> f32 {
+        self.axis.src.y.max(self.axis.dst.y) + self.radius
+    }
+}
@@ -0,0 +1,21 @@
+use neo_primitive::d2::circle::Circle2D;
+
+use crate::traits::NeoBounded2D;
+
+impl NeoBounded2D for Circle2D {
+    fn min_x(&self) -> f32 {
+        self.center.x - self.radius
+    }
+
+    fn min_y(&self) -> f32 {
+        self.center.y - self.radius
+    }
+
+    fn max_x(&self) -> f32 {
+        self.center.x + self.radius
+    }
+
+    fn max_y(&self) -> f32 {
+        self.center.y + self.radius
+    }
+}
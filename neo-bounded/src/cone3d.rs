@@ -0,0 +1,29 @@
+use neo_primitive::d3::cone::Cone3D;
+
+use crate::traits::NeoBounded3D;
+
+impl NeoBounded3D for Cone3D {
+    fn min_x(&self) -> f32 {
+        self.apex.x.min(self.base_center.x - self.base_extent().x)
+    }
+
+    fn min_y(&self) -> f32 {
+        self.apex.y.min(self.base_center.y - self.base_extent().y)
+    }
+
+    fn min_z(&self) -> f32 {
+        self.apex.z.min(self.base_center.z - self.base_extent().z)
+    }
+
+    fn max_x(&self) -> f32 {
+        self.apex.x.max(self.base_center.x + self.base_extent().x)
+    }
+
+    fn max_y(&self) -> f32 {
+        self.apex.y.max(self.base_center.y + self.base_extent().y)
+    }
+
+    fn max_z(&self) -> f32 {
+        self.apex.z.max(self.base_center.z + self.base_extent().z)
+    }
+}
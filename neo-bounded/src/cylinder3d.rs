@@ -0,0 +1,29 @@
+use neo_primitive::d3::cylinder::Cylinder3D;
+
+use crate::traits::NeoBounded3D;
+
+impl NeoBounded3D for Cylinder3D {
+    fn min_x(&self) -> f32 {
+        self.axis.src.x.min(self.axis.dst.x) - self.cap_extent().x
+    }
+
+    fn min_y(&self) -> f32 {
+        self.axis.src.y.min(self.axis.dst.y) - self.cap_extent().y
+    }
+
+    fn min_z(&self) -> f32 {
+        self.axis.src.z.min(self.axis.dst.z) - self.cap_extent().z
+    }
+
+    fn max_x(&self) -> f32 {
+        self.axis.src.x.max(self.axis.dst.x) + self.cap_extent().x
+    }
+
+    fn max_y(&self) -> f32 {
+        self.axis.src.y.max(self.axis.dst.y) + self.cap_extent().y
+    }
+
+    fn max_z(&self) -> f32 {
+        self.axis.src.z.max(self.axis.dst.z) + self.cap_extent().z
+    }
+}
@@ -1,9 +1,16 @@
 pub(crate) mod aabb2d;
 pub(crate) mod aabb3d;
+pub(crate) mod capsule2d;
+pub(crate) mod capsule3d;
+pub(crate) mod circle2d;
+pub(crate) mod cone3d;
+pub(crate) mod cylinder3d;
 pub(crate) mod float_ord;
 pub(crate) mod geo_polygon;
 pub(crate) mod line2d;
 pub(crate) mod line3d;
 pub(crate) mod polygon3d;
+pub(crate) mod sphere3d;
 pub(crate) mod surface;
 pub mod traits;
+pub(crate) mod triangle3d;
@@ -0,0 +1,29 @@
+use neo_primitive::d3::sphere::Sphere3D;
+
+use crate::traits::NeoBounded3D;
+
+impl NeoBounded3D for Sphere3D {
+    fn min_x(&self) -> f32 {
+        self.center.x - self.radius
+    }
+
+    fn min_y(&self) -> f32 {
+        self.center.y - self.radius
+    }
+
+    fn min_z(&self) -> f32 {
+        self.center.z - self.radius
+    }
+
+    fn max_x(&self) -> f32 {
+        self.center.x + self.radius
+    }
+
+    fn max_y(&self) -> f32 {
+        self.center.y + self.radius
+    }
+
+    fn max_z(&self) -> f32 {
+        self.center.z + self.radius
+    }
+}
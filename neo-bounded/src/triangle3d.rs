@@ -0,0 +1,29 @@
+use neo_surface::triangle3d::def::Triangle3D;
+
+use crate::traits::NeoBounded3D;
+
+impl NeoBounded3D for Triangle3D {
+    fn min_x(&self) -> f32 {
+        self.a.x.min(self.b.x).min(self.c.x)
+    }
+
+    fn min_y(&self) -> f32 {
+        self.a.y.min(self.b.y).min(self.c.y)
+    }
+
+    fn min_z(&self) -> f32 {
+        self.a.z.min(self.b.z).min(self.c.z)
+    }
+
+    fn max_x(&self) -> f32 {
+        self.a.x.max(self.b.x).max(self.c.x)
+    }
+
+    fn max_y(&self) -> f32 {
+        self.a.y.max(self.b.y).max(self.c.y)
+    }
+
+    fn max_z(&self) -> f32 {
+        self.a.z.max(self.b.z).max(self.c.z)
+    }
+}
@@ -1,5 +1,5 @@
-use glam::Vec3;
-use neo_plane::Plane;
+use glam::{Affine3A, Quat, Vec2, Vec3};
+use neo_plane::{transform_around, Plane};
 
 pub const COORDINATE_SYSTEM_EPS: f32 = 0.000_1;
 
@@ -51,15 +51,47 @@ impl CoordinateSystem {
     }
 }
 
+impl CoordinateSystem {
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        Self {
+            origin: transform.transform_point3(self.origin),
+            plane: self.plane.transform(transform),
+        }
+    }
+
+    pub fn rotate_around(&self, pivot: Vec3, quat: Quat) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_quat(quat)))
+    }
+
+    /// scales along the world axes, keeping `pivot` in place. Negative factors mirror
+    pub fn scale_around(&self, pivot: Vec3, factor: Vec3) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_scale(factor)))
+    }
+}
+
 impl CoordinateSystem {
     pub fn is_point_in_coordinate_system(&self, point: Vec3) -> bool {
         self.plane.is_point_in_plane(point - self.origin)
     }
 }
 
+impl CoordinateSystem {
+    /// maps a point of the local 2D space into 3D. The 2D origin is mapped onto the origin of the
+    /// coordinate system
+    pub fn inject_point(&self, point: Vec2) -> Vec3 {
+        self.plane.injection_rotation() * point.extend(0.0) + self.origin
+    }
+
+    /// maps a 3D point into the local 2D space. Points which are not located in the coordinate
+    /// system are projected onto it along the normal
+    pub fn project_point(&self, point: Vec3) -> Vec2 {
+        (self.plane.xy_projection_rotation() * (point - self.origin)).truncate()
+    }
+}
+
 #[cfg(test)]
 mod coord_sys {
-    use glam::Vec3;
+    use glam::{Affine3A, Quat, Vec2, Vec3};
 
     use crate::CoordinateSystem;
 
@@ -146,4 +178,64 @@ mod coord_sys {
 
         assert!(c.is_point_in_coordinate_system(origin - ax1 - ax2));
     }
+
+    #[test]
+    fn injected_point_is_in_coordinate_system() {
+        let c =
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE * 3.0, Vec3::new(1.0, -2.0, 0.5));
+        let injected = c.inject_point(Vec2::new(2.0, -7.0));
+
+        assert!(c.is_point_in_coordinate_system(injected));
+        assert!((injected.distance(c.origin) - Vec2::new(2.0, -7.0).length()).abs() < 0.001);
+    }
+
+    #[test]
+    fn injection_projection_roundtrip_works() {
+        let c =
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE * 3.0, Vec3::new(1.0, -2.0, 0.5));
+        let point = Vec2::new(2.0, -7.0);
+
+        assert!(c
+            .project_point(c.inject_point(point))
+            .abs_diff_eq(point, 0.001));
+    }
+
+    #[test]
+    fn projection_drops_normal_offset() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::Z);
+        let point = Vec3::new(3.0, 4.0, 10.0);
+
+        assert!((c.project_point(point).length() - 13.0_f32.sqrt()).abs() < 0.001);
+        assert!(c
+            .project_point(point)
+            .abs_diff_eq(c.project_point(point - Vec3::Z * 10.0), 0.001));
+    }
+
+    #[test]
+    fn transform_moves_injected_points_along() {
+        let c =
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE * 3.0, Vec3::new(1.0, -2.0, 0.5));
+        let t = Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 0.5, 1.0),
+            Quat::from_rotation_y(0.3),
+            Vec3::new(-4.0, 1.0, 2.0),
+        );
+        let transformed = c.transform(&t);
+        for point in [Vec2::ZERO, Vec2::new(2.0, -7.0), Vec2::new(-1.0, 0.5)] {
+            assert!(transformed
+                .is_point_in_coordinate_system(t.transform_point3(c.inject_point(point))));
+        }
+    }
+
+    #[test]
+    fn rotate_and_scale_around_pivot_work() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::X, Vec3::Z);
+        let rotated = c.rotate_around(Vec3::ZERO, Quat::from_rotation_z(90.0_f32.to_radians()));
+        assert!(rotated.origin.abs_diff_eq(Vec3::Y, 0.001));
+        assert!(rotated.plane.normal.abs_diff_eq(Vec3::Z, 0.001));
+
+        let mirrored = c.scale_around(Vec3::X, Vec3::new(2.0, 2.0, -1.0));
+        assert!(mirrored.origin.abs_diff_eq(Vec3::X, 0.001));
+        assert!(mirrored.plane.normal.abs_diff_eq(-Vec3::Z, 0.001));
+    }
 }
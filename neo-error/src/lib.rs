@@ -0,0 +1,3 @@
+pub mod neo_geometry_error;
+
+pub use crate::neo_geometry_error::NeoGeometryError;
@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// Reasons why a geometric operation can't produce a result for its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NeoGeometryError {
+    /// the input contains NaN or infinite coordinates
+    NonFinite,
+    /// the input collapses to something of lower dimension, e.g. a zero length direction or a
+    /// polygon without area
+    Degenerate(&'static str),
+    /// intermediate results contradict each other, usually because of floating point errors
+    NumericalInconsistency(&'static str),
+    /// the operation only works for geometries located in the same plane
+    NotCoplanar,
+}
+
+impl fmt::Display for NeoGeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NeoGeometryError::NonFinite => write!(f, "input contains non finite coordinates"),
+            NeoGeometryError::Degenerate(what) => write!(f, "degenerate input: {what}"),
+            NeoGeometryError::NumericalInconsistency(what) => {
+                write!(f, "numerical inconsistency: {what}")
+            }
+            NeoGeometryError::NotCoplanar => write!(f, "the geometries are not coplanar"),
+        }
+    }
+}
+
+impl std::error::Error for NeoGeometryError {}
@@ -0,0 +1,174 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use glam::Vec3;
+use neo_intersection::results::RayRay3DIntersection;
+use neo_intersection::trait_def::NeoIntersectable;
+use neo_ray::d3::def::Ray3D;
+
+/// the axis case analysis which was used before the closest approach solution, kept to compare
+/// the speed of both
+mod axis_case_analysis {
+    use glam::{Mat2, Vec2, Vec3};
+    use neo_intersection::results::{RayHit3D, RayRay3DIntersection};
+    use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+    use neo_ray::d3::def::Ray3D;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum ZeroDim {
+        X,
+        Y,
+        Z,
+    }
+
+    impl ZeroDim {
+        fn make_getter(&self) -> fn(Vec3) -> f32 {
+            match self {
+                ZeroDim::X => |vec| vec.x,
+                ZeroDim::Y => |vec| vec.y,
+                ZeroDim::Z => |vec| vec.z,
+            }
+        }
+
+        fn others(&self) -> [Self; 2] {
+            match self {
+                ZeroDim::X => [Self::Y, Self::Z],
+                ZeroDim::Y => [Self::X, Self::Z],
+                ZeroDim::Z => [Self::X, Self::Y],
+            }
+        }
+    }
+
+    pub fn intersection(r1: &Ray3D, r2: &Ray3D) -> RayRay3DIntersection {
+        if r1.is_parallel_to(r2) {
+            if r1.is_point_on_ray(r2.origin) {
+                RayRay3DIntersection::Collinear
+            } else {
+                RayRay3DIntersection::Parallel
+            }
+        } else {
+            match calculate_intersection_scalar(r1, r2) {
+                Some(s) => {
+                    let normal = -r2
+                        .direction
+                        .cross(r1.direction.cross(r2.direction))
+                        .normalize_or_zero();
+                    RayRay3DIntersection::Intersection(RayHit3D::new(r1, s, normal))
+                }
+                None => RayRay3DIntersection::Skewed,
+            }
+        }
+    }
+
+    fn calculate_intersection_scalar(r1: &Ray3D, r2: &Ray3D) -> Option<f32> {
+        let dir1 = r1.direction;
+        let dir2 = r2.direction;
+
+        let dirs: [[f32; 2]; 3] = [[dir1.x, dir2.x], [dir1.y, dir2.y], [dir1.z, dir2.z]];
+        let near_zero = |x: f32| x.abs() < NEO_LINE_RAY_3D_EPS;
+
+        match dirs {
+            [[a, b], _, _] if near_zero(a) && near_zero(b) => {
+                solve_intersection_equations_dim_zero(r1, r2, ZeroDim::X)
+            }
+            [_, [a, b], _] if near_zero(a) && near_zero(b) => {
+                solve_intersection_equations_dim_zero(r1, r2, ZeroDim::Y)
+            }
+            [_, _, [a, b]] if near_zero(a) && near_zero(b) => {
+                solve_intersection_equations_dim_zero(r1, r2, ZeroDim::Z)
+            }
+            _ => solve_overdetermined_intersection_system(r1, r2),
+        }
+    }
+
+    fn solve_overdetermined_intersection_system(r1: &Ray3D, r2: &Ray3D) -> Option<f32> {
+        let dir1 = r1.direction;
+        let dir2 = r2.direction;
+        let origin_diff = r2.origin - r1.origin;
+
+        let a = dir1.dot(dir1);
+        let b = dir1.dot(dir2);
+        let c = dir2.dot(dir2);
+        let d = dir1.dot(origin_diff);
+        let e = dir2.dot(origin_diff);
+
+        let dd = a * c - b * b;
+        (dd.abs() > NEO_LINE_RAY_3D_EPS).then(|| -(b * e - c * d) / dd)
+    }
+
+    fn solve_intersection_equations_dim_zero(r1: &Ray3D, r2: &Ray3D, d: ZeroDim) -> Option<f32> {
+        let p1 = r1.origin;
+        let p2 = r2.origin;
+        let dir1 = r1.direction;
+        let dir2 = r2.direction;
+
+        let (get0, [get1, get2]) = (d.make_getter(), d.others().map(|d| d.make_getter()));
+
+        let is_valid = (get0(p1) - get0(p2)).abs() < NEO_LINE_RAY_3D_EPS;
+
+        is_valid
+            .then(|| {
+                Mat2::from_cols(
+                    Vec2::new(get1(dir1), get2(dir1)),
+                    -Vec2::new(get1(dir2), get2(dir2)),
+                )
+            })
+            .filter(|a| a.determinant().abs() >= NEO_LINE_RAY_3D_EPS)
+            .map(|a| a.inverse())
+            .map(|inv_a| {
+                let b = Vec2::new(get1(p2) - get1(p1), get2(p2) - get2(p1));
+                inv_a.mul_vec2(b)
+            })
+            .map(|x| x.x)
+    }
+}
+
+/// intersecting, skewed, axis aligned and parallel pairs of rays
+fn ray_pairs() -> Vec<(Ray3D, Ray3D)> {
+    let mut pairs = vec![];
+    for i in 0..64 {
+        let f = i as f32;
+        let origin = Vec3::new(f.sin() * 10.0, f.cos() * 5.0, f * 0.25);
+        let d1 = Vec3::new(1.0, f.sin(), f.cos() * 0.5);
+        let d2 = Vec3::new(f.cos(), 1.0, -f.sin());
+        pairs.push((
+            Ray3D::new(origin - d1 * 3.0, d1),
+            Ray3D::new(origin + d2 * 2.0, d2),
+        ));
+        pairs.push((
+            Ray3D::new(origin, d1),
+            Ray3D::new(origin + d1.cross(d2), d2),
+        ));
+        pairs.push((
+            Ray3D::new(origin, Vec3::new(1.0, f, 0.0)),
+            Ray3D::new(origin + Vec3::Y, Vec3::new(f, -1.0, 0.0)),
+        ));
+        pairs.push((Ray3D::new(origin, d1), Ray3D::new(origin + Vec3::Z, d1)));
+    }
+    pairs
+}
+
+fn bench_ray_ray_3d(c: &mut Criterion) {
+    let pairs = ray_pairs();
+    let mut group = c.benchmark_group("ray_ray_3d");
+    group.bench_function("closest_approach", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|(r1, r2)| black_box(r1).intersection(black_box(r2)))
+                .filter(|i| matches!(i, RayRay3DIntersection::Intersection(_)))
+                .count()
+        })
+    });
+    group.bench_function("axis_case_analysis", |b| {
+        b.iter(|| {
+            pairs
+                .iter()
+                .map(|(r1, r2)| axis_case_analysis::intersection(black_box(r1), black_box(r2)))
+                .filter(|i| matches!(i, RayRay3DIntersection::Intersection(_)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ray_ray_3d);
+criterion_main!(benches);
@@ -0,0 +1,544 @@
+use neo_aabb::d2::def::AABB2D;
+use neo_aabb::d3::def::AABB3D;
+use neo_bounded::traits::{NeoBounded2D, NeoBounded3D};
+use neo_error::NeoGeometryError;
+
+use crate::float_ord_cmp;
+use crate::trait_def::{NeoIntersectable, NeoIntersects, NeoTryIntersectable, NeoTryIntersects};
+
+/// Non-empty narrow phase result of the pair `(lhs[lhs_index], rhs[rhs_index])`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchIntersection<T> {
+    pub lhs_index: usize,
+    pub rhs_index: usize,
+    pub intersection: T,
+}
+
+/// Index pairs `(lhs_index, rhs_index)` of all shapes whose bounding boxes overlap, sorted
+/// lexicographically. Touching boxes count as overlapping
+pub fn broad_phase_2d<A: NeoBounded2D, B: NeoBounded2D>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<(usize, usize)> {
+    let lhs = lhs.iter().map(NeoBounded2D::aabb).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(NeoBounded2D::aabb).collect::<Vec<_>>();
+    sort_and_sweep(
+        &lhs,
+        &rhs,
+        |aabb| (aabb.min.x, aabb.max.x),
+        AABB2D::intersects,
+    )
+}
+
+/// Index pairs `(lhs_index, rhs_index)` of all shapes whose bounding boxes overlap, sorted
+/// lexicographically. Touching boxes count as overlapping
+pub fn broad_phase_3d<A: NeoBounded3D, B: NeoBounded3D>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<(usize, usize)> {
+    let lhs = lhs.iter().map(NeoBounded3D::aabb).collect::<Vec<_>>();
+    let rhs = rhs.iter().map(NeoBounded3D::aabb).collect::<Vec<_>>();
+    sort_and_sweep(
+        &lhs,
+        &rhs,
+        |aabb| (aabb.min.x, aabb.max.x),
+        AABB3D::intersects,
+    )
+}
+
+/// All index pairs of intersecting shapes. This is the cheap variant of
+/// [`batch_intersection_2d`] if the intersection results themselves aren't needed
+pub fn batch_intersects_2d<A, B>(lhs: &[A], rhs: &[B]) -> Vec<(usize, usize)>
+where
+    A: NeoBounded2D + NeoIntersects<B>,
+    B: NeoBounded2D,
+{
+    narrow_phase(broad_phase_2d(lhs, rhs), |i, j| {
+        lhs[i].intersects(&rhs[j]).then_some((i, j))
+    })
+}
+
+/// All index pairs of intersecting shapes. This is the cheap variant of
+/// [`batch_intersection_3d`] if the intersection results themselves aren't needed
+pub fn batch_intersects_3d<A, B>(lhs: &[A], rhs: &[B]) -> Vec<(usize, usize)>
+where
+    A: NeoBounded3D + NeoIntersects<B>,
+    B: NeoBounded3D,
+{
+    narrow_phase(broad_phase_3d(lhs, rhs), |i, j| {
+        lhs[i].intersects(&rhs[j]).then_some((i, j))
+    })
+}
+
+/// Intersects every shape of `lhs` with every shape of `rhs` and returns the non-empty results.
+/// Pairs are pruned by their bounding boxes first and rejected by [`NeoIntersects`] before the
+/// full intersection is computed. The results are sorted by their indices
+pub fn batch_intersection_2d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<<A as NeoIntersectable<B>>::Output>>
+where
+    A: NeoBounded2D + NeoIntersectable<B> + NeoIntersects<B>,
+    B: NeoBounded2D,
+{
+    narrow_phase(broad_phase_2d(lhs, rhs), |i, j| {
+        intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+/// Intersects every shape of `lhs` with every shape of `rhs` and returns the non-empty results.
+/// Pairs are pruned by their bounding boxes first and rejected by [`NeoIntersects`] before the
+/// full intersection is computed. The results are sorted by their indices
+pub fn batch_intersection_3d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<<A as NeoIntersectable<B>>::Output>>
+where
+    A: NeoBounded3D + NeoIntersectable<B> + NeoIntersects<B>,
+    B: NeoBounded3D,
+{
+    narrow_phase(broad_phase_3d(lhs, rhs), |i, j| {
+        intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+/// fallible variant of [`batch_intersection_2d`]. A pair which can't be intersected is reported
+/// with its error instead of being reported as a miss
+pub fn try_batch_intersection_2d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<Result<<A as NeoTryIntersectable<B>>::Output, NeoGeometryError>>>
+where
+    A: NeoBounded2D + NeoTryIntersectable<B> + NeoTryIntersects<B>,
+    B: NeoBounded2D,
+{
+    narrow_phase(broad_phase_2d(lhs, rhs), |i, j| {
+        try_intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+/// fallible variant of [`batch_intersection_3d`]. A pair which can't be intersected is reported
+/// with its error instead of being reported as a miss, so broken shapes don't go unnoticed
+pub fn try_batch_intersection_3d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<Result<<A as NeoTryIntersectable<B>>::Output, NeoGeometryError>>>
+where
+    A: NeoBounded3D + NeoTryIntersectable<B> + NeoTryIntersects<B>,
+    B: NeoBounded3D,
+{
+    narrow_phase(broad_phase_3d(lhs, rhs), |i, j| {
+        try_intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+/// [`batch_intersects_2d`] with the narrow phase running on the rayon thread pool
+#[cfg(feature = "parallel")]
+pub fn par_batch_intersects_2d<A, B>(lhs: &[A], rhs: &[B]) -> Vec<(usize, usize)>
+where
+    A: NeoBounded2D + NeoIntersects<B> + Sync,
+    B: NeoBounded2D + Sync,
+{
+    par_narrow_phase(broad_phase_2d(lhs, rhs), |i, j| {
+        lhs[i].intersects(&rhs[j]).then_some((i, j))
+    })
+}
+
+/// [`batch_intersects_3d`] with the narrow phase running on the rayon thread pool
+#[cfg(feature = "parallel")]
+pub fn par_batch_intersects_3d<A, B>(lhs: &[A], rhs: &[B]) -> Vec<(usize, usize)>
+where
+    A: NeoBounded3D + NeoIntersects<B> + Sync,
+    B: NeoBounded3D + Sync,
+{
+    par_narrow_phase(broad_phase_3d(lhs, rhs), |i, j| {
+        lhs[i].intersects(&rhs[j]).then_some((i, j))
+    })
+}
+
+/// [`batch_intersection_2d`] with the narrow phase running on the rayon thread pool
+#[cfg(feature = "parallel")]
+pub fn par_batch_intersection_2d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<<A as NeoIntersectable<B>>::Output>>
+where
+    A: NeoBounded2D + NeoIntersectable<B> + NeoIntersects<B> + Sync,
+    B: NeoBounded2D + Sync,
+    <A as NeoIntersectable<B>>::Output: Send,
+{
+    par_narrow_phase(broad_phase_2d(lhs, rhs), |i, j| {
+        intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+/// [`batch_intersection_3d`] with the narrow phase running on the rayon thread pool
+#[cfg(feature = "parallel")]
+pub fn par_batch_intersection_3d<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+) -> Vec<BatchIntersection<<A as NeoIntersectable<B>>::Output>>
+where
+    A: NeoBounded3D + NeoIntersectable<B> + NeoIntersects<B> + Sync,
+    B: NeoBounded3D + Sync,
+    <A as NeoIntersectable<B>>::Output: Send,
+{
+    par_narrow_phase(broad_phase_3d(lhs, rhs), |i, j| {
+        intersect_pair(lhs, rhs, i, j)
+    })
+}
+
+fn intersect_pair<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+    lhs_index: usize,
+    rhs_index: usize,
+) -> Option<BatchIntersection<<A as NeoIntersectable<B>>::Output>>
+where
+    A: NeoIntersectable<B> + NeoIntersects<B>,
+{
+    let (a, b) = (&lhs[lhs_index], &rhs[rhs_index]);
+    a.intersects(b).then(|| BatchIntersection {
+        lhs_index,
+        rhs_index,
+        intersection: a.intersection(b),
+    })
+}
+
+fn try_intersect_pair<A, B>(
+    lhs: &[A],
+    rhs: &[B],
+    lhs_index: usize,
+    rhs_index: usize,
+) -> Option<BatchIntersection<Result<<A as NeoTryIntersectable<B>>::Output, NeoGeometryError>>>
+where
+    A: NeoTryIntersectable<B> + NeoTryIntersects<B>,
+{
+    let (a, b) = (&lhs[lhs_index], &rhs[rhs_index]);
+    let intersection = match a.try_intersects(b) {
+        Ok(false) => return None,
+        Ok(true) => a.try_intersection(b),
+        Err(err) => Err(err),
+    };
+    Some(BatchIntersection {
+        lhs_index,
+        rhs_index,
+        intersection,
+    })
+}
+
+/// sweeps over the x axis of both box lists and tests the boxes with overlapping x intervals
+/// with `overlaps`
+fn sort_and_sweep<T>(
+    lhs: &[T],
+    rhs: &[T],
+    x_interval: impl Fn(&T) -> (f32, f32),
+    overlaps: impl Fn(&T, &T) -> bool,
+) -> Vec<(usize, usize)> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Side {
+        Lhs,
+        Rhs,
+    }
+
+    let mut events = lhs
+        .iter()
+        .enumerate()
+        .map(|(i, aabb)| (x_interval(aabb), Side::Lhs, i))
+        .chain(
+            rhs.iter()
+                .enumerate()
+                .map(|(i, aabb)| (x_interval(aabb), Side::Rhs, i)),
+        )
+        .collect::<Vec<_>>();
+    events.sort_by(|((a, _), _, _), ((b, _), _, _)| float_ord_cmp(a, b));
+
+    let mut active_lhs: Vec<(f32, usize)> = vec![];
+    let mut active_rhs: Vec<(f32, usize)> = vec![];
+    let mut pairs = vec![];
+    for ((min_x, max_x), side, index) in events {
+        active_lhs.retain(|(active_max_x, _)| *active_max_x >= min_x);
+        active_rhs.retain(|(active_max_x, _)| *active_max_x >= min_x);
+        match side {
+            Side::Lhs => {
+                pairs.extend(
+                    active_rhs
+                        .iter()
+                        .filter(|(_, j)| overlaps(&lhs[index], &rhs[*j]))
+                        .map(|(_, j)| (index, *j)),
+                );
+                active_lhs.push((max_x, index));
+            }
+            Side::Rhs => {
+                pairs.extend(
+                    active_lhs
+                        .iter()
+                        .filter(|(_, i)| overlaps(&lhs[*i], &rhs[index]))
+                        .map(|(_, i)| (*i, index)),
+                );
+                active_rhs.push((max_x, index));
+            }
+        }
+    }
+    pairs.sort_unstable();
+    pairs
+}
+
+fn narrow_phase<T>(pairs: Vec<(usize, usize)>, test: impl Fn(usize, usize) -> Option<T>) -> Vec<T> {
+    pairs.into_iter().filter_map(|(i, j)| test(i, j)).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn par_narrow_phase<T: Send>(
+    pairs: Vec<(usize, usize)>,
+    test: impl Fn(usize, usize) -> Option<T> + Sync,
+) -> Vec<T> {
+    use rayon::prelude::*;
+    // rayons `collect` keeps the order of the pairs
+    pairs
+        .into_par_iter()
+        .filter_map(|(i, j)| test(i, j))
+        .collect()
+}
+
+#[cfg(test)]
+mod many_vs_many {
+    use glam::{Vec2, Vec3};
+    use neo_bounded::traits::NeoBounded2D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::batch::{
+        batch_intersection_2d, batch_intersection_3d, batch_intersects_2d, broad_phase_2d,
+        try_batch_intersection_2d, try_batch_intersection_3d,
+    };
+    use crate::results::SurfaceSurface3DIntersection;
+    use crate::trait_def::{NeoIntersectable, NeoIntersects};
+
+    fn square(min: Vec2, size: f32) -> geo::Polygon<f32> {
+        geo::Rect::new(min.to_geo(), (min + Vec2::splat(size)).to_geo()).to_polygon()
+    }
+
+    /// square with side length 2 centered at `origin`
+    fn square_surface(origin: Vec3, local_x: Vec3, local_y: Vec3) -> NeoSurface {
+        let c = CoordinateSystem::from_origin_and_plane(
+            origin,
+            Plane::from_local_axis(local_x, local_y),
+        );
+        NeoSurface::new(c, geo::Coord::zero(), square(Vec2::NEG_ONE, 2.0))
+    }
+
+    /// pseudo random but reproducible scattering of the segments
+    fn segments(n: usize) -> Vec<LineSegment2D> {
+        (0..n)
+            .map(|i| {
+                let f = i as f32;
+                let src = Vec2::new((f * 7.3) % 20.0, (f * 3.1) % 20.0);
+                LineSegment2D::new(src, src + Vec2::new((f % 3.0) - 1.0, (f % 5.0) - 2.0))
+            })
+            .collect()
+    }
+
+    fn polygons() -> Vec<geo::Polygon<f32>> {
+        (0..5)
+            .flat_map(|x| (0..5).map(move |y| square(Vec2::new(x as f32, y as f32) * 4.0, 2.0)))
+            .collect()
+    }
+
+    #[test]
+    fn broad_phase_matches_double_loop() {
+        let lhs = segments(60);
+        let rhs = polygons();
+        let expected = lhs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, l)| {
+                rhs.iter()
+                    .enumerate()
+                    .filter_map(move |(j, p)| l.aabb().intersects(&p.aabb()).then_some((i, j)))
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(broad_phase_2d(&lhs, &rhs), expected);
+    }
+
+    #[test]
+    fn broad_phase_reports_touching_boxes() {
+        let lhs = [square(Vec2::ZERO, 1.0)];
+        let rhs = [square(Vec2::X, 1.0), square(Vec2::X * 1.5, 1.0)];
+        assert_eq!(broad_phase_2d(&lhs, &rhs), vec![(0, 0)]);
+    }
+
+    #[test]
+    fn batch_matches_double_loop_2d() {
+        let lhs = segments(60);
+        let rhs = polygons();
+        let expected = lhs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, l)| {
+                rhs.iter()
+                    .enumerate()
+                    .filter(move |(_, p)| l.intersects(*p))
+                    .map(move |(j, p)| (i, j, l.intersection(p)))
+            })
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+
+        let batch = batch_intersection_2d(&lhs, &rhs);
+        let batch = batch
+            .into_iter()
+            .map(|b| (b.lhs_index, b.rhs_index, b.intersection))
+            .collect::<Vec<_>>();
+        assert_eq!(batch, expected);
+
+        let pairs = expected
+            .iter()
+            .map(|(i, j, _)| (*i, *j))
+            .collect::<Vec<_>>();
+        assert_eq!(batch_intersects_2d(&lhs, &rhs), pairs);
+    }
+
+    #[test]
+    fn batch_surface_clashes() {
+        let walls = [
+            square_surface(Vec3::ZERO, Vec3::X, Vec3::Y),
+            square_surface(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Z),
+            square_surface(Vec3::new(10.0, 0.0, 0.0), Vec3::X, Vec3::Y),
+        ];
+        let slabs = [
+            square_surface(Vec3::new(1.0, 1.0, 0.0), Vec3::X, Vec3::Y),
+            square_surface(Vec3::splat(10.0), Vec3::Y, Vec3::Z),
+        ];
+        let batch = batch_intersection_3d(&walls, &slabs);
+        let pairs = batch
+            .iter()
+            .map(|b| (b.lhs_index, b.rhs_index))
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, vec![(0, 0), (1, 0)]);
+        assert!(matches!(
+            batch[0].intersection,
+            SurfaceSurface3DIntersection::Surface(_)
+        ));
+        assert!(matches!(
+            batch[1].intersection,
+            SurfaceSurface3DIntersection::Parts(_)
+        ));
+    }
+
+    #[test]
+    fn try_batch_matches_batch_2d() {
+        let lhs = polygons();
+        let rhs = [square(Vec2::ONE, 6.0), square(Vec2::new(9.0, 15.0), 1.0)];
+        let batch = batch_intersection_2d(&lhs, &rhs);
+        let try_batch = try_batch_intersection_2d(&lhs, &rhs);
+        assert!(!batch.is_empty());
+        assert_eq!(try_batch.len(), batch.len());
+        for (t, b) in try_batch.into_iter().zip(batch) {
+            assert_eq!((t.lhs_index, t.rhs_index), (b.lhs_index, b.rhs_index));
+            assert_eq!(t.intersection, Ok(b.intersection));
+        }
+    }
+
+    #[test]
+    fn try_batch_reports_broken_pairs() {
+        let mut broken = square_surface(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Z);
+        broken.coordinate_system.plane.normal = Vec3::ZERO;
+        let walls = [square_surface(Vec3::ZERO, Vec3::X, Vec3::Y), broken];
+        let slabs = [square_surface(Vec3::new(1.0, 1.0, 0.0), Vec3::X, Vec3::Y)];
+        let batch = try_batch_intersection_3d(&walls, &slabs);
+        assert_eq!(batch.len(), 2);
+        assert!(matches!(
+            batch[0].intersection,
+            Ok(SurfaceSurface3DIntersection::Surface(_))
+        ));
+        assert_eq!((batch[1].lhs_index, batch[1].rhs_index), (1, 0));
+        assert!(matches!(
+            batch[1].intersection,
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+    }
+
+    /// geometry which can't be shared between threads still works with the sequential batch
+    #[test]
+    fn batch_works_without_sync() {
+        use std::rc::Rc;
+
+        #[derive(Debug)]
+        struct Shared(Rc<LineSegment2D>);
+
+        impl NeoBounded2D for Shared {
+            fn min_x(&self) -> f32 {
+                self.0.min_x()
+            }
+            fn min_y(&self) -> f32 {
+                self.0.min_y()
+            }
+            fn max_x(&self) -> f32 {
+                self.0.max_x()
+            }
+            fn max_y(&self) -> f32 {
+                self.0.max_y()
+            }
+        }
+
+        impl NeoIntersects<geo::Polygon<f32>> for Shared {
+            fn intersects(&self, rhs: &geo::Polygon<f32>) -> bool {
+                self.0.intersects(rhs)
+            }
+        }
+
+        let lhs = segments(60)
+            .into_iter()
+            .map(|l| Shared(Rc::new(l)))
+            .collect::<Vec<_>>();
+        let rhs = polygons();
+        assert_eq!(
+            batch_intersects_2d(&lhs, &rhs),
+            batch_intersects_2d(&segments(60), &rhs)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_batch_matches_sequential_batch() {
+        use crate::batch::{
+            batch_intersects_3d, par_batch_intersection_2d, par_batch_intersection_3d,
+            par_batch_intersects_2d, par_batch_intersects_3d,
+        };
+
+        let lhs = segments(60);
+        let rhs = polygons();
+        assert_eq!(
+            par_batch_intersection_2d(&lhs, &rhs),
+            batch_intersection_2d(&lhs, &rhs)
+        );
+        assert_eq!(
+            par_batch_intersects_2d(&lhs, &rhs),
+            batch_intersects_2d(&lhs, &rhs)
+        );
+
+        let walls = [
+            square_surface(Vec3::ZERO, Vec3::X, Vec3::Y),
+            square_surface(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Z),
+        ];
+        assert_eq!(
+            par_batch_intersection_3d(&walls, &walls),
+            batch_intersection_3d(&walls, &walls)
+        );
+        assert_eq!(
+            par_batch_intersects_3d(&walls, &walls),
+            batch_intersects_3d(&walls, &walls)
+        );
+    }
+
+    #[test]
+    fn batch_with_empty_input() {
+        let lhs: [LineSegment2D; 0] = [];
+        assert!(batch_intersection_2d(&lhs, &polygons()).is_empty());
+        assert!(batch_intersection_2d(&segments(3), &Vec::<geo::Polygon<f32>>::new()).is_empty());
+    }
+}
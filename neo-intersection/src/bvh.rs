@@ -0,0 +1,224 @@
+use glam::Vec3;
+use neo_aabb::d3::def::AABB3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::triangle3d::def::Triangle3D;
+
+use crate::distance::aabb3d::first_point_inside;
+use crate::float_ord_cmp;
+use crate::ray3d::triangle::{sorted_unique_hits, RayTriangle3DHit};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+/// maximum number of triangles in a leaf of the hierarchy
+const LEAF_SIZE: usize = 4;
+
+/// Bounding volume hierarchy over triangles, e.g. the ones from [`NeoSurface::tessellate`]. It's
+/// built once, after that every ray only tests the triangles whose boxes it crosses
+///
+/// [`NeoSurface::tessellate`]: neo_surface::surface::def::NeoSurface::tessellate
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangleBvh3D {
+    /// sorted so that every node covers a contiguous range
+    triangles: Vec<Triangle3D>,
+    /// the root is the first node
+    nodes: Vec<BvhNode>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BvhNode {
+    aabb: AABB3D,
+    kind: BvhNodeKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum BvhNodeKind {
+    /// covers `triangles[start..end]`
+    Leaf { start: usize, end: usize },
+    /// indices of the child nodes
+    Inner { left: usize, right: usize },
+}
+
+impl TriangleBvh3D {
+    pub fn new(mut triangles: Vec<Triangle3D>) -> Self {
+        let mut nodes = vec![];
+        if !triangles.is_empty() {
+            build_node(&mut triangles, 0, &mut nodes);
+        }
+        Self { triangles, nodes }
+    }
+
+    pub fn triangles(&self) -> &[Triangle3D] {
+        &self.triangles
+    }
+
+    /// triangles in the leaves whose boxes are crossed by the ray. Rays are treated as unbounded
+    /// lines, like in the triangle intersection
+    fn candidates<'a>(&'a self, ray: &'a Ray3D) -> impl Iterator<Item = &'a Triangle3D> + 'a {
+        let mut stack = if self.nodes.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        let unbounded = (f32::NEG_INFINITY, f32::INFINITY);
+        std::iter::from_fn(move || {
+            while let Some(index) = stack.pop() {
+                let node = &self.nodes[index];
+                if first_point_inside(&node.aabb, ray.origin, ray.direction, unbounded).is_none() {
+                    continue;
+                }
+                match node.kind {
+                    BvhNodeKind::Leaf { start, end } => return Some(&self.triangles[start..end]),
+                    BvhNodeKind::Inner { left, right } => stack.extend([right, left]),
+                }
+            }
+            None
+        })
+        .flatten()
+    }
+}
+
+impl From<Vec<Triangle3D>> for TriangleBvh3D {
+    fn from(triangles: Vec<Triangle3D>) -> Self {
+        Self::new(triangles)
+    }
+}
+
+/// Same result as the intersection with the slice of all triangles
+impl NeoIntersectable<TriangleBvh3D> for Ray3D {
+    type Output = Vec<RayTriangle3DHit>;
+
+    fn intersection(&self, rhs: &TriangleBvh3D) -> Self::Output {
+        sorted_unique_hits(self, rhs.candidates(self))
+    }
+}
+
+impl NeoIntersects<TriangleBvh3D> for Ray3D {
+    fn intersects(&self, rhs: &TriangleBvh3D) -> bool {
+        rhs.candidates(self).any(|tri| self.intersects(tri))
+    }
+}
+
+mirror_intersectable!(Ray3D => TriangleBvh3D);
+
+/// pushes the node of the triangles, which start at `offset` in the whole list, and its children.
+/// The triangles are split at the median of their centroids along the longest axis of the box
+fn build_node(triangles: &mut [Triangle3D], offset: usize, nodes: &mut Vec<BvhNode>) -> usize {
+    let aabb = padded_aabb(triangles);
+    let index = nodes.len();
+    nodes.push(BvhNode {
+        aabb,
+        kind: BvhNodeKind::Leaf {
+            start: offset,
+            end: offset + triangles.len(),
+        },
+    });
+    if triangles.len() <= LEAF_SIZE {
+        return index;
+    }
+
+    let extent = aabb.max - aabb.min;
+    let axis = if extent.x >= extent.y && extent.x >= extent.z {
+        0
+    } else if extent.y >= extent.z {
+        1
+    } else {
+        2
+    };
+    let mid = triangles.len() / 2;
+    triangles.select_nth_unstable_by(mid, |a, b| {
+        float_ord_cmp(&a.centroid()[axis], &b.centroid()[axis])
+    });
+    let (lhs, rhs) = triangles.split_at_mut(mid);
+    let left = build_node(lhs, offset, nodes);
+    let right = build_node(rhs, offset + mid, nodes);
+    nodes[index].kind = BvhNodeKind::Inner { left, right };
+    index
+}
+
+/// box around the triangles, grown by the tolerance of the triangle intersection so hits on the
+/// edges aren't pruned
+fn padded_aabb(triangles: &[Triangle3D]) -> AABB3D {
+    let corners = triangles.iter().flat_map(Triangle3D::array);
+    let (min, max) = corners.fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), p| (min.min(p), max.max(p)),
+    );
+    let padding = NEO_LINE_RAY_3D_EPS * (1.0 + (max - min).max_element());
+    AABB3D::new(min - Vec3::splat(padding), max + Vec3::splat(padding))
+}
+
+#[cfg(test)]
+mod triangle_bvh {
+    use glam::{Vec2, Vec3};
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d3::def::Ray3D;
+    use neo_surface::surface::def::NeoSurface;
+    use neo_surface::triangle3d::def::Triangle3D;
+
+    use crate::bvh::TriangleBvh3D;
+    use crate::trait_def::{NeoIntersectable, NeoIntersects};
+
+    /// a row of parallel walls, every wall has a door shaped hole
+    fn walls() -> Vec<Triangle3D> {
+        (0..20)
+            .flat_map(|i| {
+                let line = LineSegment2D::new(Vec2::ZERO, Vec2::X * 4.0);
+                let wall = NeoSurface::from_line_and_heights_vertical(line, 0.0, 3.0);
+                let door = geo::Rect::new((1.0, 0.0), (2.0, 2.0)).to_polygon();
+                let shape = wall.shape.clone();
+                let wall = NeoSurface {
+                    shape: geo::Polygon::new(
+                        shape.exterior().clone(),
+                        vec![door.exterior().clone()],
+                    ),
+                    ..wall
+                };
+                wall.translate_by(Vec3::Y * i as f32).tessellate()
+            })
+            .collect()
+    }
+
+    fn rays() -> Vec<Ray3D> {
+        (0..50)
+            .map(|i| {
+                let f = i as f32;
+                let origin = Vec3::new((f * 0.37) % 5.0, -3.0, (f * 0.61) % 3.5);
+                Ray3D::new(
+                    origin,
+                    Vec3::new((f % 3.0) - 1.0, 1.0 + f % 2.0, (f % 5.0) * 0.1),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bvh_matches_linear_scan() {
+        let triangles = walls();
+        let bvh = TriangleBvh3D::new(triangles.clone());
+        for ray in rays() {
+            let expected = ray.intersection(triangles.as_slice());
+            let hits = ray.intersection(&bvh);
+            assert_eq!(hits.len(), expected.len(), "{ray:?}");
+            for (hit, expected) in hits.iter().zip(&expected) {
+                assert!(hit.hit.point.abs_diff_eq(expected.hit.point, 0.000_1));
+            }
+            assert_eq!(ray.intersects(&bvh), !expected.is_empty());
+        }
+    }
+
+    #[test]
+    fn bvh_prunes_triangles() {
+        let bvh = TriangleBvh3D::new(walls());
+        let ray = Ray3D::new(Vec3::new(3.5, 5.0, 1.0), Vec3::new(0.0, 0.01, 1.0));
+        let candidates = bvh.candidates(&ray).count();
+        assert!(candidates > 0 && candidates < bvh.triangles().len() / 4);
+        assert_eq!(ray.intersection(&bvh).len(), 1);
+    }
+
+    #[test]
+    fn empty_bvh_works() {
+        let bvh = TriangleBvh3D::new(vec![]);
+        assert!(Ray3D::X.intersection(&bvh).is_empty());
+        assert!(!Ray3D::X.intersects(&bvh));
+    }
+}
@@ -1,25 +1,98 @@
+use glam::Vec3;
 use neo_coordinate_system::{CoordinateSystem, COORDINATE_SYSTEM_EPS};
+use neo_error::NeoGeometryError;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
 
+use crate::intersection_set::IntersectionDimension;
 use crate::results::{PlanePlaneIntersection, RayCoordSys3DIntersection};
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    NeoIntersectable, NeoIntersectionResult, NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+};
+use crate::validation::ensure_valid_coordinate_system;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CoordSysCoordSysIntersection {
     Same,
-    Parallel { distance: f32 },
+    /// the distance is `NAN` if the coordinate systems couldn't be intersected, see
+    /// [`NeoTryIntersectable::try_intersection`]
+    Parallel {
+        distance: f32,
+    },
     Ray(Ray3D),
 }
 
+impl NeoIntersectionResult for CoordSysCoordSysIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    /// the intersections are unbounded, so only the dimension is reported
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            CoordSysCoordSysIntersection::Same => Some(IntersectionDimension::Surface),
+            CoordSysCoordSysIntersection::Parallel { .. } => None,
+            CoordSysCoordSysIntersection::Ray(_) => Some(IntersectionDimension::Line),
+        }
+    }
+}
+
 impl NeoIntersectable for CoordinateSystem {
     type Output = CoordSysCoordSysIntersection;
+
     fn intersection(&self, rhs: &Self) -> Self::Output {
-        let inter = self.plane.intersection(&rhs.plane);
-        match inter {
-            PlanePlaneIntersection::Same => classify_parallel_coordinate_systems(self, rhs),
-            PlanePlaneIntersection::Ray(plane_intersection_ray) => {
-                classify_intersection_ray(self, rhs, plane_intersection_ray)
-            }
+        classify_coordinate_systems(self, rhs)
+            .unwrap_or(CoordSysCoordSysIntersection::Parallel { distance: f32::NAN })
+    }
+}
+
+impl NeoTryIntersectable for CoordinateSystem {
+    type Output = CoordSysCoordSysIntersection;
+
+    fn try_intersection(&self, rhs: &Self) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_coordinate_system(self)?;
+        ensure_valid_coordinate_system(rhs)?;
+        classify_coordinate_systems(self, rhs)
+    }
+}
+
+/// planes which aren't parallel always meet, the intersection ray isn't located
+impl NeoIntersects for CoordinateSystem {
+    fn intersects(&self, rhs: &Self) -> bool {
+        coordinate_systems_meet(self, rhs).unwrap_or(false)
+    }
+}
+
+impl NeoTryIntersects for CoordinateSystem {
+    fn try_intersects(&self, rhs: &Self) -> Result<bool, NeoGeometryError> {
+        ensure_valid_coordinate_system(self)?;
+        ensure_valid_coordinate_system(rhs)?;
+        coordinate_systems_meet(self, rhs)
+    }
+}
+
+fn coordinate_systems_meet(
+    c1: &CoordinateSystem,
+    c2: &CoordinateSystem,
+) -> Result<bool, NeoGeometryError> {
+    match c1.plane.intersection(&c2.plane) {
+        PlanePlaneIntersection::Same => Ok(matches!(
+            classify_parallel_coordinate_systems(c1, c2)?,
+            CoordSysCoordSysIntersection::Same
+        )),
+        PlanePlaneIntersection::Ray(_) => Ok(true),
+    }
+}
+
+fn classify_coordinate_systems(
+    c1: &CoordinateSystem,
+    c2: &CoordinateSystem,
+) -> Result<CoordSysCoordSysIntersection, NeoGeometryError> {
+    match c1.plane.intersection(&c2.plane) {
+        PlanePlaneIntersection::Same => classify_parallel_coordinate_systems(c1, c2),
+        PlanePlaneIntersection::Ray(plane_intersection_ray) => {
+            classify_intersection_ray(c1, c2, plane_intersection_ray)
         }
     }
 }
@@ -27,17 +100,18 @@ impl NeoIntersectable for CoordinateSystem {
 pub(crate) fn classify_parallel_coordinate_systems(
     c1: &CoordinateSystem,
     c2: &CoordinateSystem,
-) -> CoordSysCoordSysIntersection {
+) -> Result<CoordSysCoordSysIntersection, NeoGeometryError> {
     let normal_ray = Ray3D::new(c1.origin, c1.plane.normal);
-    let intersection = normal_ray.intersection(c2);
-    let RayCoordSys3DIntersection::Point(point_in_other) = intersection else {
-        unreachable!("the normal ray of parallel coords intersect the other coordinate system\n\n{normal_ray:?}\n\n{c1:?}\n{c2:?}\n\n{intersection:?}");
+    let RayCoordSys3DIntersection::Point(point_in_other) = normal_ray.intersection(c2) else {
+        return Err(NeoGeometryError::NumericalInconsistency(
+            "the normal ray of parallel coordinate systems misses the other coordinate system",
+        ));
     };
-    let distance = c1.origin.distance(point_in_other);
+    let distance = c1.origin.distance(point_in_other.point);
     if distance < COORDINATE_SYSTEM_EPS {
-        CoordSysCoordSysIntersection::Same
+        Ok(CoordSysCoordSysIntersection::Same)
     } else {
-        CoordSysCoordSysIntersection::Parallel { distance }
+        Ok(CoordSysCoordSysIntersection::Parallel { distance })
     }
 }
 
@@ -45,14 +119,20 @@ pub(crate) fn classify_intersection_ray(
     c1: &CoordinateSystem,
     c2: &CoordinateSystem,
     plane_intersection_ray: Ray3D,
-) -> CoordSysCoordSysIntersection {
+) -> Result<CoordSysCoordSysIntersection, NeoGeometryError> {
     let orthogonal_dir_in_c1 = plane_intersection_ray.direction.cross(c1.plane.normal);
     let non_parallel_ray_in_plane = Ray3D::new(c1.origin, orthogonal_dir_in_c1);
-    let intersection = non_parallel_ray_in_plane.intersection(c2);
-    let RayCoordSys3DIntersection::Point(point_in_other) = intersection else {
-        unreachable!("the normal ray of parallel coords intersect the other coordinate system\n\n{non_parallel_ray_in_plane:?}\n\n{c2:?}\n\n{intersection:?}");
+    let RayCoordSys3DIntersection::Point(point_in_other) =
+        non_parallel_ray_in_plane.intersection(c2)
+    else {
+        return Err(NeoGeometryError::NumericalInconsistency(
+            "a ray orthogonal to the plane intersection misses the other coordinate system",
+        ));
     };
-    CoordSysCoordSysIntersection::Ray(Ray3D::new(point_in_other, plane_intersection_ray.direction))
+    Ok(CoordSysCoordSysIntersection::Ray(Ray3D::new(
+        point_in_other.point,
+        plane_intersection_ray.direction,
+    )))
 }
 
 #[test]
@@ -95,3 +175,31 @@ fn ray_intersection_works() {
     assert!(c2.is_point_in_coordinate_system(ray.origin));
     assert!(c2.is_point_in_coordinate_system(ray.origin + ray.direction));
 }
+
+#[test]
+fn try_intersection_works() {
+    use glam::Vec3;
+    let c1 = CoordinateSystem::from_origin_and_normal(Vec3::Y, Vec3::Y);
+    let c2 = CoordinateSystem::from_origin_and_normal(Vec3::X, Vec3::X);
+    assert_eq!(c1.try_intersection(&c2), Ok(c1.intersection(&c2)));
+
+    let nan = CoordinateSystem::from_origin_and_normal(Vec3::NAN, Vec3::X);
+    assert_eq!(c1.try_intersection(&nan), Err(NeoGeometryError::NonFinite));
+
+    let mut flat = c2;
+    flat.plane.normal = Vec3::ZERO;
+    assert!(matches!(
+        c1.try_intersection(&flat),
+        Err(NeoGeometryError::Degenerate(_))
+    ));
+}
+
+#[test]
+fn bad_input_doesnt_panic() {
+    use glam::Vec3;
+    let c1 = CoordinateSystem::from_origin_and_normal(Vec3::Y, Vec3::Y);
+    let nan = CoordinateSystem::from_origin_and_normal(Vec3::NAN, Vec3::Y);
+    assert!(c1.intersection(&nan).dimension().is_none());
+    assert!(!c1.intersects(&nan));
+    assert_eq!(c1.try_intersects(&nan), Err(NeoGeometryError::NonFinite));
+}
@@ -1,2 +1 @@
 pub mod coord_sys;
-pub mod ray;
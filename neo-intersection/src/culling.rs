@@ -0,0 +1,24 @@
+use neo_surface::triangle3d::def::Triangle3D;
+
+/// Which sides of a triangle are ignored during intersection tests. The front face is the side the
+/// triangle normal points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriangleCulling {
+    #[default]
+    None,
+    BackFace,
+    FrontFace,
+}
+
+/// Triangle which is only intersected on the sides which are not culled
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CulledTriangle3D {
+    pub triangle: Triangle3D,
+    pub culling: TriangleCulling,
+}
+
+impl CulledTriangle3D {
+    pub const fn new(triangle: Triangle3D, culling: TriangleCulling) -> Self {
+        Self { triangle, culling }
+    }
+}
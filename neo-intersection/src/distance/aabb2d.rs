@@ -0,0 +1,71 @@
+use glam::Vec2;
+use neo_aabb::d2::def::AABB2D;
+
+use crate::distance::result::Distance2D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for AABB2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let [(lhs_x, rhs_x), (lhs_y, rhs_y)] = [0, 1].map(|axis| {
+            closest_in_ranges(
+                (self.min[axis], self.max[axis]),
+                (rhs.min[axis], rhs.max[axis]),
+            )
+        });
+        Distance2D::between(Vec2::new(lhs_x, lhs_y), Vec2::new(rhs_x, rhs_y))
+    }
+}
+
+impl NeoDistance<geo::Polygon<f32>> for AABB2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &geo::Polygon<f32>) -> Self::Output {
+        self.as_rect().to_polygon().distance(rhs)
+    }
+}
+
+mirror_distance!(AABB2D => geo::Polygon<f32>);
+
+/// closest pair of values of two ranges. Overlapping ranges meet in the middle of the overlap
+pub(crate) fn closest_in_ranges(
+    (a_min, a_max): (f32, f32),
+    (b_min, b_max): (f32, f32),
+) -> (f32, f32) {
+    if a_max < b_min {
+        (a_max, b_min)
+    } else if b_max < a_min {
+        (a_min, b_max)
+    } else {
+        let mid = (a_min.max(b_min) + a_max.min(b_max)) * 0.5;
+        (mid, mid)
+    }
+}
+
+#[cfg(test)]
+mod aabb2d_distance {
+    use glam::Vec2;
+    use neo_aabb::d2::def::AABB2D;
+
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn aabb_aabb_distance() {
+        let a = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let b = AABB2D::new(Vec2::new(4.0, 5.0), Vec2::new(5.0, 6.0));
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 5.0);
+        assert_eq!(dist.lhs_point, Vec2::ONE);
+        assert_eq!(dist.rhs_point, Vec2::new(4.0, 5.0));
+    }
+
+    #[test]
+    fn overlapping_aabbs_meet_in_overlap() {
+        let a = AABB2D::new(Vec2::ZERO, Vec2::splat(2.0));
+        let b = AABB2D::new(Vec2::ONE, Vec2::splat(3.0));
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 0.0);
+        assert_eq!(dist.lhs_point, Vec2::splat(1.5));
+    }
+}
@@ -0,0 +1,192 @@
+use glam::Vec3;
+use neo_aabb::d3::def::AABB3D;
+use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::aabb2d::closest_in_ranges;
+use crate::distance::point3d::{closest_point_on_plane, signed_plane_distance};
+use crate::distance::result::Distance3D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for AABB3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let [(lhs_x, rhs_x), (lhs_y, rhs_y), (lhs_z, rhs_z)] = [0, 1, 2].map(|axis| {
+            closest_in_ranges(
+                (self.min[axis], self.max[axis]),
+                (rhs.min[axis], rhs.max[axis]),
+            )
+        });
+        Distance3D::between(
+            Vec3::new(lhs_x, lhs_y, lhs_z),
+            Vec3::new(rhs_x, rhs_y, rhs_z),
+        )
+    }
+}
+
+impl NeoDistance<CoordinateSystem> for AABB3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &CoordinateSystem) -> Self::Output {
+        // the plane cuts the box if it cuts one of its edges
+        let crossing = aabb_edges(self).into_iter().find_map(|edge| {
+            let [d_src, d_dst] = edge.array().map(|p| signed_plane_distance(rhs, p));
+            (d_src * d_dst <= 0.0).then(|| {
+                if d_src == d_dst {
+                    edge.src
+                } else {
+                    edge.inject_scalar(d_src / (d_src - d_dst))
+                }
+            })
+        });
+        if let Some(p) = crossing {
+            return Distance3D::between(p, p);
+        }
+        Distance3D::closest(
+            aabb_corners(self).map(|p| Distance3D::between(p, closest_point_on_plane(rhs, p))),
+        )
+    }
+}
+
+impl NeoDistance<NeoSurface> for AABB3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &NeoSurface) -> Self::Output {
+        // both are convex or planar, so the closest points are located on an edge of one of them.
+        // This also finds intersections since one of the edges has to cut the other geometry then
+        let surface_edges = rhs
+            .as_polygon_3d()
+            .iter_all_lines()
+            .map(|edge| edge.distance(self).swapped())
+            .collect::<Vec<_>>();
+        Distance3D::closest(
+            surface_edges
+                .into_iter()
+                .chain(aabb_edges(self).map(|edge| edge.distance(rhs))),
+        )
+    }
+}
+
+mirror_distance!(AABB3D => CoordinateSystem, NeoSurface);
+
+pub(crate) fn aabb_corners(aabb: &AABB3D) -> [Vec3; 8] {
+    std::array::from_fn(|i| {
+        Vec3::new(
+            if i & 1 == 0 { aabb.min.x } else { aabb.max.x },
+            if i & 2 == 0 { aabb.min.y } else { aabb.max.y },
+            if i & 4 == 0 { aabb.min.z } else { aabb.max.z },
+        )
+    })
+}
+
+/// the 12 edges of the box, 4 along each axis
+pub(crate) fn aabb_edges(aabb: &AABB3D) -> [LineSegment3D; 12] {
+    let corners = aabb_corners(aabb);
+    std::array::from_fn(|i| {
+        let axis_bit = 1 << (i / 4);
+        // spread the 2 bit counter over the two bits which don't belong to the axis
+        let counter = i % 4;
+        let low_mask = axis_bit - 1;
+        let start = (counter & low_mask) | ((counter & !low_mask) << 1);
+        LineSegment3D::new(corners[start], corners[start | axis_bit])
+    })
+}
+
+/// slab test of a line `origin + t * direction` with `t` in `t_range` against the box, returns the
+/// first point inside the box
+pub(crate) fn first_point_inside(
+    aabb: &AABB3D,
+    origin: Vec3,
+    direction: Vec3,
+    (t_min, t_max): (f32, f32),
+) -> Option<Vec3> {
+    let mut t_enter = t_min;
+    let mut t_exit = t_max;
+    for axis in 0..3 {
+        let (o, d) = (origin[axis], direction[axis]);
+        if d.abs() < f32::EPSILON {
+            if o < aabb.min[axis] || o > aabb.max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let [a, b] = [aabb.min[axis], aabb.max[axis]].map(|bound| (bound - o) / d);
+        t_enter = t_enter.max(a.min(b));
+        t_exit = t_exit.min(a.max(b));
+    }
+    if t_enter > t_exit {
+        return None;
+    }
+    // an unbounded line parallel to all axis (zero direction) doesn't have a finite parameter
+    let t = if t_enter.is_finite() { t_enter } else { 0.0 };
+    Some(origin + t * direction)
+}
+
+#[cfg(test)]
+mod aabb3d_distance {
+    use glam::{Vec2, Vec3};
+    use neo_aabb::d3::def::AABB3D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::distance::aabb3d::aabb_edges;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn aabb_edges_are_axis_aligned_and_unique() {
+        let aabb = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let edges = aabb_edges(&aabb);
+        for (i, edge) in edges.iter().enumerate() {
+            assert!((edge.length() - 1.0).abs() < 0.000_1, "{edge:?}");
+            for other in edges.iter().skip(i + 1) {
+                assert_ne!(edge, other);
+            }
+        }
+    }
+
+    #[test]
+    fn aabb_aabb_distance() {
+        let a = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let b = AABB3D::new(Vec3::new(0.5, 3.0, 5.0), Vec3::new(2.0, 4.0, 6.0));
+        let dist = a.distance(&b);
+        assert!((dist.distance - 20.0_f32.sqrt()).abs() < 0.000_1);
+        assert!(dist
+            .lhs_point
+            .abs_diff_eq(Vec3::new(0.75, 1.0, 1.0), 0.000_1));
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(0.75, 3.0, 5.0), 0.000_1));
+    }
+
+    #[test]
+    fn aabb_coord_sys_distance() {
+        let aabb = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let cutting = CoordinateSystem::from_origin_and_normal(Vec3::splat(0.5), Vec3::ONE);
+        assert_eq!(aabb.distance(&cutting).distance, 0.0);
+
+        let above = CoordinateSystem::from_origin_and_normal(Vec3::Z * 3.0, Vec3::Z);
+        let dist = aabb.distance(&above);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!((dist.lhs_point.z - 1.0).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn aabb_surface_distance() {
+        let p = Plane::from_local_axis(Vec3::X, Vec3::Y);
+        let c = CoordinateSystem::from_origin_and_plane(Vec3::Z * 3.0, p);
+        let shape = geo::Rect::<f32>::new(Vec2::splat(0.25).to_geo(), Vec2::splat(0.75).to_geo());
+        let surface = NeoSurface::new(c, geo::Coord::zero(), shape.to_polygon());
+
+        let aabb = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let dist = aabb.distance(&surface);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+
+        // the surface floats inside of the box without touching its edges
+        let aabb = AABB3D::new(Vec3::new(0.0, 0.0, 2.0), Vec3::new(1.0, 1.0, 4.0));
+        assert_eq!(aabb.distance(&surface).distance, 0.0);
+    }
+}
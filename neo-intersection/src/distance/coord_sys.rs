@@ -0,0 +1,92 @@
+use neo_coordinate_system::CoordinateSystem;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::point3d::{closest_point_on_plane, signed_plane_distance};
+use crate::distance::result::Distance3D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for CoordinateSystem {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let (n1, n2) = (self.plane.normal, rhs.plane.normal);
+        let direction = n1.cross(n2);
+        let length_squared = direction.length_squared();
+        if length_squared < f32::EPSILON {
+            return Distance3D::between(closest_point_on_plane(self, rhs.origin), rhs.origin);
+        }
+        // point on the intersection line of both planes
+        let (h1, h2) = (n1.dot(self.origin), n2.dot(rhs.origin));
+        let p = (h1 * n2.cross(direction) + h2 * direction.cross(n1)) / length_squared;
+        Distance3D::between(p, p)
+    }
+}
+
+impl NeoDistance<NeoSurface> for CoordinateSystem {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &NeoSurface) -> Self::Output {
+        let polygon = rhs.as_polygon_3d();
+        let crossing = polygon.iter_all_lines().find_map(|edge| {
+            let [d_src, d_dst] = edge.array().map(|p| signed_plane_distance(self, p));
+            (d_src * d_dst <= 0.0).then(|| {
+                if d_src == d_dst {
+                    edge.src
+                } else {
+                    edge.inject_scalar(d_src / (d_src - d_dst))
+                }
+            })
+        });
+        if let Some(p) = crossing {
+            return Distance3D::between(p, p);
+        }
+        Distance3D::closest(
+            polygon
+                .iter_all_points()
+                .map(|p| Distance3D::between(closest_point_on_plane(self, *p), *p)),
+        )
+    }
+}
+
+mirror_distance!(CoordinateSystem => NeoSurface);
+
+#[cfg(test)]
+mod coord_sys_distance {
+    use glam::Vec3;
+    use neo_coordinate_system::CoordinateSystem;
+
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn crossing_coord_systems_meet_on_both_planes() {
+        let a = CoordinateSystem::from_origin_and_normal(Vec3::Z, Vec3::Z);
+        let b = CoordinateSystem::from_origin_and_normal(Vec3::X * 2.0, Vec3::X);
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 0.0);
+        assert!(a.is_point_in_coordinate_system(dist.lhs_point));
+        assert!(b.is_point_in_coordinate_system(dist.lhs_point));
+    }
+
+    #[test]
+    fn parallel_coord_systems_distance() {
+        let a = CoordinateSystem::from_origin_and_normal(Vec3::Z, Vec3::Z);
+        let b = CoordinateSystem::from_origin_and_normal(Vec3::new(1.0, 2.0, 4.0), -Vec3::Z);
+        let dist = a.distance(&b);
+        assert!((dist.distance - 3.0).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn coord_sys_surface_distance() {
+        // vertical unit square standing on z = 1
+        let surface = unit_square(Vec3::Z, Vec3::X, Vec3::Z);
+
+        let floor = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        let dist = floor.distance(&surface);
+        assert!((dist.distance - 1.0).abs() < 0.000_1);
+        assert!((dist.rhs_point.z - 1.0).abs() < 0.000_1);
+
+        let cutting = CoordinateSystem::from_origin_and_normal(Vec3::Z * 1.5, Vec3::Z);
+        assert_eq!(cutting.distance(&surface).distance, 0.0);
+    }
+}
@@ -0,0 +1,132 @@
+use geo::Intersects;
+use glam::Vec2;
+use neo_aabb::d2::def::AABB2D;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::def::Ray2D;
+
+use crate::distance::polygon2d::polygon_edges;
+use crate::distance::result::Distance2D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for LineSegment2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        if let Some(p) = crossing_point(self, rhs.src, rhs.direction(), true) {
+            return Distance2D::between(p, p);
+        }
+        Distance2D::closest([
+            NeoDistance::distance(&self.src, rhs),
+            NeoDistance::distance(&self.dst, rhs),
+            NeoDistance::distance(&rhs.src, self).swapped(),
+            NeoDistance::distance(&rhs.dst, self).swapped(),
+        ])
+    }
+}
+
+impl NeoDistance<Ray2D> for LineSegment2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Ray2D) -> Self::Output {
+        if let Some(p) = crossing_point(self, rhs.origin, rhs.direction(), false) {
+            return Distance2D::between(p, p);
+        }
+        Distance2D::closest([
+            NeoDistance::distance(&self.src, rhs),
+            NeoDistance::distance(&self.dst, rhs),
+        ])
+    }
+}
+
+impl NeoDistance<AABB2D> for LineSegment2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &AABB2D) -> Self::Output {
+        self.distance(&rhs.as_rect().to_polygon())
+    }
+}
+
+impl NeoDistance<geo::Polygon<f32>> for LineSegment2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &geo::Polygon<f32>) -> Self::Output {
+        // if the line isn't located completely inside of the polygon, it crosses an edge
+        if rhs.intersects(&self.src.to_geo()) {
+            return Distance2D::between(self.src, self.src);
+        }
+        Distance2D::closest(polygon_edges(rhs).map(|edge| self.distance(&edge)))
+    }
+}
+
+mirror_distance!(LineSegment2D => Ray2D, AABB2D, geo::Polygon<f32>);
+
+/// point where the segment crosses the other segment (`bounded`) or line starting at `origin`
+/// with `direction`. Parallel lines never cross, overlaps are found by the endpoint checks
+fn crossing_point(
+    line: &LineSegment2D,
+    origin: Vec2,
+    direction: Vec2,
+    bounded: bool,
+) -> Option<Vec2> {
+    let line_direction = line.direction();
+    let denom = line_direction.perp_dot(direction);
+    if denom.abs() < f32::EPSILON {
+        return None;
+    }
+    let offset = origin - line.src;
+    let t = offset.perp_dot(direction) / denom;
+    let s = offset.perp_dot(line_direction) / denom;
+    let in_range = |x: f32| (0.0..=1.0).contains(&x);
+    (in_range(t) && (!bounded || in_range(s))).then(|| line.src + t * line_direction)
+}
+
+#[cfg(test)]
+mod line2d_distance {
+    use glam::Vec2;
+    use neo_aabb::d2::def::AABB2D;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn crossing_segments_have_zero_distance() {
+        let a = LineSegment2D::new(Vec2::ZERO, Vec2::ONE);
+        let b = LineSegment2D::new(Vec2::Y, Vec2::X);
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 0.0);
+        assert!(dist.lhs_point.abs_diff_eq(Vec2::splat(0.5), 0.000_1));
+    }
+
+    #[test]
+    fn parallel_segments_distance() {
+        let a = LineSegment2D::new(Vec2::ZERO, Vec2::X);
+        let b = LineSegment2D::new(Vec2::new(0.5, 2.0), Vec2::new(3.0, 2.0));
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 2.0);
+        assert_eq!(dist.lhs_point.y, 0.0);
+        assert_eq!(dist.rhs_point.y, 2.0);
+    }
+
+    #[test]
+    fn segment_ray_distance_uses_unbounded_line() {
+        let line = LineSegment2D::new(Vec2::new(0.0, 1.0), Vec2::new(0.0, 2.0));
+        let ray = Ray2D::new(Vec2::new(5.0, 0.0), Vec2::X);
+        let dist = line.distance(&ray);
+        assert_eq!(dist.distance, 1.0);
+        assert_eq!(dist.rhs_point, Vec2::ZERO);
+    }
+
+    #[test]
+    fn segment_inside_aabb_has_zero_distance() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::splat(4.0));
+        let line = LineSegment2D::new(Vec2::ONE, Vec2::splat(2.0));
+        assert_eq!(line.distance(&aabb).distance, 0.0);
+
+        let line = LineSegment2D::new(Vec2::new(5.0, 1.0), Vec2::new(6.0, 2.0));
+        let dist = line.distance(&aabb);
+        assert_eq!(dist.distance, 1.0);
+        assert_eq!(dist.rhs_point, Vec2::new(4.0, 1.0));
+    }
+}
@@ -0,0 +1,222 @@
+use glam::Vec3;
+use neo_aabb::d3::def::AABB3D;
+use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::aabb3d::{aabb_edges, first_point_inside};
+use crate::distance::point3d::{closest_point_on_plane, signed_plane_distance};
+use crate::distance::result::Distance3D;
+use crate::line3d::surface::LineSurface3DIntersection;
+use crate::line_intersection_parts::Line3DIntersectionParts;
+use crate::trait_def::{mirror_distance, NeoDistance, NeoIntersectable};
+
+impl NeoDistance for LineSegment3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let (s, t) = closest_parameters(
+            (self.src, self.direction(), true),
+            (rhs.src, rhs.direction(), true),
+        );
+        Distance3D::between(self.inject_scalar(s), rhs.inject_scalar(t))
+    }
+}
+
+impl NeoDistance<Ray3D> for LineSegment3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Ray3D) -> Self::Output {
+        let (s, t) = closest_parameters(
+            (self.src, self.direction(), true),
+            (rhs.origin, rhs.direction, false),
+        );
+        Distance3D::between(self.inject_scalar(s), rhs.origin + t * rhs.direction)
+    }
+}
+
+impl NeoDistance<AABB3D> for LineSegment3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &AABB3D) -> Self::Output {
+        if let Some(p) = first_point_inside(rhs, self.src, self.direction(), (0.0, 1.0)) {
+            return Distance3D::between(p, p);
+        }
+        // a separated box is closest to an endpoint or to one of the box edges
+        Distance3D::closest(
+            [
+                NeoDistance::distance(&self.src, rhs),
+                NeoDistance::distance(&self.dst, rhs),
+            ]
+            .into_iter()
+            .chain(aabb_edges(rhs).map(|edge| self.distance(&edge))),
+        )
+    }
+}
+
+impl NeoDistance<CoordinateSystem> for LineSegment3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &CoordinateSystem) -> Self::Output {
+        let [d_src, d_dst] = self.array().map(|p| signed_plane_distance(rhs, p));
+        if d_src * d_dst <= 0.0 {
+            let p = if d_src == d_dst {
+                self.src
+            } else {
+                self.inject_scalar(d_src / (d_src - d_dst))
+            };
+            return Distance3D::between(p, p);
+        }
+        let p = if d_src.abs() <= d_dst.abs() {
+            self.src
+        } else {
+            self.dst
+        };
+        Distance3D::between(p, closest_point_on_plane(rhs, p))
+    }
+}
+
+impl NeoDistance<NeoSurface> for LineSegment3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &NeoSurface) -> Self::Output {
+        let touching_point = match self.intersection(rhs) {
+            LineSurface3DIntersection::None => None,
+            LineSurface3DIntersection::Point(p) => Some(p),
+            LineSurface3DIntersection::Parts(ps) => ps.first().map(|part| match part {
+                Line3DIntersectionParts::Point(p) => *p,
+                Line3DIntersectionParts::Line(l) => l.src,
+            }),
+        };
+        if let Some(p) = touching_point {
+            return Distance3D::between(p, p);
+        }
+        // a separated surface is closest to an endpoint or to one of the boundary edges
+        Distance3D::closest(
+            [
+                NeoDistance::distance(&self.src, rhs),
+                NeoDistance::distance(&self.dst, rhs),
+            ]
+            .into_iter()
+            .chain(
+                rhs.as_polygon_3d()
+                    .iter_all_lines()
+                    .map(|edge| self.distance(&edge)),
+            ),
+        )
+    }
+}
+
+mirror_distance!(LineSegment3D => Ray3D, AABB3D, CoordinateSystem, NeoSurface);
+
+/// parameters of the closest points of two lines given as `(origin, direction, bounded)`.
+/// Bounded lines are segments with parameters in `[0, 1]`, unbounded lines are infinite. Parallel
+/// lines are measured from the origin of the first line
+pub(crate) fn closest_parameters(
+    (origin_a, dir_a, bounded_a): (Vec3, Vec3, bool),
+    (origin_b, dir_b, bounded_b): (Vec3, Vec3, bool),
+) -> (f32, f32) {
+    let clamp_a = |x: f32| if bounded_a { x.clamp(0.0, 1.0) } else { x };
+    let clamp_b = |x: f32| if bounded_b { x.clamp(0.0, 1.0) } else { x };
+
+    let r = origin_a - origin_b;
+    let a = dir_a.length_squared();
+    let e = dir_b.length_squared();
+    let f = dir_b.dot(r);
+    if a <= f32::EPSILON && e <= f32::EPSILON {
+        return (0.0, 0.0);
+    }
+    if a <= f32::EPSILON {
+        return (0.0, clamp_b(f / e));
+    }
+    let c = dir_a.dot(r);
+    if e <= f32::EPSILON {
+        return (clamp_a(-c / a), 0.0);
+    }
+    let b = dir_a.dot(dir_b);
+    let denom = a * e - b * b;
+    let s = if denom.abs() > f32::EPSILON * a * e {
+        clamp_a((b * f - c * e) / denom)
+    } else {
+        0.0
+    };
+    let t = (b * s + f) / e;
+    let clamped_t = clamp_b(t);
+    if clamped_t == t {
+        (s, t)
+    } else {
+        (clamp_a((b * clamped_t - c) / a), clamped_t)
+    }
+}
+
+#[cfg(test)]
+mod line3d_distance {
+    use glam::Vec3;
+    use neo_aabb::d3::def::AABB3D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn skew_segments_distance() {
+        let a = LineSegment3D::new(Vec3::NEG_X, Vec3::X);
+        let b = LineSegment3D::new(Vec3::new(0.0, -1.0, 2.0), Vec3::new(0.0, 1.0, 2.0));
+        let dist = a.distance(&b);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!(dist.lhs_point.abs_diff_eq(Vec3::ZERO, 0.000_1));
+        assert!(dist.rhs_point.abs_diff_eq(Vec3::Z * 2.0, 0.000_1));
+    }
+
+    #[test]
+    fn segment_distance_clamps_to_endpoints() {
+        // two pipes, the closest points are endpoints of both
+        let a = LineSegment3D::new(Vec3::ZERO, Vec3::X);
+        let b = LineSegment3D::new(Vec3::new(2.0, 0.05, 0.0), Vec3::new(2.0, 3.0, 0.0));
+        let dist = a.distance(&b);
+        assert!(dist.lhs_point.abs_diff_eq(Vec3::X, 0.000_1));
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(2.0, 0.05, 0.0), 0.000_1));
+        assert!(!dist.is_within(1.0));
+    }
+
+    #[test]
+    fn segment_ray_distance() {
+        let a = LineSegment3D::new(Vec3::Z, Vec3::Z * 2.0);
+        let ray = Ray3D::new(Vec3::new(5.0, 0.0, 0.0), Vec3::X);
+        let dist = a.distance(&ray);
+        assert!((dist.distance - 1.0).abs() < 0.000_1);
+        assert!(dist.rhs_point.abs_diff_eq(Vec3::ZERO, 0.000_1));
+    }
+
+    #[test]
+    fn segment_aabb_distance() {
+        let aabb = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let crossing = LineSegment3D::new(Vec3::splat(-1.0), Vec3::splat(2.0));
+        assert_eq!(crossing.distance(&aabb).distance, 0.0);
+
+        // passes the edge x = 1, y = 1 diagonally
+        let line = LineSegment3D::new(Vec3::new(3.0, 1.0, 0.5), Vec3::new(1.0, 3.0, 0.5));
+        let dist = line.distance(&aabb);
+        assert!((dist.distance - 2.0_f32.sqrt()).abs() < 0.000_1);
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(1.0, 1.0, 0.5), 0.000_1));
+    }
+
+    #[test]
+    fn segment_coord_sys_distance() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        let crossing = LineSegment3D::new(Vec3::NEG_Z, Vec3::Z * 3.0);
+        let dist = crossing.distance(&c);
+        assert_eq!(dist.distance, 0.0);
+        assert!(dist.lhs_point.abs_diff_eq(Vec3::ZERO, 0.000_1));
+
+        let above = LineSegment3D::new(Vec3::Z * 2.0, Vec3::new(1.0, 0.0, 4.0));
+        let dist = above.distance(&c);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+    }
+}
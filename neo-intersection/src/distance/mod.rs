@@ -0,0 +1,12 @@
+pub mod aabb2d;
+pub mod aabb3d;
+pub mod coord_sys;
+pub mod line2d;
+pub mod line3d;
+pub mod point2d;
+pub mod point3d;
+pub mod polygon2d;
+pub mod ray2d;
+pub mod ray3d;
+pub mod result;
+pub mod surface;
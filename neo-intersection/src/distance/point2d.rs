@@ -0,0 +1,115 @@
+use geo::Intersects;
+use glam::Vec2;
+use neo_aabb::d2::def::AABB2D;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::def::Ray2D;
+
+use crate::distance::polygon2d::polygon_edges;
+use crate::distance::result::Distance2D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for Vec2 {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        Distance2D::between(*self, *rhs)
+    }
+}
+
+impl NeoDistance<LineSegment2D> for Vec2 {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &LineSegment2D) -> Self::Output {
+        Distance2D::between(*self, closest_point_on_segment(rhs, *self))
+    }
+}
+
+impl NeoDistance<Ray2D> for Vec2 {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Ray2D) -> Self::Output {
+        Distance2D::between(*self, rhs.project_point(*self))
+    }
+}
+
+impl NeoDistance<AABB2D> for Vec2 {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &AABB2D) -> Self::Output {
+        Distance2D::between(*self, self.clamp(rhs.min, rhs.max))
+    }
+}
+
+impl NeoDistance<geo::Polygon<f32>> for Vec2 {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &geo::Polygon<f32>) -> Self::Output {
+        if rhs.intersects(&self.to_geo()) {
+            return Distance2D::between(*self, *self);
+        }
+        Distance2D::closest(polygon_edges(rhs).map(|edge| self.distance(&edge)))
+    }
+}
+
+mirror_distance!(Vec2 => LineSegment2D, Ray2D, AABB2D, geo::Polygon<f32>);
+
+pub(crate) fn closest_point_on_segment(line: &LineSegment2D, point: Vec2) -> Vec2 {
+    let direction = line.direction();
+    let length_squared = direction.length_squared();
+    if length_squared == 0.0 {
+        return line.src;
+    }
+    let scalar = ((point - line.src).dot(direction) / length_squared).clamp(0.0, 1.0);
+    line.src + scalar * direction
+}
+
+#[cfg(test)]
+mod point2d_distance {
+    use glam::Vec2;
+    use neo_aabb::d2::def::AABB2D;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::distance::result::Distance2D;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn point_segment_distance_clamps_to_endpoints() {
+        let line = LineSegment2D::new(Vec2::ZERO, Vec2::X);
+        let dist = NeoDistance::distance(&Vec2::new(2.0, 1.0), &line);
+        assert_eq!(dist, Distance2D::between(Vec2::new(2.0, 1.0), Vec2::X));
+        let dist = NeoDistance::distance(&Vec2::new(0.5, 1.0), &line);
+        assert_eq!(dist.rhs_point, Vec2::new(0.5, 0.0));
+        assert_eq!(dist.distance, 1.0);
+    }
+
+    #[test]
+    fn point_polygon_distance_respects_holes() {
+        let polygon = geo::Polygon::new(
+            geo::LineString::from(vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]),
+            vec![geo::LineString::from(vec![
+                (1.0, 1.0),
+                (3.0, 1.0),
+                (3.0, 3.0),
+                (1.0, 3.0),
+            ])],
+        );
+        let inside = Vec2::new(0.5, 2.0);
+        assert_eq!(NeoDistance::distance(&inside, &polygon).distance, 0.0);
+
+        let in_hole = Vec2::new(2.0, 1.5);
+        let dist = NeoDistance::distance(&in_hole, &polygon);
+        assert!((dist.distance - 0.5).abs() < 0.000_1);
+        assert!(dist.rhs_point.abs_diff_eq(Vec2::new(2.0, 1.0), 0.000_1));
+    }
+
+    #[test]
+    fn mirrored_distance_swaps_points() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let point = Vec2::new(3.0, 0.5);
+        let dist = aabb.distance(&point);
+        assert_eq!(dist.lhs_point, Vec2::new(1.0, 0.5));
+        assert_eq!(dist.rhs_point, point);
+        assert_eq!(dist.distance, 2.0);
+    }
+}
@@ -0,0 +1,130 @@
+use glam::Vec3;
+use neo_aabb::d3::def::AABB3D;
+use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::result::Distance3D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        Distance3D::between(*self, *rhs)
+    }
+}
+
+impl NeoDistance<LineSegment3D> for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &LineSegment3D) -> Self::Output {
+        Distance3D::between(*self, closest_point_on_segment(rhs, *self))
+    }
+}
+
+impl NeoDistance<Ray3D> for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Ray3D) -> Self::Output {
+        Distance3D::between(*self, rhs.project_point(*self))
+    }
+}
+
+impl NeoDistance<AABB3D> for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &AABB3D) -> Self::Output {
+        Distance3D::between(*self, self.clamp(rhs.min, rhs.max))
+    }
+}
+
+impl NeoDistance<CoordinateSystem> for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &CoordinateSystem) -> Self::Output {
+        Distance3D::between(*self, closest_point_on_plane(rhs, *self))
+    }
+}
+
+impl NeoDistance<NeoSurface> for Vec3 {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &NeoSurface) -> Self::Output {
+        let projected = closest_point_on_plane(&rhs.coordinate_system, *self);
+        if rhs.is_point_in_surface(projected) {
+            return Distance3D::between(*self, projected);
+        }
+        Distance3D::closest(
+            rhs.as_polygon_3d()
+                .iter_all_lines()
+                .map(|edge| self.distance(&edge)),
+        )
+    }
+}
+
+mirror_distance!(Vec3 => LineSegment3D, Ray3D, AABB3D, CoordinateSystem, NeoSurface);
+
+pub(crate) fn closest_point_on_segment(line: &LineSegment3D, point: Vec3) -> Vec3 {
+    let direction = line.direction();
+    let length_squared = direction.length_squared();
+    if length_squared == 0.0 {
+        return line.src;
+    }
+    let scalar = ((point - line.src).dot(direction) / length_squared).clamp(0.0, 1.0);
+    line.src + scalar * direction
+}
+
+/// signed distance of the point to the plane of the coordinate system, positive on the side the
+/// normal points to
+pub(crate) fn signed_plane_distance(coord_sys: &CoordinateSystem, point: Vec3) -> f32 {
+    coord_sys.plane.normal.dot(point - coord_sys.origin)
+}
+
+pub(crate) fn closest_point_on_plane(coord_sys: &CoordinateSystem, point: Vec3) -> Vec3 {
+    point - coord_sys.plane.normal * signed_plane_distance(coord_sys, point)
+}
+
+#[cfg(test)]
+mod point3d_distance {
+    use glam::Vec3;
+    use neo_coordinate_system::CoordinateSystem;
+
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn point_above_surface_projects_onto_it() {
+        let dist = NeoDistance::distance(
+            &Vec3::new(0.5, 0.5, 2.0),
+            &unit_square(Vec3::ZERO, Vec3::X, Vec3::Y),
+        );
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(0.5, 0.5, 0.0), 0.000_1));
+    }
+
+    #[test]
+    fn point_beside_surface_is_closest_to_boundary() {
+        let dist = NeoDistance::distance(
+            &Vec3::new(4.0, 0.5, 4.0),
+            &unit_square(Vec3::ZERO, Vec3::X, Vec3::Y),
+        );
+        assert!((dist.distance - 5.0).abs() < 0.000_1);
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(1.0, 0.5, 0.0), 0.000_1));
+    }
+
+    #[test]
+    fn point_coord_sys_distance() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::Z, Vec3::Z);
+        let dist = NeoDistance::distance(&Vec3::new(1.0, 2.0, -1.0), &c);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(1.0, 2.0, 1.0), 0.000_1));
+    }
+}
@@ -0,0 +1,68 @@
+use geo::{CoordsIter, LinesIter};
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::distance::result::Distance2D;
+use crate::trait_def::NeoDistance;
+
+impl NeoDistance for geo::Polygon<f32> {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        // if none of the vertices is contained in the other polygon, overlapping polygons have
+        // crossing edges
+        let contained_vertex = |a: &geo::Polygon<f32>, b: &geo::Polygon<f32>| {
+            a.exterior_coords_iter()
+                .map(|c| c.to_glam())
+                .find(|p| NeoDistance::distance(p, b).distance == 0.0)
+        };
+        if let Some(p) = contained_vertex(self, rhs).or_else(|| contained_vertex(rhs, self)) {
+            return Distance2D::between(p, p);
+        }
+        Distance2D::closest(
+            polygon_edges(self)
+                .flat_map(|a| polygon_edges(rhs).map(move |b| a.distance(&b)))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// all edges of the exterior and the interiors of the polygon
+pub(crate) fn polygon_edges(
+    polygon: &geo::Polygon<f32>,
+) -> impl Iterator<Item = LineSegment2D> + '_ {
+    polygon
+        .lines_iter()
+        .map(|l| LineSegment2D::new(l.start.to_glam(), l.end.to_glam()))
+}
+
+#[cfg(test)]
+mod polygon2d_distance {
+    use glam::Vec2;
+
+    use crate::trait_def::NeoDistance;
+
+    fn square(min: (f32, f32), max: (f32, f32)) -> geo::Polygon<f32> {
+        geo::Rect::<f32>::new(min, max).to_polygon()
+    }
+
+    #[test]
+    fn polygon_polygon_distance() {
+        let a = square((0.0, 0.0), (1.0, 1.0));
+        let b = square((3.0, 0.5), (4.0, 2.0));
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 2.0);
+        assert_eq!(dist.lhs_point.x, 1.0);
+        assert_eq!(dist.rhs_point.x, 3.0);
+    }
+
+    #[test]
+    fn nested_polygons_have_zero_distance() {
+        let a = square((0.0, 0.0), (4.0, 4.0));
+        let b = square((1.0, 1.0), (2.0, 2.0));
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 0.0);
+        assert_eq!(b.distance(&a).distance, 0.0);
+        assert!(dist.lhs_point.cmpge(Vec2::ONE).all());
+    }
+}
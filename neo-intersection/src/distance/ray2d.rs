@@ -0,0 +1,76 @@
+use neo_aabb::d2::def::AABB2D;
+use neo_ray::d2::def::Ray2D;
+
+use crate::distance::polygon2d::polygon_edges;
+use crate::distance::result::Distance2D;
+use crate::trait_def::{mirror_distance, NeoDistance};
+
+impl NeoDistance for Ray2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let denom = self.direction().perp_dot(rhs.direction());
+        if denom.abs() < f32::EPSILON {
+            return Distance2D::between(self.project_point(rhs.origin), rhs.origin);
+        }
+        let t = (rhs.origin - self.origin).perp_dot(rhs.direction()) / denom;
+        let p = self.origin + t * self.direction();
+        Distance2D::between(p, p)
+    }
+}
+
+impl NeoDistance<AABB2D> for Ray2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &AABB2D) -> Self::Output {
+        self.distance(&rhs.as_rect().to_polygon())
+    }
+}
+
+impl NeoDistance<geo::Polygon<f32>> for Ray2D {
+    type Output = Distance2D;
+
+    fn distance(&self, rhs: &geo::Polygon<f32>) -> Self::Output {
+        // an unbounded line can't be located completely inside of the polygon
+        Distance2D::closest(polygon_edges(rhs).map(|edge| self.distance(&edge)))
+    }
+}
+
+mirror_distance!(Ray2D => AABB2D, geo::Polygon<f32>);
+
+#[cfg(test)]
+mod ray2d_distance {
+    use glam::Vec2;
+    use neo_aabb::d2::def::AABB2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn crossing_rays_have_zero_distance() {
+        let a = Ray2D::new(Vec2::ZERO, Vec2::X);
+        let b = Ray2D::new(Vec2::new(3.0, 5.0), Vec2::Y);
+        let dist = a.distance(&b);
+        assert_eq!(dist.distance, 0.0);
+        assert!(dist.lhs_point.abs_diff_eq(Vec2::new(3.0, 0.0), 0.000_1));
+    }
+
+    #[test]
+    fn parallel_rays_distance() {
+        let a = Ray2D::new(Vec2::ZERO, Vec2::X);
+        let b = Ray2D::new(Vec2::new(3.0, 2.0), -Vec2::X);
+        assert_eq!(a.distance(&b).distance, 2.0);
+    }
+
+    #[test]
+    fn ray_aabb_distance() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let ray = Ray2D::new(Vec2::new(0.0, 3.0), Vec2::X);
+        let dist = ray.distance(&aabb);
+        assert_eq!(dist.distance, 2.0);
+        assert_eq!(dist.rhs_point.y, 1.0);
+
+        let ray = Ray2D::new(Vec2::new(-3.0, 0.5), Vec2::X);
+        assert_eq!(ray.distance(&aabb).distance, 0.0);
+    }
+}
@@ -0,0 +1,133 @@
+use neo_aabb::d3::def::AABB3D;
+use neo_coordinate_system::CoordinateSystem;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::aabb3d::{aabb_edges, first_point_inside};
+use crate::distance::line3d::closest_parameters;
+use crate::distance::point3d::{closest_point_on_plane, signed_plane_distance};
+use crate::distance::result::Distance3D;
+use crate::surface::ray::SurfaceRay3DIntersection;
+use crate::trait_def::{mirror_distance, NeoDistance, NeoIntersectable};
+
+impl NeoDistance for Ray3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        let (s, t) = closest_parameters(
+            (self.origin, self.direction, false),
+            (rhs.origin, rhs.direction, false),
+        );
+        Distance3D::between(
+            self.origin + s * self.direction,
+            rhs.origin + t * rhs.direction,
+        )
+    }
+}
+
+impl NeoDistance<AABB3D> for Ray3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &AABB3D) -> Self::Output {
+        let unbounded = (f32::NEG_INFINITY, f32::INFINITY);
+        if let Some(p) = first_point_inside(rhs, self.origin, self.direction, unbounded) {
+            return Distance3D::between(p, p);
+        }
+        Distance3D::closest(aabb_edges(rhs).map(|edge| edge.distance(self).swapped()))
+    }
+}
+
+impl NeoDistance<CoordinateSystem> for Ray3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &CoordinateSystem) -> Self::Output {
+        let speed = rhs.plane.normal.dot(self.direction);
+        if speed.abs() < f32::EPSILON {
+            return Distance3D::between(self.origin, closest_point_on_plane(rhs, self.origin));
+        }
+        let p = self.origin - self.direction * (signed_plane_distance(rhs, self.origin) / speed);
+        Distance3D::between(p, p)
+    }
+}
+
+impl NeoDistance<NeoSurface> for Ray3D {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &NeoSurface) -> Self::Output {
+        if let SurfaceRay3DIntersection::Point(hit) = self.intersection(rhs) {
+            return Distance3D::between(hit.point, hit.point);
+        }
+        // an unbounded line is closest to one of the boundary edges, even if it is located in the
+        // plane of the surface
+        Distance3D::closest(
+            rhs.as_polygon_3d()
+                .iter_all_lines()
+                .map(|edge| edge.distance(self).swapped()),
+        )
+    }
+}
+
+mirror_distance!(Ray3D => AABB3D, CoordinateSystem, NeoSurface);
+
+#[cfg(test)]
+mod ray3d_distance {
+    use glam::Vec3;
+    use neo_aabb::d3::def::AABB3D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn skew_rays_distance() {
+        let a = Ray3D::new(Vec3::ZERO, Vec3::X);
+        let b = Ray3D::new(Vec3::new(5.0, 7.0, 3.0), Vec3::Y);
+        let dist = a.distance(&b);
+        assert!((dist.distance - 3.0).abs() < 0.000_1);
+        assert!(dist.lhs_point.abs_diff_eq(Vec3::X * 5.0, 0.000_1));
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(5.0, 0.0, 3.0), 0.000_1));
+    }
+
+    #[test]
+    fn ray_aabb_distance() {
+        let aabb = AABB3D::new(Vec3::ZERO, Vec3::ONE);
+        let hitting = Ray3D::new(Vec3::new(0.5, 0.5, 10.0), Vec3::Z);
+        assert_eq!(hitting.distance(&aabb).distance, 0.0);
+
+        let passing = Ray3D::new(Vec3::new(0.5, 3.0, 10.0), Vec3::Z);
+        let dist = passing.distance(&aabb);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!((dist.rhs_point.y - 1.0).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn ray_coord_sys_distance() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        let parallel = Ray3D::new(Vec3::Z, Vec3::X);
+        assert!((parallel.distance(&c).distance - 1.0).abs() < 0.000_1);
+        let skewed = Ray3D::new(Vec3::Z, Vec3::ONE);
+        let dist = skewed.distance(&c);
+        assert_eq!(dist.distance, 0.0);
+        assert!(dist
+            .lhs_point
+            .abs_diff_eq(Vec3::new(-1.0, -1.0, 0.0), 0.000_1));
+    }
+
+    #[test]
+    fn ray_surface_distance() {
+        let surface = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+
+        let hitting = Ray3D::new(Vec3::new(0.5, 0.5, 1.0), Vec3::NEG_Z);
+        assert_eq!(hitting.distance(&surface).distance, 0.0);
+
+        let missing = Ray3D::new(Vec3::new(3.0, 0.5, 1.0), Vec3::NEG_Z);
+        let dist = missing.distance(&surface);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+        assert!(dist
+            .rhs_point
+            .abs_diff_eq(Vec3::new(1.0, 0.5, 0.0), 0.000_1));
+    }
+}
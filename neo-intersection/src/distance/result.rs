@@ -0,0 +1,99 @@
+use glam::{Vec2, Vec3};
+
+use crate::float_ord_cmp;
+
+/// minimum distance between two 2D geometries. `lhs_point` is located on the left hand side
+/// operand, `rhs_point` on the right hand side operand. Intersecting geometries have a distance of
+/// zero and both points are located in the intersection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance2D {
+    pub distance: f32,
+    pub lhs_point: Vec2,
+    pub rhs_point: Vec2,
+}
+
+impl Distance2D {
+    /// distance of geometries without any points, they are infinitely far apart
+    pub const INFINITE: Self = Self {
+        distance: f32::INFINITY,
+        lhs_point: Vec2::NAN,
+        rhs_point: Vec2::NAN,
+    };
+
+    pub fn between(lhs_point: Vec2, rhs_point: Vec2) -> Self {
+        Self {
+            distance: lhs_point.distance(rhs_point),
+            lhs_point,
+            rhs_point,
+        }
+    }
+
+    /// the same distance seen from the right hand side operand
+    pub fn swapped(self) -> Self {
+        Self {
+            lhs_point: self.rhs_point,
+            rhs_point: self.lhs_point,
+            ..self
+        }
+    }
+
+    /// whether the geometries are closer than `clearance` to each other
+    pub fn is_within(&self, clearance: f32) -> bool {
+        self.distance < clearance
+    }
+
+    pub(crate) fn closest(candidates: impl IntoIterator<Item = Self>) -> Self {
+        candidates
+            .into_iter()
+            .min_by(|a, b| float_ord_cmp(&a.distance, &b.distance))
+            .unwrap_or(Self::INFINITE)
+    }
+}
+
+/// minimum distance between two 3D geometries. `lhs_point` is located on the left hand side
+/// operand, `rhs_point` on the right hand side operand. Intersecting geometries have a distance of
+/// zero and both points are located in the intersection.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance3D {
+    pub distance: f32,
+    pub lhs_point: Vec3,
+    pub rhs_point: Vec3,
+}
+
+impl Distance3D {
+    /// distance of geometries without any points, they are infinitely far apart
+    pub const INFINITE: Self = Self {
+        distance: f32::INFINITY,
+        lhs_point: Vec3::NAN,
+        rhs_point: Vec3::NAN,
+    };
+
+    pub fn between(lhs_point: Vec3, rhs_point: Vec3) -> Self {
+        Self {
+            distance: lhs_point.distance(rhs_point),
+            lhs_point,
+            rhs_point,
+        }
+    }
+
+    /// the same distance seen from the right hand side operand
+    pub fn swapped(self) -> Self {
+        Self {
+            lhs_point: self.rhs_point,
+            rhs_point: self.lhs_point,
+            ..self
+        }
+    }
+
+    /// whether the geometries are closer than `clearance` to each other
+    pub fn is_within(&self, clearance: f32) -> bool {
+        self.distance < clearance
+    }
+
+    pub(crate) fn closest(candidates: impl IntoIterator<Item = Self>) -> Self {
+        candidates
+            .into_iter()
+            .min_by(|a, b| float_ord_cmp(&a.distance, &b.distance))
+            .unwrap_or(Self::INFINITE)
+    }
+}
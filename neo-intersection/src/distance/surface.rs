@@ -0,0 +1,57 @@
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::result::Distance3D;
+use crate::trait_def::NeoDistance;
+
+impl NeoDistance for NeoSurface {
+    type Output = Distance3D;
+
+    fn distance(&self, rhs: &Self) -> Self::Output {
+        // the closest points of two planar polygons are located on an edge of one of them. This
+        // also finds intersections since one of the boundaries has to cut the other surface then
+        let lhs_edges = self
+            .as_polygon_3d()
+            .iter_all_lines()
+            .map(|edge| edge.distance(rhs))
+            .collect::<Vec<_>>();
+        let rhs_edges = rhs
+            .as_polygon_3d()
+            .iter_all_lines()
+            .map(|edge| edge.distance(self).swapped())
+            .collect::<Vec<_>>();
+        Distance3D::closest(lhs_edges.into_iter().chain(rhs_edges))
+    }
+}
+
+#[cfg(test)]
+mod surface_distance {
+    use glam::Vec3;
+
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::NeoDistance;
+
+    #[test]
+    fn parallel_surfaces_distance() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let b = unit_square(Vec3::new(0.5, 0.5, 2.0), Vec3::X, Vec3::Y);
+        let dist = a.distance(&b);
+        assert!((dist.distance - 2.0).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn perpendicular_surfaces_distance() {
+        let floor = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let wall = unit_square(Vec3::new(0.5, 0.5, 0.25), Vec3::X, Vec3::Z);
+        let dist = floor.distance(&wall);
+        assert!((dist.distance - 0.25).abs() < 0.000_1);
+        assert!((dist.lhs_point.z).abs() < 0.000_1);
+        assert!((dist.rhs_point.z - 0.25).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn crossing_surfaces_have_zero_distance() {
+        let floor = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let wall = unit_square(Vec3::new(0.0, 0.5, -0.5), Vec3::X, Vec3::Z);
+        assert_eq!(floor.distance(&wall).distance, 0.0);
+    }
+}
@@ -0,0 +1,171 @@
+use glam::{Vec2, Vec3};
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::trait_def::NeoIntersectionResult;
+
+/// dimension of a non empty intersection, ordered from points to surfaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IntersectionDimension {
+    Point,
+    Line,
+    Surface,
+}
+
+/// any intersection result converted into plain lists of geometry, see
+/// [`NeoIntersectionResult::into_set`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntersectionSet<P, S, A> {
+    /// dimension of the original result. It's kept, since unbounded results don't list their
+    /// geometry
+    pub dimension: Option<IntersectionDimension>,
+    pub points: Vec<P>,
+    pub segments: Vec<S>,
+    pub surfaces: Vec<A>,
+}
+
+/// surfaces of 2D intersections are polygons in the plane of the inputs
+pub type IntersectionSet2D = IntersectionSet<Vec2, LineSegment2D, geo::Polygon<f32>>;
+pub type IntersectionSet3D = IntersectionSet<Vec3, LineSegment3D, NeoSurface>;
+
+impl<P: Clone, S: Clone, A: Clone> NeoIntersectionResult for IntersectionSet<P, S, A> {
+    type Point = P;
+    type Segment = S;
+    type Surface = A;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        self.dimension
+    }
+
+    fn points(&self) -> Vec<P> {
+        self.points.clone()
+    }
+
+    fn segments(&self) -> Vec<S> {
+        self.segments.clone()
+    }
+
+    fn surfaces(&self) -> Vec<A> {
+        self.surfaces.clone()
+    }
+
+    fn into_set(self) -> Self {
+        self
+    }
+}
+
+/// a list of parts has the highest dimension of its parts
+impl<T: NeoIntersectionResult> NeoIntersectionResult for Vec<T> {
+    type Point = T::Point;
+    type Segment = T::Segment;
+    type Surface = T::Surface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        self.iter().filter_map(T::dimension).max()
+    }
+
+    fn points(&self) -> Vec<T::Point> {
+        self.iter().flat_map(T::points).collect()
+    }
+
+    fn segments(&self) -> Vec<T::Segment> {
+        self.iter().flat_map(T::segments).collect()
+    }
+
+    fn surfaces(&self) -> Vec<T::Surface> {
+        self.iter().flat_map(T::surfaces).collect()
+    }
+}
+
+#[cfg(test)]
+mod result_sets {
+    use glam::{Vec2, Vec3};
+    use neo_aabb::d2::def::AABB2D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_ray::d2::def::Ray2D;
+    use neo_surface::triangle3d::def::Triangle3D;
+
+    use crate::intersection_set::{IntersectionDimension, IntersectionSet2D, IntersectionSet3D};
+    use crate::line_intersection_parts::Line2DIntersectionParts;
+    use crate::trait_def::{NeoIntersectable, NeoIntersectionResult};
+
+    /// generic consumer which only knows the common trait
+    fn describe<R: NeoIntersectionResult>(
+        result: R,
+    ) -> (Option<IntersectionDimension>, [usize; 3]) {
+        let counts = [
+            result.points().len(),
+            result.segments().len(),
+            result.surfaces().len(),
+        ];
+        (result.dimension(), counts)
+    }
+
+    #[test]
+    fn different_results_are_consumed_uniformly() {
+        let l1 = LineSegment2D::new(Vec2::ZERO, Vec2::ONE);
+        let l2 = LineSegment2D::new(Vec2::X, Vec2::Y);
+        assert_eq!(
+            describe(l1.intersection(&l2)),
+            (Some(IntersectionDimension::Point), [1, 0, 0])
+        );
+
+        let ray = Ray2D::new(Vec2::new(-1.0, 0.5), Vec2::X);
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(
+            describe(ray.intersection(&aabb)),
+            (Some(IntersectionDimension::Line), [0, 1, 0])
+        );
+
+        let floor = Triangle3D::new(Vec3::ZERO, Vec3::X * 4.0, Vec3::Y * 4.0);
+        let shifted = Triangle3D::new(Vec3::new(1.0, 1.0, 0.0), Vec3::X * 5.0, Vec3::Y * 5.0);
+        assert_eq!(
+            describe(floor.intersection(&shifted)),
+            (Some(IntersectionDimension::Surface), [0, 0, 1])
+        );
+
+        let far = LineSegment3D::new(Vec3::Z, Vec3::Z + Vec3::X);
+        let near = LineSegment3D::new(Vec3::ZERO, Vec3::Y);
+        let result = far.intersection(&near);
+        assert!(result.is_empty());
+        assert_eq!(describe(result), (None, [0, 0, 0]));
+    }
+
+    #[test]
+    fn unbounded_results_only_report_their_dimension() {
+        let p1 = Plane::from_local_axis(Vec3::X, Vec3::Y);
+        let p2 = Plane::from_local_axis(Vec3::X, Vec3::Z);
+        let set = p1.intersection(&p2).into_set();
+        assert_eq!(set.dimension, Some(IntersectionDimension::Line));
+        assert!(set.segments.is_empty());
+
+        let c = CoordinateSystem::from_origin_and_plane(Vec3::ONE, p1);
+        let set = c.intersection(&c).into_set();
+        assert_eq!(set.dimension, Some(IntersectionDimension::Surface));
+        assert!(!set.is_empty());
+    }
+
+    #[test]
+    fn parts_have_the_highest_dimension() {
+        let parts = vec![
+            Line2DIntersectionParts::Point(Vec2::ZERO),
+            Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X, Vec2::ONE)),
+        ];
+        let set: IntersectionSet2D = parts.into_set();
+        assert_eq!(set.dimension, Some(IntersectionDimension::Line));
+        assert_eq!(set.points, vec![Vec2::ZERO]);
+        assert_eq!(set.segments.len(), 1);
+
+        let empty: IntersectionSet3D = IntersectionSet3D {
+            dimension: None,
+            points: vec![],
+            segments: vec![],
+            surfaces: vec![],
+        };
+        assert!(empty.clone().into_set().is_empty());
+    }
+}
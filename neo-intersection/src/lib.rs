@@ -1,13 +1,28 @@
+pub mod batch;
+pub mod bvh;
 pub(crate) mod coord_sys;
+pub mod culling;
+pub(crate) mod distance;
+pub mod intersection_set;
 pub(crate) mod line2d;
 pub(crate) mod line3d;
 pub mod line_intersection_parts;
+pub mod optics;
 pub(crate) mod plane;
+pub(crate) mod polygon2d;
 pub(crate) mod ray2d;
 pub(crate) mod ray3d;
+pub(crate) mod ray_hit;
 pub mod results;
 pub(crate) mod surface;
+#[cfg(test)]
+pub(crate) mod test_surfaces;
+pub(crate) mod time_of_impact;
 pub mod trait_def;
+pub(crate) mod triangle3d;
+pub(crate) mod validation;
+
+pub use neo_error::NeoGeometryError;
 
 pub(crate) fn float_ord_cmp(f1: &f32, f2: &f32) -> std::cmp::Ordering {
     f1.partial_cmp(f2).unwrap_or(std::cmp::Ordering::Less)
@@ -2,8 +2,9 @@ use glam::Vec2;
 use neo_bounded::traits::NeoBounded2D;
 use neo_line_segment::d2::def::LineSegment2D;
 
+use crate::intersection_set::IntersectionDimension;
 use crate::ray2d::ray::RayRay2DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineLine2DIntersection {
@@ -26,6 +27,36 @@ impl LineLine2DIntersection {
     }
 }
 
+impl NeoIntersectionResult for LineLine2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            LineLine2DIntersection::None
+            | LineLine2DIntersection::Parallel
+            | LineLine2DIntersection::CollinearNoOverlap => None,
+            LineLine2DIntersection::CollinearOverlap(_) => Some(IntersectionDimension::Line),
+            LineLine2DIntersection::Intersection(_) => Some(IntersectionDimension::Point),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            LineLine2DIntersection::Intersection(p) => vec![*p],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            LineLine2DIntersection::CollinearOverlap(overlap) => vec![overlap.overlap()],
+            _ => vec![],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Line2DOverlap {
     before: LineSegment2D,
@@ -57,6 +88,26 @@ impl NeoIntersectable for LineSegment2D {
     }
 }
 
+/// segments whose boxes are disjoint never intersect, collinear ones only compare the scalars of
+/// the end points instead of building the overlap
+impl NeoIntersects for LineSegment2D {
+    fn intersects(&self, rhs: &Self) -> bool {
+        if !self.aabb().intersects(&rhs.aabb()) {
+            return false;
+        }
+        match self.ray().intersection(&rhs.ray()) {
+            RayRay2DIntersection::Parallel => false,
+            RayRay2DIntersection::Collinear => rhs
+                .array()
+                .into_iter()
+                .any(|v| (0.0..=1.0).contains(&self.scalar_of(v))),
+            RayRay2DIntersection::Intersection(hit) => {
+                self.is_point_on_line(hit.point) && rhs.is_point_on_line(hit.point)
+            }
+        }
+    }
+}
+
 pub(crate) fn classify_aabbs_intersecting(
     l1: &LineSegment2D,
     l2: &LineSegment2D,
@@ -65,8 +116,8 @@ pub(crate) fn classify_aabbs_intersecting(
     match inter {
         RayRay2DIntersection::Parallel => LineLine2DIntersection::Parallel,
         RayRay2DIntersection::Collinear => classify_collinear_overlap(l1, l2),
-        RayRay2DIntersection::Intersection(intersection_point) => {
-            classify_intersecting_relation_to(l1, l2, intersection_point)
+        RayRay2DIntersection::Intersection(hit) => {
+            classify_intersecting_relation_to(l1, l2, hit.point)
         }
     }
 }
@@ -0,0 +1,118 @@
+use geo::LinesIter;
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::line_intersection_parts::{sort_parts_along, Line2DIntersectionParts};
+use crate::results::LineLine2DIntersection;
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<geo::LineString<f32>> for LineSegment2D {
+    type Output = Vec<Line2DIntersectionParts>;
+
+    fn intersection(&self, rhs: &geo::LineString<f32>) -> Self::Output {
+        let parts = rhs
+            .lines_iter()
+            .filter_map(|l| match self.intersection(&LineSegment2D::from(l)) {
+                LineLine2DIntersection::Intersection(p) => Some(Line2DIntersectionParts::Point(p)),
+                LineLine2DIntersection::CollinearOverlap(overlap) => {
+                    Some(Line2DIntersectionParts::Line(overlap.overlap()))
+                }
+                _ => None,
+            })
+            .collect();
+        sort_parts_along(parts, self.src, self.direction())
+    }
+}
+
+impl NeoIntersectable<geo::MultiLineString<f32>> for LineSegment2D {
+    type Output = Vec<Line2DIntersectionParts>;
+
+    fn intersection(&self, rhs: &geo::MultiLineString<f32>) -> Self::Output {
+        let parts = rhs.iter().flat_map(|ls| self.intersection(ls)).collect();
+        sort_parts_along(parts, self.src, self.direction())
+    }
+}
+
+impl NeoIntersects<geo::LineString<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::LineString<f32>) -> bool {
+        rhs.lines_iter().any(|l| {
+            matches!(
+                self.intersection(&LineSegment2D::from(l)),
+                LineLine2DIntersection::Intersection(_)
+                    | LineLine2DIntersection::CollinearOverlap(_)
+            )
+        })
+    }
+}
+
+impl NeoIntersects<geo::MultiLineString<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::MultiLineString<f32>) -> bool {
+        rhs.iter().any(|ls| self.intersects(ls))
+    }
+}
+
+mirror_intersectable!(LineSegment2D => geo::LineString<f32>, geo::MultiLineString<f32>);
+
+#[cfg(test)]
+mod line_linestring {
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::line_intersection_parts::Line2DIntersectionParts;
+    use crate::trait_def::NeoIntersectable;
+
+    fn zigzag() -> geo::LineString<f32> {
+        geo::LineString::from(vec![(0.0, -1.0), (1.0, 1.0), (2.0, -1.0), (3.0, 1.0)])
+    }
+
+    #[test]
+    fn crossing_points_are_sorted() {
+        let line = LineSegment2D::new(Vec2::X * 3.0, Vec2::ZERO);
+        assert_eq!(
+            line.intersection(&zigzag()),
+            vec![
+                Line2DIntersectionParts::Point(Vec2::X * 2.5),
+                Line2DIntersectionParts::Point(Vec2::X * 1.5),
+                Line2DIntersectionParts::Point(Vec2::X * 0.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn shared_vertex_is_reported_once() {
+        let line = LineSegment2D::new(Vec2::new(0.0, 1.0), Vec2::new(2.0, 1.0));
+        assert_eq!(
+            line.intersection(&zigzag()),
+            vec![Line2DIntersectionParts::Point(Vec2::ONE)]
+        );
+    }
+
+    #[test]
+    fn collinear_segments_are_merged() {
+        let linestring =
+            geo::LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 1.0)]);
+        let line = LineSegment2D::new(Vec2::new(-1.0, 0.0), Vec2::new(1.5, 0.0));
+        assert_eq!(
+            line.intersection(&linestring),
+            vec![Line2DIntersectionParts::Line(LineSegment2D::new(
+                Vec2::ZERO,
+                Vec2::X * 1.5
+            ))]
+        );
+    }
+
+    #[test]
+    fn multi_linestring_works() {
+        let lines = geo::MultiLineString::new(vec![
+            geo::LineString::from(vec![(2.0, -1.0), (2.0, 1.0)]),
+            geo::LineString::from(vec![(1.0, -1.0), (1.0, 1.0)]),
+        ]);
+        let line = LineSegment2D::new(Vec2::ZERO, Vec2::X * 3.0);
+        assert_eq!(
+            line.intersection(&lines),
+            vec![
+                Line2DIntersectionParts::Point(Vec2::X),
+                Line2DIntersectionParts::Point(Vec2::X * 2.0),
+            ]
+        );
+    }
+}
@@ -1,3 +1,4 @@
 pub mod line;
+pub mod linestring;
+pub mod multipolygon;
 pub mod polygon;
-pub mod ray;
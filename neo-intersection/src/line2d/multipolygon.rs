@@ -0,0 +1,137 @@
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::line_intersection_parts::{sort_parts_along, Line2DIntersectionParts};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<geo::MultiPolygon<f32>> for LineSegment2D {
+    type Output = Vec<Line2DIntersectionParts>;
+
+    fn intersection(&self, rhs: &geo::MultiPolygon<f32>) -> Self::Output {
+        let parts = rhs
+            .iter()
+            .flat_map(|polygon| self.intersection(polygon).list_parts())
+            .collect();
+        sort_parts_along(parts, self.src, self.direction())
+    }
+}
+
+impl NeoIntersectable<geo::Rect<f32>> for LineSegment2D {
+    type Output = Vec<Line2DIntersectionParts>;
+
+    fn intersection(&self, rhs: &geo::Rect<f32>) -> Self::Output {
+        let parts = self.intersection(&rhs.to_polygon()).list_parts();
+        sort_parts_along(parts, self.src, self.direction())
+    }
+}
+
+impl NeoIntersectable<geo::Triangle<f32>> for LineSegment2D {
+    type Output = Vec<Line2DIntersectionParts>;
+
+    fn intersection(&self, rhs: &geo::Triangle<f32>) -> Self::Output {
+        let parts = self.intersection(&rhs.to_polygon()).list_parts();
+        sort_parts_along(parts, self.src, self.direction())
+    }
+}
+
+impl NeoIntersects<geo::MultiPolygon<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::MultiPolygon<f32>) -> bool {
+        rhs.iter().any(|polygon| self.intersects(polygon))
+    }
+}
+
+impl NeoIntersects<geo::Rect<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::Rect<f32>) -> bool {
+        self.intersects(&rhs.to_polygon())
+    }
+}
+
+impl NeoIntersects<geo::Triangle<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::Triangle<f32>) -> bool {
+        self.intersects(&rhs.to_polygon())
+    }
+}
+
+mirror_intersectable!(LineSegment2D => geo::MultiPolygon<f32>, geo::Rect<f32>, geo::Triangle<f32>);
+
+#[cfg(test)]
+mod line_multipolygon {
+    use glam::Vec2;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::line_intersection_parts::Line2DIntersectionParts;
+    use crate::trait_def::NeoIntersectable;
+
+    fn unit_square_at(offset: Vec2) -> geo::Polygon<f32> {
+        geo::Rect::new(offset.to_geo(), (offset + Vec2::ONE).to_geo()).to_polygon()
+    }
+
+    #[test]
+    fn multipolygon_parts_are_sorted() {
+        let polygons = geo::MultiPolygon::new(vec![
+            unit_square_at(Vec2::X * 4.0),
+            unit_square_at(Vec2::ZERO),
+        ]);
+        let line = LineSegment2D::new(Vec2::new(-1.0, 0.5), Vec2::new(6.0, 0.5));
+        assert_eq!(
+            line.intersection(&polygons),
+            vec![
+                Line2DIntersectionParts::Line(LineSegment2D::new(
+                    Vec2::new(0.0, 0.5),
+                    Vec2::new(1.0, 0.5)
+                )),
+                Line2DIntersectionParts::Line(LineSegment2D::new(
+                    Vec2::new(4.0, 0.5),
+                    Vec2::new(5.0, 0.5)
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn touching_polygons_are_merged() {
+        let polygons =
+            geo::MultiPolygon::new(vec![unit_square_at(Vec2::ZERO), unit_square_at(Vec2::X)]);
+        let line = LineSegment2D::new(Vec2::new(2.5, 0.5), Vec2::new(-0.5, 0.5));
+        assert_eq!(
+            line.intersection(&polygons),
+            vec![Line2DIntersectionParts::Line(LineSegment2D::new(
+                Vec2::new(2.0, 0.5),
+                Vec2::new(0.0, 0.5)
+            ))]
+        );
+    }
+
+    #[test]
+    fn rect_works() {
+        let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo());
+        let line = LineSegment2D::new(Vec2::new(0.5, 2.0), Vec2::new(0.5, 0.5));
+        assert_eq!(
+            line.intersection(&rect),
+            vec![Line2DIntersectionParts::Line(LineSegment2D::new(
+                Vec2::new(0.5, 1.0),
+                Vec2::new(0.5, 0.5)
+            ))]
+        );
+    }
+
+    #[test]
+    fn triangle_works() {
+        let triangle = geo::Triangle::new(
+            Vec2::ZERO.to_geo(),
+            (Vec2::X * 2.0).to_geo(),
+            (Vec2::Y * 2.0).to_geo(),
+        );
+        let line = LineSegment2D::new(Vec2::new(0.0, 3.0), Vec2::new(3.0, 0.0));
+        assert_eq!(line.intersection(&triangle), vec![]);
+
+        let line = LineSegment2D::new(Vec2::new(2.0, 0.5), Vec2::new(-1.0, 0.5));
+        assert_eq!(
+            line.intersection(&triangle),
+            vec![Line2DIntersectionParts::Line(LineSegment2D::new(
+                Vec2::new(1.5, 0.5),
+                Vec2::new(0.0, 0.5)
+            ))]
+        );
+    }
+}
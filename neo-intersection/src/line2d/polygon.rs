@@ -4,8 +4,11 @@ use neo_geo_glam_interop::to_geo::ConvertToGeo;
 use neo_line_segment::d2::def::LineSegment2D;
 
 use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
 use crate::line_intersection_parts::Line2DIntersectionParts;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum LinePolygon2DIntersection {
@@ -15,6 +18,48 @@ pub enum LinePolygon2DIntersection {
     Parts(Vec<Line2DIntersectionParts>),
 }
 
+impl LinePolygon2DIntersection {
+    pub fn list_parts(self) -> Vec<Line2DIntersectionParts> {
+        match self {
+            LinePolygon2DIntersection::None => vec![],
+            LinePolygon2DIntersection::Point(p) => vec![Line2DIntersectionParts::Point(p)],
+            LinePolygon2DIntersection::Line(l) => vec![Line2DIntersectionParts::Line(l)],
+            LinePolygon2DIntersection::Parts(ps) => ps,
+        }
+    }
+}
+
+impl NeoIntersectionResult for LinePolygon2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            LinePolygon2DIntersection::None => None,
+            LinePolygon2DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            LinePolygon2DIntersection::Line(_) => Some(IntersectionDimension::Line),
+            LinePolygon2DIntersection::Parts(ps) => ps.dimension(),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            LinePolygon2DIntersection::Point(p) => vec![*p],
+            LinePolygon2DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            LinePolygon2DIntersection::Line(l) => vec![*l],
+            LinePolygon2DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable<geo::Polygon<f32>> for LineSegment2D {
     type Output = LinePolygon2DIntersection;
 
@@ -32,7 +77,7 @@ impl NeoIntersectable<geo::Polygon<f32>> for LineSegment2D {
                 [0.0_f32, 1.0]
                     .into_iter()
                     .zip(self.array().into_iter())
-                    .filter(|(_, c)| rhs.intersects(&c.to_geo())),
+                    .filter(|(_, c)| Intersects::intersects(rhs, &c.to_geo())),
             )
             .fold(vec![], |mut res, elem| {
                 if !res
@@ -90,6 +135,20 @@ impl NeoIntersectable<geo::Polygon<f32>> for LineSegment2D {
     }
 }
 
+impl NeoIntersects<geo::Polygon<f32>> for LineSegment2D {
+    fn intersects(&self, rhs: &geo::Polygon<f32>) -> bool {
+        self.array()
+            .into_iter()
+            .any(|c| Intersects::intersects(rhs, &c.to_geo()))
+            || rhs
+                .lines_iter()
+                .map(LineSegment2D::from)
+                .any(|l| self.intersection(&l).intersection_point().is_some())
+    }
+}
+
+mirror_intersectable!(LineSegment2D => geo::Polygon<f32>);
+
 #[cfg(test)]
 mod line_polygon {
     use glam::Vec2;
@@ -0,0 +1,178 @@
+use glam::Vec3;
+use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::constants::NEO_LINE_SEGMENT_3D_EPS;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_plane::Plane;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::point3d::signed_plane_distance;
+use crate::intersection_set::IntersectionDimension;
+use crate::results::RayCoordSys3DIntersection;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineCoordSys3DIntersection {
+    None,
+    Point(Vec3),
+    /// the whole line is located in the coordinate system
+    Line(LineSegment3D),
+}
+
+impl NeoIntersectionResult for LineCoordSys3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            LineCoordSys3DIntersection::None => None,
+            LineCoordSys3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            LineCoordSys3DIntersection::Line(_) => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            LineCoordSys3DIntersection::Point(p) => vec![*p],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            LineCoordSys3DIntersection::Line(l) => vec![*l],
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectable<CoordinateSystem> for LineSegment3D {
+    type Output = LineCoordSys3DIntersection;
+
+    fn intersection(&self, rhs: &CoordinateSystem) -> Self::Output {
+        if self.is_degenerated() {
+            return if rhs.is_point_in_coordinate_system(self.src) {
+                LineCoordSys3DIntersection::Point(self.src)
+            } else {
+                LineCoordSys3DIntersection::None
+            };
+        }
+        match self.ray().intersection(rhs) {
+            RayCoordSys3DIntersection::Parallel => LineCoordSys3DIntersection::None,
+            RayCoordSys3DIntersection::Point(hit) => {
+                if self.is_point_on_line(hit.point) {
+                    LineCoordSys3DIntersection::Point(hit.point)
+                } else {
+                    LineCoordSys3DIntersection::None
+                }
+            }
+            RayCoordSys3DIntersection::Ray(_) => LineCoordSys3DIntersection::Line(*self),
+        }
+    }
+}
+
+/// Planes pass through the origin, so this is the intersection with the coordinate system at the
+/// origin
+impl NeoIntersectable<Plane> for LineSegment3D {
+    type Output = LineCoordSys3DIntersection;
+
+    fn intersection(&self, rhs: &Plane) -> Self::Output {
+        let coord_sys_plane = CoordinateSystem::from_origin_and_plane(Vec3::ZERO, *rhs);
+        self.intersection(&coord_sys_plane)
+    }
+}
+
+/// the ends of the line lie on different sides of the plane or one of them lies in it
+impl NeoIntersects<CoordinateSystem> for LineSegment3D {
+    fn intersects(&self, rhs: &CoordinateSystem) -> bool {
+        let normal_length = rhs.plane.normal.length();
+        let [src, dst] = self
+            .array()
+            .map(|p| signed_plane_distance(rhs, p) / normal_length);
+        src * dst <= 0.0 || src.abs().min(dst.abs()) < NEO_LINE_SEGMENT_3D_EPS
+    }
+}
+
+impl NeoIntersects<Plane> for LineSegment3D {
+    fn intersects(&self, rhs: &Plane) -> bool {
+        let coord_sys_plane = CoordinateSystem::from_origin_and_plane(Vec3::ZERO, *rhs);
+        self.intersects(&coord_sys_plane)
+    }
+}
+
+mirror_intersectable!(LineSegment3D => CoordinateSystem, Plane);
+
+#[cfg(test)]
+mod line_coord_sys {
+    use glam::Vec3;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+
+    use crate::line3d::coord_sys::LineCoordSys3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    fn floor() -> CoordinateSystem {
+        CoordinateSystem::from_origin_and_normal(Vec3::Z, Vec3::Z)
+    }
+
+    #[test]
+    fn crossing_line_works() {
+        let line = LineSegment3D::new(Vec3::ZERO, Vec3::Z * 2.0 + Vec3::X * 2.0);
+        assert_eq!(
+            line.intersection(&floor()),
+            LineCoordSys3DIntersection::Point(Vec3::X + Vec3::Z)
+        );
+    }
+
+    #[test]
+    fn line_ending_before_coord_sys_works() {
+        let line = LineSegment3D::new(Vec3::ZERO, Vec3::Z * 0.5);
+        assert_eq!(
+            line.intersection(&floor()),
+            LineCoordSys3DIntersection::None
+        );
+        assert_eq!(
+            line.flip().intersection(&floor()),
+            LineCoordSys3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn endpoint_on_coord_sys_works() {
+        let line = LineSegment3D::new(Vec3::ZERO, Vec3::Z);
+        assert_eq!(
+            line.intersection(&floor()),
+            LineCoordSys3DIntersection::Point(Vec3::Z)
+        );
+    }
+
+    #[test]
+    fn parallel_line_works() {
+        let line = LineSegment3D::new(Vec3::ZERO, Vec3::X);
+        assert_eq!(
+            line.intersection(&floor()),
+            LineCoordSys3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn contained_line_works() {
+        let line = LineSegment3D::new(Vec3::Z, Vec3::Z + Vec3::ONE - Vec3::Z);
+        assert_eq!(
+            line.intersection(&floor()),
+            LineCoordSys3DIntersection::Line(line)
+        );
+    }
+
+    #[test]
+    fn plane_works() {
+        let line = LineSegment3D::new(-Vec3::ONE, Vec3::ONE);
+        assert_eq!(
+            line.intersection(&Plane::from_normal(Vec3::Y)),
+            LineCoordSys3DIntersection::Point(Vec3::ZERO)
+        );
+    }
+}
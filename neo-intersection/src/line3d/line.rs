@@ -1,9 +1,13 @@
 use glam::Vec3;
 use neo_bounded::traits::NeoBounded3D;
 use neo_line_segment::d3::def::LineSegment3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_surface::surface::def::NeoSurface;
 
-use crate::results::RayRay3DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray3d::ray::are_collinear;
+use crate::results::{RayRay3DApproach, RayRay3DIntersection};
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineLine3DIntersection {
@@ -14,6 +18,36 @@ pub enum LineLine3DIntersection {
     Intersection(Vec3),
 }
 
+impl NeoIntersectionResult for LineLine3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            LineLine3DIntersection::None
+            | LineLine3DIntersection::Parallel
+            | LineLine3DIntersection::CollinearNoOverlap => None,
+            LineLine3DIntersection::CollinearOverlap(_) => Some(IntersectionDimension::Line),
+            LineLine3DIntersection::Intersection(_) => Some(IntersectionDimension::Point),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            LineLine3DIntersection::Intersection(p) => vec![*p],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            LineLine3DIntersection::CollinearOverlap(overlap) => vec![overlap.overlap()],
+            _ => vec![],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Line3DOverlap {
     before: LineSegment3D,
@@ -41,6 +75,31 @@ impl NeoIntersectable for LineSegment3D {
     }
 }
 
+/// segments whose boxes are disjoint never intersect, collinear ones only compare the scalars of
+/// the end points instead of building the overlap
+impl NeoIntersects for LineSegment3D {
+    fn intersects(&self, rhs: &Self) -> bool {
+        if !self.aabb().intersects(&rhs.aabb()) {
+            return false;
+        }
+        let (lhs_ray, rhs_ray) = (self.ray(), rhs.ray());
+        match RayRay3DApproach::between(&lhs_ray, &rhs_ray, NEO_LINE_RAY_3D_EPS) {
+            Some(approach) => {
+                approach.gap <= NEO_LINE_RAY_3D_EPS
+                    && self.is_point_on_line(approach.lhs_point)
+                    && rhs.is_point_on_line(approach.lhs_point)
+            }
+            None => {
+                are_collinear(&lhs_ray, &rhs_ray, NEO_LINE_RAY_3D_EPS)
+                    && rhs
+                        .array()
+                        .into_iter()
+                        .any(|v| (0.0..=1.0).contains(&self.scalar_of(v)))
+            }
+        }
+    }
+}
+
 pub(crate) fn classify_aabbs_intersecting(
     l1: &LineSegment3D,
     l2: &LineSegment3D,
@@ -48,9 +107,7 @@ pub(crate) fn classify_aabbs_intersecting(
     match l1.ray().intersection(&l2.ray()) {
         RayRay3DIntersection::Parallel => LineLine3DIntersection::Parallel,
         RayRay3DIntersection::Collinear => classify_collinear_overlap(l1, l2),
-        RayRay3DIntersection::Intersection(intersection_point) => {
-            classify_intersection_point(l1, l2, intersection_point)
-        }
+        RayRay3DIntersection::Intersection(hit) => classify_intersection_point(l1, l2, hit.point),
         RayRay3DIntersection::Skewed => LineLine3DIntersection::None,
     }
 }
@@ -1 +1,3 @@
+pub mod coord_sys;
 pub mod line;
+pub mod surface;
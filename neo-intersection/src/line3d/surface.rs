@@ -0,0 +1,217 @@
+use glam::Vec3;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::polygon3d::def::NeoPolygon3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::intersection_set::IntersectionDimension;
+use crate::line2d::polygon::LinePolygon2DIntersection;
+use crate::line3d::coord_sys::LineCoordSys3DIntersection;
+use crate::line_intersection_parts::Line3DIntersectionParts;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineSurface3DIntersection {
+    None,
+    Point(Vec3),
+    /// the line is located in the plane of the surface, these are the parts of the line inside the
+    /// surface sorted from `src` to `dst`
+    Parts(Vec<Line3DIntersectionParts>),
+}
+
+impl NeoIntersectionResult for LineSurface3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            LineSurface3DIntersection::None => None,
+            LineSurface3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            LineSurface3DIntersection::Parts(ps) => ps.dimension(),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            LineSurface3DIntersection::None => vec![],
+            LineSurface3DIntersection::Point(p) => vec![*p],
+            LineSurface3DIntersection::Parts(ps) => ps.points(),
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            LineSurface3DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectable<NeoSurface> for LineSegment3D {
+    type Output = LineSurface3DIntersection;
+
+    fn intersection(&self, rhs: &NeoSurface) -> Self::Output {
+        match self.intersection(&rhs.coordinate_system) {
+            LineCoordSys3DIntersection::None => LineSurface3DIntersection::None,
+            LineCoordSys3DIntersection::Point(p) => {
+                if rhs.is_point_in_surface(p) {
+                    LineSurface3DIntersection::Point(p)
+                } else {
+                    LineSurface3DIntersection::None
+                }
+            }
+            LineCoordSys3DIntersection::Line(line) => contained_line_case_analysis(rhs, &line),
+        }
+    }
+}
+
+impl NeoIntersectable<NeoPolygon3D> for LineSegment3D {
+    type Output = LineSurface3DIntersection;
+
+    fn intersection(&self, rhs: &NeoPolygon3D) -> Self::Output {
+        self.intersection(&NeoSurface::from_polygon_3d(rhs.clone()))
+    }
+}
+
+impl NeoIntersects<NeoSurface> for LineSegment3D {
+    fn intersects(&self, rhs: &NeoSurface) -> bool {
+        match self.intersection(&rhs.coordinate_system) {
+            LineCoordSys3DIntersection::None => false,
+            LineCoordSys3DIntersection::Point(p) => rhs.is_point_in_surface(p),
+            LineCoordSys3DIntersection::Line(line) => {
+                let [src, dst] = line.array().map(|p| rhs.project_point_xy(p).to_glam());
+                LineSegment2D::new(src, dst).intersects(&rhs.shape)
+            }
+        }
+    }
+}
+
+impl NeoIntersects<NeoPolygon3D> for LineSegment3D {
+    fn intersects(&self, rhs: &NeoPolygon3D) -> bool {
+        self.intersects(&NeoSurface::from_polygon_3d(rhs.clone()))
+    }
+}
+
+mirror_intersectable!(LineSegment3D => NeoSurface, NeoPolygon3D);
+
+pub(crate) fn contained_line_case_analysis(
+    surface: &NeoSurface,
+    line: &LineSegment3D,
+) -> LineSurface3DIntersection {
+    let [src, dst] = line.array().map(|p| surface.project_point_xy(p).to_glam());
+    let injection_func = surface.injection_function();
+    match LineSegment2D::new(src, dst).intersection(&surface.shape) {
+        LinePolygon2DIntersection::None => LineSurface3DIntersection::None,
+        LinePolygon2DIntersection::Point(p) => LineSurface3DIntersection::Point(injection_func(p)),
+        LinePolygon2DIntersection::Line(l) => {
+            LineSurface3DIntersection::Parts(vec![Line3DIntersectionParts::Line(
+                LineSegment3D::new(injection_func(l.src), injection_func(l.dst)),
+            )])
+        }
+        LinePolygon2DIntersection::Parts(parts) => LineSurface3DIntersection::Parts(
+            parts
+                .into_iter()
+                .map(|part| part.inject_with(&injection_func))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod line_surface {
+    use glam::{Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::line3d::surface::LineSurface3DIntersection;
+    use crate::line_intersection_parts::Line3DIntersectionParts;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    /// 4x4 floor at height 1 with a 2x2 hole in the middle, far away from the world origin
+    fn floor_with_hole() -> NeoSurface {
+        let offset = Vec3::new(100.0, -50.0, 1.0);
+        let ext = geo::Rect::new(Vec2::ZERO.to_geo(), (Vec2::ONE * 4.0).to_geo())
+            .to_polygon()
+            .exterior()
+            .clone();
+        let int = geo::Rect::new(Vec2::ONE.to_geo(), (Vec2::ONE * 3.0).to_geo())
+            .to_polygon()
+            .exterior()
+            .clone();
+        let c = CoordinateSystem::from_origin_and_plane(
+            offset,
+            Plane::from_local_axis(Vec3::X, Vec3::Y),
+        );
+        NeoSurface::new(c, geo::Coord::zero(), geo::Polygon::new(ext, vec![int]))
+    }
+
+    fn at(x: f32, y: f32, z: f32) -> Vec3 {
+        Vec3::new(100.0 + x, -50.0 + y, z)
+    }
+
+    #[test]
+    fn crossing_line_works() {
+        let line = LineSegment3D::new(at(0.5, 0.5, 0.0), at(0.5, 0.5, 2.0));
+        let LineSurface3DIntersection::Point(p) = line.intersection(&floor_with_hole()) else {
+            panic!("expected the line to cross the floor");
+        };
+        assert!(p.abs_diff_eq(at(0.5, 0.5, 1.0), EPS));
+    }
+
+    #[test]
+    fn crossing_line_through_hole_works() {
+        let line = LineSegment3D::new(at(2.0, 2.0, 0.0), at(2.0, 2.0, 2.0));
+        assert_eq!(
+            line.intersection(&floor_with_hole()),
+            LineSurface3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn line_ending_above_surface_works() {
+        let line = LineSegment3D::new(at(0.5, 0.5, 1.5), at(0.5, 0.5, 2.0));
+        assert_eq!(
+            line.intersection(&floor_with_hole()),
+            LineSurface3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn contained_line_works() {
+        let line = LineSegment3D::new(at(-1.0, 2.0, 1.0), at(5.0, 2.0, 1.0));
+        let LineSurface3DIntersection::Parts(parts) = line.intersection(&floor_with_hole()) else {
+            panic!("expected the line to lie in the floor");
+        };
+        let expected = [
+            (at(0.0, 2.0, 1.0), at(1.0, 2.0, 1.0)),
+            (at(3.0, 2.0, 1.0), at(4.0, 2.0, 1.0)),
+        ];
+        assert_eq!(parts.len(), expected.len());
+        parts.iter().zip(expected).for_each(|(part, (src, dst))| {
+            let Line3DIntersectionParts::Line(l) = part else {
+                panic!("expected {part:?} to be a line");
+            };
+            assert!(l.src.abs_diff_eq(src, EPS), "{l:?}");
+            assert!(l.dst.abs_diff_eq(dst, EPS), "{l:?}");
+        });
+    }
+
+    #[test]
+    fn polygon_works() {
+        let polygon = floor_with_hole().as_polygon_3d();
+        let line = LineSegment3D::new(at(3.5, 0.5, 3.0), at(3.5, 0.5, -3.0));
+        let LineSurface3DIntersection::Point(p) = line.intersection(&polygon) else {
+            panic!("expected the line to cross the polygon");
+        };
+        assert!(p.abs_diff_eq(at(3.5, 0.5, 1.0), EPS));
+    }
+}
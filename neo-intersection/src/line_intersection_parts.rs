@@ -1,6 +1,11 @@
 use glam::{Vec2, Vec3};
 use neo_line_segment::d2::def::LineSegment2D;
 use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
+use crate::trait_def::NeoIntersectionResult;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Line3DIntersectionParts {
@@ -8,6 +13,33 @@ pub enum Line3DIntersectionParts {
     Line(LineSegment3D),
 }
 
+impl NeoIntersectionResult for Line3DIntersectionParts {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            Line3DIntersectionParts::Point(_) => Some(IntersectionDimension::Point),
+            Line3DIntersectionParts::Line(_) => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            Line3DIntersectionParts::Point(p) => vec![*p],
+            Line3DIntersectionParts::Line(_) => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            Line3DIntersectionParts::Point(_) => vec![],
+            Line3DIntersectionParts::Line(l) => vec![*l],
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Line2DIntersectionParts {
     Point(Vec2),
@@ -27,3 +59,126 @@ impl Line2DIntersectionParts {
         }
     }
 }
+
+impl NeoIntersectionResult for Line2DIntersectionParts {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            Line2DIntersectionParts::Point(_) => Some(IntersectionDimension::Point),
+            Line2DIntersectionParts::Line(_) => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            Line2DIntersectionParts::Point(p) => vec![*p],
+            Line2DIntersectionParts::Line(_) => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            Line2DIntersectionParts::Point(_) => vec![],
+            Line2DIntersectionParts::Line(l) => vec![*l],
+        }
+    }
+}
+
+/// Sorts the parts along the `direction` starting at `origin`. Lines are flipped to point in the
+/// same direction, points on lines are dropped and touching or overlapping lines are merged.
+///
+/// A zero direction, e.g. of a degenerate query segment, has no order. The parts are only
+/// deduplicated then
+pub(crate) fn sort_parts_along(
+    parts: Vec<Line2DIntersectionParts>,
+    origin: Vec2,
+    direction: Vec2,
+) -> Vec<Line2DIntersectionParts> {
+    if direction.length_squared() == 0.0 {
+        return parts.into_iter().fold(vec![], |mut unique, part| {
+            if !unique.contains(&part) {
+                unique.push(part);
+            }
+            unique
+        });
+    }
+    let scalar_of = |p: Vec2| (p - origin).dot(direction) / direction.length_squared();
+    let eps = 0.000_1 / direction.length();
+
+    let mut ranges = parts
+        .into_iter()
+        .map(|part| match part {
+            Line2DIntersectionParts::Point(p) => (scalar_of(p), p, scalar_of(p), p),
+            Line2DIntersectionParts::Line(l) => {
+                let [a, b] = [scalar_of(l.src), scalar_of(l.dst)];
+                if a <= b {
+                    (a, l.src, b, l.dst)
+                } else {
+                    (b, l.dst, a, l.src)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    ranges.sort_by(|a, b| float_ord_cmp(&a.0, &b.0));
+
+    let mut merged: Vec<(f32, Vec2, f32, Vec2)> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.0 <= last.2 + eps => {
+                if range.2 > last.2 {
+                    last.2 = range.2;
+                    last.3 = range.3;
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    merged
+        .into_iter()
+        .map(|(start, src, end, dst)| {
+            if end - start <= eps {
+                Line2DIntersectionParts::Point(src)
+            } else {
+                Line2DIntersectionParts::Line(LineSegment2D::new(src, dst))
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn sort_parts_along_works() {
+    let parts = vec![
+        Line2DIntersectionParts::Point(Vec2::X * 3.0),
+        Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X * 2.0, Vec2::X)),
+        Line2DIntersectionParts::Point(Vec2::X * 1.5),
+        Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X * 2.0, Vec2::X * 2.5)),
+        Line2DIntersectionParts::Point(Vec2::X * 3.0),
+    ];
+    assert_eq!(
+        sort_parts_along(parts, Vec2::ZERO, Vec2::X),
+        vec![
+            Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X, Vec2::X * 2.5)),
+            Line2DIntersectionParts::Point(Vec2::X * 3.0),
+        ]
+    );
+}
+
+#[test]
+fn sort_parts_along_zero_direction_deduplicates() {
+    let parts = vec![
+        Line2DIntersectionParts::Point(Vec2::ONE),
+        Line2DIntersectionParts::Point(Vec2::ONE),
+        Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X, Vec2::Y)),
+    ];
+    assert_eq!(
+        sort_parts_along(parts, Vec2::ONE, Vec2::ZERO),
+        vec![
+            Line2DIntersectionParts::Point(Vec2::ONE),
+            Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X, Vec2::Y)),
+        ]
+    );
+}
@@ -0,0 +1,195 @@
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::polyline3d::def::Polyline3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::float_ord_cmp;
+use crate::ray_hit::RayHit3D;
+use crate::results::SurfaceRay3DIntersection;
+use crate::trait_def::NeoIntersectable;
+
+/// Mirrors the ray at the hit. The reflected ray starts at the hit point and keeps the length of
+/// the direction vector
+pub fn reflect(ray: &Ray3D, hit: &RayHit3D) -> Ray3D {
+    let normal = hit.normal.normalize();
+    let direction = ray.direction - 2.0 * ray.direction.dot(normal) * normal;
+    Ray3D::new(hit.point, direction)
+}
+
+/// Bends the ray at the hit following Snell's law. `eta` is the ratio of the refractive index on
+/// the front side of the hit shape to the one on its back side, e.g. `1.0 / 1.5` for a surface
+/// facing from air into glass. Rays coming from the back side use the inverse ratio.
+///
+/// The refracted ray starts at the hit point and has a normalized direction. Returns `None` on
+/// total internal reflection
+pub fn refract(ray: &Ray3D, hit: &RayHit3D, eta: f32) -> Option<Ray3D> {
+    let incoming = ray.direction.normalize();
+    let (normal, eta) = if hit.front_face {
+        (hit.normal.normalize(), eta)
+    } else {
+        (-hit.normal.normalize(), eta.recip())
+    };
+    let cos_in = -incoming.dot(normal);
+    let sin_out_squared = eta * eta * (1.0 - cos_in * cos_in);
+    (sin_out_squared <= 1.0).then(|| {
+        let cos_out = (1.0 - sin_out_squared).sqrt();
+        let direction = eta * incoming + (eta * cos_in - cos_out) * normal;
+        Ray3D::new(hit.point, direction)
+    })
+}
+
+/// Closest hit with one of the surfaces which is strictly in front of the ray origin. Rays that
+/// lie within a surface graze it and don't count as hits
+pub fn first_hit(ray: &Ray3D, surfaces: &[NeoSurface]) -> Option<RayHit3D> {
+    let min_t = NEO_LINE_RAY_3D_EPS / ray.direction.length();
+    surfaces
+        .iter()
+        .filter_map(|surface| match surface.intersection(ray) {
+            SurfaceRay3DIntersection::Point(hit) => Some(hit),
+            _ => None,
+        })
+        .filter(|hit| hit.t > min_t)
+        .min_by(|a, b| float_ord_cmp(&a.t, &b.t))
+}
+
+/// Follows the ray through at most `max_bounces` reflections off the surfaces. The path starts at
+/// the ray origin and contains every hit point. If the ray escapes before running out of bounces,
+/// the path ends one direction length after the last point. Use [`Polyline3D::iter_lines`] to get
+/// the individual segments
+pub fn trace_reflections(ray: &Ray3D, surfaces: &[NeoSurface], max_bounces: usize) -> Polyline3D {
+    let mut points = vec![ray.origin];
+    let mut ray = *ray;
+    for _ in 0..max_bounces {
+        let Some(hit) = first_hit(&ray, surfaces) else {
+            points.push(ray.origin + ray.direction);
+            break;
+        };
+        points.push(hit.point);
+        ray = reflect(&ray, &hit);
+    }
+    Polyline3D::new(points)
+}
+
+#[cfg(test)]
+mod optics_tests {
+    use glam::{Vec2, Vec3};
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d3::def::Ray3D;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::optics::{first_hit, reflect, refract, trace_reflections};
+    use crate::ray_hit::RayHit3D;
+
+    const EPS: f32 = 0.000_1;
+
+    fn floor_hit(ray: &Ray3D, t: f32) -> RayHit3D {
+        RayHit3D::new(ray, t, Vec3::Z)
+    }
+
+    /// vertical wall along the x axis at the given y offset, reaching from x = -10 to x = 10
+    fn wall_at(y: f32) -> NeoSurface {
+        let line = LineSegment2D::new(Vec2::new(-10.0, y), Vec2::new(10.0, y));
+        NeoSurface::from_line_and_heights_vertical(line, -10.0, 10.0)
+    }
+
+    #[test]
+    fn reflect_works() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, 1.0), Vec3::new(1.0, 0.0, -1.0));
+        let reflected = reflect(&ray, &floor_hit(&ray, 1.0));
+
+        assert!(reflected.origin.abs_diff_eq(Vec3::ZERO, EPS));
+        assert!(reflected
+            .direction
+            .abs_diff_eq(Vec3::new(1.0, 0.0, 1.0), EPS));
+    }
+
+    #[test]
+    fn reflect_ignores_hit_side() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, -1.0), Vec3::new(1.0, 0.0, 1.0));
+        let reflected = reflect(&ray, &floor_hit(&ray, 1.0));
+
+        assert!(reflected
+            .direction
+            .abs_diff_eq(Vec3::new(1.0, 0.0, -1.0), EPS));
+    }
+
+    #[test]
+    fn refract_with_same_medium_keeps_direction() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, 1.0), Vec3::new(1.0, 0.0, -1.0));
+        let refracted = refract(&ray, &floor_hit(&ray, 1.0), 1.0).unwrap();
+
+        assert!(refracted
+            .direction
+            .abs_diff_eq(ray.direction.normalize(), EPS));
+    }
+
+    #[test]
+    fn refract_bends_towards_normal() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, 1.0), Vec3::new(1.0, 0.0, -1.0));
+        let eta = 1.0 / 1.5;
+        let refracted = refract(&ray, &floor_hit(&ray, 1.0), eta).unwrap();
+
+        let sin_in = std::f32::consts::FRAC_1_SQRT_2;
+        let sin_out = refracted.direction.x;
+        assert!((sin_out - sin_in * eta).abs() < EPS);
+        assert!(refracted.direction.z < 0.0);
+        assert!((refracted.direction.length() - 1.0).abs() < EPS);
+    }
+
+    #[test]
+    fn refract_from_back_side_uses_inverse_eta() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, -2.0), Vec3::new(1.0, 0.0, 2.0));
+        let hit = floor_hit(&ray, 1.0);
+        assert!(!hit.front_face);
+
+        let refracted = refract(&ray, &hit, 1.0 / 1.5).unwrap();
+        let sin_in = ray.direction.normalize().x;
+        assert!((refracted.direction.x - sin_in * 1.5).abs() < EPS);
+        assert!(refracted.direction.z > 0.0);
+    }
+
+    #[test]
+    fn total_internal_reflection_works() {
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, -1.0), Vec3::new(1.0, 0.0, 1.0));
+        assert_eq!(refract(&ray, &floor_hit(&ray, 1.0), 1.0 / 1.5), None);
+    }
+
+    #[test]
+    fn first_hit_ignores_surfaces_behind() {
+        let surfaces = [wall_at(-1.0), wall_at(3.0), wall_at(2.0)];
+        let ray = Ray3D::new(Vec3::ZERO, Vec3::Y);
+        let hit = first_hit(&ray, &surfaces).unwrap();
+
+        assert!(hit.point.abs_diff_eq(Vec3::Y * 2.0, EPS));
+    }
+
+    #[test]
+    fn trace_between_mirrors_works() {
+        let surfaces = [wall_at(0.0), wall_at(2.0)];
+        let ray = Ray3D::new(Vec3::Y, Vec3::new(1.0, 1.0, 0.0));
+        let path = trace_reflections(&ray, &surfaces, 3);
+
+        let expected = [
+            Vec3::Y,
+            Vec3::new(1.0, 2.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(5.0, 2.0, 0.0),
+        ];
+        assert_eq!(path.points.len(), expected.len());
+        path.iter_points()
+            .zip(expected)
+            .for_each(|(p, e)| assert!(p.abs_diff_eq(e, EPS), "{p:?} != {e:?}"));
+        assert_eq!(path.iter_lines().count(), 3);
+    }
+
+    #[test]
+    fn trace_escaping_ray_works() {
+        let surfaces = [wall_at(2.0)];
+        let ray = Ray3D::new(Vec3::ZERO, Vec3::Y);
+        let path = trace_reflections(&ray, &surfaces, 5);
+
+        assert_eq!(path.points.len(), 3);
+        assert!(path.points[1].abs_diff_eq(Vec3::Y * 2.0, EPS));
+        assert!(path.points[2].abs_diff_eq(Vec3::Y, EPS));
+    }
+}
@@ -1,8 +1,11 @@
 use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_plane::Plane;
 use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
 
-use crate::trait_def::NeoIntersectable;
+use crate::intersection_set::IntersectionDimension;
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PlanePlaneIntersection {
@@ -10,6 +13,20 @@ pub enum PlanePlaneIntersection {
     Ray(Ray3D),
 }
 
+impl NeoIntersectionResult for PlanePlaneIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    /// both cases are unbounded, so only the dimension is reported
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            PlanePlaneIntersection::Same => Some(IntersectionDimension::Surface),
+            PlanePlaneIntersection::Ray(_) => Some(IntersectionDimension::Line),
+        }
+    }
+}
+
 impl NeoIntersectable for Plane {
     type Output = PlanePlaneIntersection;
 
@@ -22,6 +39,13 @@ impl NeoIntersectable for Plane {
     }
 }
 
+impl NeoIntersects for Plane {
+    fn intersects(&self, _rhs: &Self) -> bool {
+        // planes pass through the origin, so they always intersect
+        true
+    }
+}
+
 #[test]
 fn same_works() {
     let p = Plane::from_normal(Vec3::ONE);
@@ -0,0 +1,366 @@
+use geo::{Area, LinesIter};
+use glam::Vec2;
+use neo_bounded::traits::NeoBounded2D;
+use neo_error::NeoGeometryError;
+use neo_geo_boolops::NeoGeoBoolops;
+use neo_line_segment::d2::constants::NEO_LINE_SEGMENT_2D_EPS;
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::intersection_set::IntersectionDimension;
+use crate::trait_def::{
+    NeoIntersectable, NeoIntersectionResult, NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+};
+use crate::validation::ensure_valid_polygon_2d;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolygonPolygon2DIntersection {
+    Disjoint,
+    /// the boundaries touch at these points, the interiors don't overlap
+    Touch(Vec<Vec2>),
+    /// the boundaries share these edges and possibly touch at additional points, the interiors
+    /// don't overlap
+    SharedEdges {
+        edges: Vec<LineSegment2D>,
+        points: Vec<Vec2>,
+    },
+    /// the interiors partially overlap in these polygons
+    Overlap(geo::MultiPolygon<f32>),
+    /// the left hand side polygon contains the right hand side polygon
+    Contains,
+    /// the left hand side polygon is contained in the right hand side polygon
+    Within,
+    Equal,
+}
+
+impl NeoIntersectionResult for PolygonPolygon2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    /// containment and equality don't carry the contained polygon, so they only report their
+    /// dimension
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            PolygonPolygon2DIntersection::Disjoint => None,
+            PolygonPolygon2DIntersection::Touch(_) => Some(IntersectionDimension::Point),
+            PolygonPolygon2DIntersection::SharedEdges { .. } => Some(IntersectionDimension::Line),
+            PolygonPolygon2DIntersection::Overlap(_)
+            | PolygonPolygon2DIntersection::Contains
+            | PolygonPolygon2DIntersection::Within
+            | PolygonPolygon2DIntersection::Equal => Some(IntersectionDimension::Surface),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            PolygonPolygon2DIntersection::Touch(points)
+            | PolygonPolygon2DIntersection::SharedEdges { points, .. } => points.clone(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            PolygonPolygon2DIntersection::SharedEdges { edges, .. } => edges.clone(),
+            _ => vec![],
+        }
+    }
+
+    fn surfaces(&self) -> Vec<geo::Polygon<f32>> {
+        match self {
+            PolygonPolygon2DIntersection::Overlap(mp) => mp.0.clone(),
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectable<geo::Polygon<f32>> for geo::Polygon<f32> {
+    type Output = PolygonPolygon2DIntersection;
+
+    /// reports `Disjoint` for broken polygons and if the boolean intersection of the polygons
+    /// fails, see [`NeoTryIntersectable::try_intersection`]
+    fn intersection(&self, rhs: &geo::Polygon<f32>) -> Self::Output {
+        self.try_intersection(rhs)
+            .unwrap_or(PolygonPolygon2DIntersection::Disjoint)
+    }
+}
+
+impl NeoTryIntersectable<geo::Polygon<f32>> for geo::Polygon<f32> {
+    type Output = PolygonPolygon2DIntersection;
+
+    fn try_intersection(&self, rhs: &geo::Polygon<f32>) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_polygon_2d(self)?;
+        ensure_valid_polygon_2d(rhs)?;
+        classify_polygons(self, rhs)
+    }
+}
+
+impl NeoIntersects<geo::Polygon<f32>> for geo::Polygon<f32> {
+    fn intersects(&self, rhs: &geo::Polygon<f32>) -> bool {
+        self.try_intersects(rhs).unwrap_or(false)
+    }
+}
+
+impl NeoTryIntersects<geo::Polygon<f32>> for geo::Polygon<f32> {
+    fn try_intersects(&self, rhs: &geo::Polygon<f32>) -> Result<bool, NeoGeometryError> {
+        ensure_valid_polygon_2d(self)?;
+        ensure_valid_polygon_2d(rhs)?;
+        Ok(self.aabb().intersects(&rhs.aabb()) && geo::Intersects::intersects(self, rhs))
+    }
+}
+
+fn classify_polygons(
+    lhs: &geo::Polygon<f32>,
+    rhs: &geo::Polygon<f32>,
+) -> Result<PolygonPolygon2DIntersection, NeoGeometryError> {
+    if !lhs.aabb().intersects(&rhs.aabb()) {
+        return Ok(PolygonPolygon2DIntersection::Disjoint);
+    }
+    // a failed boolean operation doesn't tell whether the polygons overlap, so it must not end up
+    // in the boundary analysis which would report them as touching or disjoint
+    let overlap = lhs
+        .neo_intersection(rhs)
+        .ok_or(NeoGeometryError::NumericalInconsistency(
+            "boolean intersection of the polygons failed",
+        ))?;
+    let area_eps = overlap_area_eps(lhs, rhs);
+    if overlap.unsigned_area() > area_eps {
+        Ok(overlap_case_analysis(lhs, rhs, overlap, area_eps))
+    } else {
+        Ok(boundary_case_analysis(lhs, rhs))
+    }
+}
+
+/// overlaps below this area are slivers along shared edges. It's the area of a sliver with width
+/// [`NEO_LINE_SEGMENT_2D_EPS`] along the diagonal of the smaller polygon, so it scales with the
+/// polygons
+fn overlap_area_eps(lhs: &geo::Polygon<f32>, rhs: &geo::Polygon<f32>) -> f32 {
+    let size = |polygon: &geo::Polygon<f32>| {
+        let aabb = polygon.aabb();
+        (aabb.max - aabb.min).length()
+    };
+    NEO_LINE_SEGMENT_2D_EPS * NEO_LINE_SEGMENT_2D_EPS.max(size(lhs).min(size(rhs)))
+}
+
+fn overlap_case_analysis(
+    lhs: &geo::Polygon<f32>,
+    rhs: &geo::Polygon<f32>,
+    overlap: geo::MultiPolygon<f32>,
+    area_eps: f32,
+) -> PolygonPolygon2DIntersection {
+    let overlap_area = overlap.unsigned_area();
+    let covers =
+        |polygon: &geo::Polygon<f32>| (polygon.unsigned_area() - overlap_area).abs() <= area_eps;
+    match (covers(lhs), covers(rhs)) {
+        (true, true) => PolygonPolygon2DIntersection::Equal,
+        (true, false) => PolygonPolygon2DIntersection::Within,
+        (false, true) => PolygonPolygon2DIntersection::Contains,
+        (false, false) => PolygonPolygon2DIntersection::Overlap(overlap),
+    }
+}
+
+fn boundary_case_analysis(
+    lhs: &geo::Polygon<f32>,
+    rhs: &geo::Polygon<f32>,
+) -> PolygonPolygon2DIntersection {
+    let mut edges: Vec<LineSegment2D> = vec![];
+    let mut points: Vec<Vec2> = vec![];
+    for l1 in lhs.lines_iter().map(LineSegment2D::from) {
+        for l2 in rhs.lines_iter().map(LineSegment2D::from) {
+            match collinear_overlap(&l1, &l2) {
+                Some(overlap) if overlap.length() > NEO_LINE_SEGMENT_2D_EPS => edges.push(overlap),
+                Some(overlap) => points.push(overlap.src),
+                None => points.extend(l1.intersection(&l2).intersection_point()),
+            }
+        }
+    }
+
+    let mut unique_points: Vec<Vec2> = vec![];
+    points
+        .into_iter()
+        .filter(|&p| !edges.iter().any(|edge| edge.is_point_on_line(p)))
+        .for_each(|p| {
+            if !unique_points
+                .iter()
+                .any(|q| q.abs_diff_eq(p, NEO_LINE_SEGMENT_2D_EPS))
+            {
+                unique_points.push(p);
+            }
+        });
+
+    match (edges.is_empty(), unique_points.is_empty()) {
+        (true, true) => PolygonPolygon2DIntersection::Disjoint,
+        (true, false) => PolygonPolygon2DIntersection::Touch(unique_points),
+        (false, _) => PolygonPolygon2DIntersection::SharedEdges {
+            edges,
+            points: unique_points,
+        },
+    }
+}
+
+/// Shared part of two collinear lines. The shared part might be degenerated if the lines only
+/// touch at their endpoints
+fn collinear_overlap(l1: &LineSegment2D, l2: &LineSegment2D) -> Option<LineSegment2D> {
+    let is_collinear = !l1.is_degenerated()
+        && !l2.is_degenerated()
+        && l1.is_parallel_to(l2)
+        && l1.ray().is_point_on_ray(l2.src);
+    if !is_collinear {
+        return None;
+    }
+    let scalar_of = |p: Vec2| (p - l1.src).dot(l1.direction()) / l1.length_squared();
+    let [a, b] = l2.array().map(scalar_of);
+    let eps = NEO_LINE_SEGMENT_2D_EPS / l1.length();
+    let [start, end] = [a.min(b).max(0.0), a.max(b).min(1.0)];
+    (end >= start - eps)
+        .then(|| LineSegment2D::new(l1.inject_scalar(start), l1.inject_scalar(end.max(start))))
+}
+
+#[cfg(test)]
+mod polygon_polygon {
+    use geo::Area;
+    use glam::Vec2;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::batch::batch_intersection_2d;
+    use crate::polygon2d::polygon::PolygonPolygon2DIntersection;
+    use crate::trait_def::{NeoIntersectable, NeoIntersects, NeoTryIntersectable};
+
+    fn square(min: Vec2, size: f32) -> geo::Polygon<f32> {
+        geo::Rect::new(min.to_geo(), (min + Vec2::ONE * size).to_geo()).to_polygon()
+    }
+
+    #[test]
+    fn disjoint_works() {
+        let a = square(Vec2::ZERO, 1.0);
+        let b = square(Vec2::X * 3.0, 1.0);
+        let c = square(Vec2::new(1.5, 0.0), 1.0);
+        assert_eq!(a.intersection(&b), PolygonPolygon2DIntersection::Disjoint);
+        assert_eq!(a.intersection(&c), PolygonPolygon2DIntersection::Disjoint);
+    }
+
+    #[test]
+    fn polygon_in_hole_is_disjoint() {
+        let ext = square(Vec2::ZERO, 4.0).exterior().clone();
+        let hole = square(Vec2::ONE, 2.0).exterior().clone();
+        let donut = geo::Polygon::new(ext, vec![hole]);
+        let inner = square(Vec2::ONE * 1.5, 1.0);
+        assert_eq!(
+            donut.intersection(&inner),
+            PolygonPolygon2DIntersection::Disjoint
+        );
+    }
+
+    #[test]
+    fn touching_corner_works() {
+        let a = square(Vec2::ZERO, 1.0);
+        let b = square(Vec2::ONE, 1.0);
+        assert_eq!(
+            a.intersection(&b),
+            PolygonPolygon2DIntersection::Touch(vec![Vec2::ONE])
+        );
+    }
+
+    #[test]
+    fn shared_edge_works() {
+        let a = square(Vec2::ZERO, 2.0);
+        let b = square(Vec2::new(2.0, 1.0), 2.0);
+        assert_eq!(
+            a.intersection(&b),
+            PolygonPolygon2DIntersection::SharedEdges {
+                edges: vec![LineSegment2D::new(Vec2::new(2.0, 1.0), Vec2::new(2.0, 2.0))],
+                points: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn overlap_works() {
+        let a = square(Vec2::ZERO, 2.0);
+        let b = square(Vec2::ONE, 2.0);
+        let PolygonPolygon2DIntersection::Overlap(overlap) = a.intersection(&b) else {
+            panic!("expected the squares to overlap");
+        };
+        assert!((overlap.unsigned_area() - 1.0).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn containment_works() {
+        let outer = square(Vec2::ZERO, 4.0);
+        let inner = square(Vec2::ONE, 1.0);
+        assert_eq!(
+            outer.intersection(&inner),
+            PolygonPolygon2DIntersection::Contains
+        );
+        assert_eq!(
+            inner.intersection(&outer),
+            PolygonPolygon2DIntersection::Within
+        );
+    }
+
+    #[test]
+    fn containment_touching_boundary_works() {
+        let outer = square(Vec2::ZERO, 4.0);
+        let inner = square(Vec2::ZERO, 1.0);
+        assert_eq!(
+            outer.intersection(&inner),
+            PolygonPolygon2DIntersection::Contains
+        );
+    }
+
+    #[test]
+    fn equal_works() {
+        let a = square(Vec2::ZERO, 1.0);
+        assert_eq!(a.intersection(&a), PolygonPolygon2DIntersection::Equal);
+    }
+
+    #[test]
+    fn small_overlap_works() {
+        let a = square(Vec2::ZERO, 0.01);
+        let b = square(Vec2::X * 0.005, 0.01);
+        let PolygonPolygon2DIntersection::Overlap(overlap) = a.intersection(&b) else {
+            panic!("expected the small squares to overlap");
+        };
+        assert!((overlap.unsigned_area() - 0.000_05).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn sliver_of_large_polygons_is_a_shared_edge() {
+        let a = square(Vec2::ZERO, 1000.0);
+        let b = square(Vec2::X * 999.9999, 1000.0);
+        let inter = a.intersection(&b);
+        assert!(
+            matches!(inter, PolygonPolygon2DIntersection::SharedEdges { .. }),
+            "{inter:?}"
+        );
+    }
+
+    #[test]
+    fn try_intersection_works() {
+        let a = square(Vec2::ZERO, 2.0);
+        let b = square(Vec2::ONE, 2.0);
+        assert_eq!(a.try_intersection(&b), Ok(a.intersection(&b)));
+
+        let mut broken = b.clone();
+        broken.exterior_mut(|ring| ring.0[1].x = f32::NAN);
+        assert_eq!(
+            a.try_intersection(&broken),
+            Err(NeoGeometryError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn broken_polygons_dont_panic() {
+        let a = square(Vec2::ZERO, 2.0);
+        let mut broken = square(Vec2::ONE, 2.0);
+        broken.exterior_mut(|ring| ring.0[1].x = f32::NAN);
+        assert_eq!(
+            a.intersection(&broken),
+            PolygonPolygon2DIntersection::Disjoint
+        );
+        assert!(!a.intersects(&broken));
+        assert!(batch_intersection_2d(&[a], &[broken]).is_empty());
+    }
+}
@@ -1,58 +1,196 @@
 use glam::Vec2;
 use neo_aabb::d2::def::AABB2D;
+use neo_error::NeoGeometryError;
 use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::constants::NEO_LINE_RAY_2D_EPS;
 use neo_ray::d2::def::Ray2D;
 
-use crate::trait_def::NeoIntersectable;
+use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::RayHit2D;
+use crate::trait_def::{
+    mirror_intersectable, mirror_try_intersectable, NeoIntersectable, NeoIntersectionResult,
+    NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+};
+use crate::validation::{ensure_valid_aabb_2d, ensure_valid_ray_2d};
 
 #[derive(Debug, PartialEq)]
 pub enum RayAABB2DIntersection {
     None,
-    Point(Vec2),
-    Line(LineSegment2D),
+    Point(RayHit2D),
+    /// the ray enters the box at `entry` and leaves it at `exit`, so `entry.t < exit.t`
+    Line {
+        entry: RayHit2D,
+        exit: RayHit2D,
+    },
+}
+
+impl RayAABB2DIntersection {
+    pub fn line(&self) -> Option<LineSegment2D> {
+        match self {
+            RayAABB2DIntersection::Line { entry, exit } => {
+                Some(LineSegment2D::new(entry.point, exit.point))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl NeoIntersectionResult for RayAABB2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayAABB2DIntersection::None => None,
+            RayAABB2DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            RayAABB2DIntersection::Line { .. } => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            RayAABB2DIntersection::Point(hit) => vec![hit.point],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            RayAABB2DIntersection::Line { entry, exit } => {
+                vec![LineSegment2D::new(entry.point, exit.point)]
+            }
+            _ => vec![],
+        }
+    }
 }
 
 impl NeoIntersectable<AABB2D> for Ray2D {
     type Output = RayAABB2DIntersection;
 
     fn intersection(&self, rhs: &AABB2D) -> Self::Output {
-        let intersection_points = rhs
-            .lines()
-            .map(LineSegment2D::from)
-            .into_iter()
-            .filter_map(|l| {
-                l.ray()
-                    .intersection(self)
-                    .intersection_point()
-                    .filter(|&p| l.is_point_on_line(p))
-            })
-            .fold(vec![], |mut res, elem| {
-                if !res.iter().any(|p: &Vec2| p.abs_diff_eq(elem, 0.000_1)) {
-                    res.push(elem);
-                }
-                res
-            });
-        match intersection_points.len() {
-            0 => RayAABB2DIntersection::None,
-            1 => RayAABB2DIntersection::Point(intersection_points[0]),
-            2 => RayAABB2DIntersection::Line(LineSegment2D::new(
-                intersection_points[0],
-                intersection_points[1],
-            )),
-            _ => unreachable!("too many intersection points"),
+        classify_hits(boundary_hits(self, rhs)).unwrap_or(RayAABB2DIntersection::None)
+    }
+}
+
+impl NeoTryIntersectable<AABB2D> for Ray2D {
+    type Output = RayAABB2DIntersection;
+
+    fn try_intersection(&self, rhs: &AABB2D) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_ray_2d(self)?;
+        ensure_valid_aabb_2d(rhs)?;
+        classify_hits(boundary_hits(self, rhs))
+    }
+}
+
+/// hits of the ray with the boundary of the box, sorted by `t`
+fn boundary_hits(ray: &Ray2D, aabb: &AABB2D) -> Vec<RayHit2D> {
+    let intersection_points = aabb
+        .lines()
+        .map(LineSegment2D::from)
+        .into_iter()
+        .filter_map(|l| {
+            l.ray()
+                .intersection(ray)
+                .intersection_point()
+                .filter(|&p| l.is_point_on_line(p))
+        })
+        .fold(vec![], |mut res, elem| {
+            if !res.iter().any(|p: &Vec2| p.abs_diff_eq(elem, 0.000_1)) {
+                res.push(elem);
+            }
+            res
+        });
+    let mut hits = intersection_points
+        .into_iter()
+        .map(|p| RayHit2D::at_point(ray, p, outward_normal(aabb, p)))
+        .collect::<Vec<_>>();
+    hits.sort_by(|a, b| float_ord_cmp(&a.t, &b.t));
+    hits
+}
+
+fn classify_hits(hits: Vec<RayHit2D>) -> Result<RayAABB2DIntersection, NeoGeometryError> {
+    match hits.as_slice() {
+        [] => Ok(RayAABB2DIntersection::None),
+        [hit] => Ok(RayAABB2DIntersection::Point(*hit)),
+        [entry, exit] => Ok(RayAABB2DIntersection::Line {
+            entry: *entry,
+            exit: *exit,
+        }),
+        _ => Err(NeoGeometryError::NumericalInconsistency(
+            "too many intersection points",
+        )),
+    }
+}
+
+impl NeoIntersects<AABB2D> for Ray2D {
+    fn intersects(&self, rhs: &AABB2D) -> bool {
+        slab_parameters(self, rhs).is_some()
+    }
+}
+
+impl NeoTryIntersects<AABB2D> for Ray2D {
+    fn try_intersects(&self, rhs: &AABB2D) -> Result<bool, NeoGeometryError> {
+        ensure_valid_ray_2d(self)?;
+        ensure_valid_aabb_2d(rhs)?;
+        Ok(slab_parameters(self, rhs).is_some())
+    }
+}
+
+/// parameters `(t_entry, t_exit)` between which the ray runs through the box, from the slab test
+pub(crate) fn slab_parameters(ray: &Ray2D, aabb: &AABB2D) -> Option<(f32, f32)> {
+    if ray.is_degenerated() {
+        return None;
+    }
+    let mut range = (f32::NEG_INFINITY, f32::INFINITY);
+    for axis in 0..2 {
+        let (origin, direction) = (ray.origin[axis], ray.direction[axis]);
+        let (min, max) = (aabb.min[axis], aabb.max[axis]);
+        if direction == 0.0 {
+            if !(min..=max).contains(&origin) {
+                return None;
+            }
+        } else {
+            let (t0, t1) = ((min - origin) / direction, (max - origin) / direction);
+            range = (range.0.max(t0.min(t1)), range.1.min(t0.max(t1)));
         }
     }
+    // rays through a corner can miss it by rounding, so the ranges may be apart by a tolerance
+    // like the boundary hits of the intersection
+    let (t_entry, t_exit) = range;
+    let gap = (t_entry - t_exit) * ray.direction.length();
+    (gap < NEO_LINE_RAY_2D_EPS).then_some((t_entry.min(t_exit), t_entry.max(t_exit)))
+}
+
+mirror_intersectable!(Ray2D => AABB2D);
+mirror_try_intersectable!(Ray2D => AABB2D);
+
+/// Outward normal of the box boundary at `point`. Corners get the diagonal normal
+fn outward_normal(aabb: &AABB2D, point: Vec2) -> Vec2 {
+    let on = |a: f32, b: f32| (a - b).abs() < 0.000_1;
+    [
+        (on(point.x, aabb.min.x), Vec2::NEG_X),
+        (on(point.x, aabb.max.x), Vec2::X),
+        (on(point.y, aabb.min.y), Vec2::NEG_Y),
+        (on(point.y, aabb.max.y), Vec2::Y),
+    ]
+    .into_iter()
+    .filter_map(|(is_on_side, normal)| is_on_side.then_some(normal))
+    .sum::<Vec2>()
+    .normalize_or_zero()
 }
 
 #[cfg(test)]
 mod ray_aabb {
     use glam::Vec2;
     use neo_aabb::d2::def::AABB2D;
+    use neo_error::NeoGeometryError;
     use neo_line_segment::d2::def::LineSegment2D;
     use neo_ray::d2::def::Ray2D;
 
     use crate::ray2d::aabb::RayAABB2DIntersection;
-    use crate::trait_def::NeoIntersectable;
+    use crate::trait_def::{NeoIntersectable, NeoTryIntersectable};
 
     #[test]
     fn no_intersection_works() {
@@ -67,10 +205,10 @@ mod ray_aabb {
         let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
         let ray = Ray2D::new(-Vec2::X, Vec2::ONE);
 
-        assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Point(Vec2::Y)
-        )
+        let RayAABB2DIntersection::Point(hit) = ray.intersection(&aabb) else {
+            panic!("expected a single intersection point");
+        };
+        assert_eq!(hit.point, Vec2::Y)
     }
 
     #[test]
@@ -78,10 +216,10 @@ mod ray_aabb {
         let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
         let ray = Ray2D::new(Vec2::Y - Vec2::X, -Vec2::Y + Vec2::X);
 
-        assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Point(Vec2::ZERO)
-        )
+        let RayAABB2DIntersection::Point(hit) = ray.intersection(&aabb) else {
+            panic!("expected a single intersection point");
+        };
+        assert_eq!(hit.point, Vec2::ZERO)
     }
 
     #[test]
@@ -89,10 +227,10 @@ mod ray_aabb {
         let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
         let ray = Ray2D::new(Vec2::Y - Vec2::X, (-Vec2::Y + Vec2::X) * 2.0);
 
-        assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Point(Vec2::ZERO)
-        )
+        let RayAABB2DIntersection::Point(hit) = ray.intersection(&aabb) else {
+            panic!("expected a single intersection point");
+        };
+        assert_eq!(hit.point, Vec2::ZERO)
     }
 
     #[test]
@@ -101,8 +239,8 @@ mod ray_aabb {
         let ray = Ray2D::new(-Vec2::X * 0.5, Vec2::ONE);
 
         assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Line(LineSegment2D::new(Vec2::Y * 0.5, Vec2::Y + Vec2::X * 0.5))
+            ray.intersection(&aabb).line(),
+            Some(LineSegment2D::new(Vec2::Y * 0.5, Vec2::Y + Vec2::X * 0.5))
         )
     }
 
@@ -112,8 +250,8 @@ mod ray_aabb {
         let ray = Ray2D::new(Vec2::ZERO, Vec2::ONE);
 
         assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Line(LineSegment2D::new(Vec2::ZERO, Vec2::ONE))
+            ray.intersection(&aabb).line(),
+            Some(LineSegment2D::new(Vec2::ZERO, Vec2::ONE))
         )
     }
 
@@ -123,11 +261,67 @@ mod ray_aabb {
         let ray = Ray2D::new(Vec2::X * f32::EPSILON, Vec2::ONE);
 
         assert_eq!(
-            ray.intersection(&aabb),
-            RayAABB2DIntersection::Line(LineSegment2D::new(
+            ray.intersection(&aabb).line(),
+            Some(LineSegment2D::new(
                 Vec2::ZERO - f32::EPSILON * Vec2::Y,
                 Vec2::ONE + f32::EPSILON * Vec2::X
             ))
         )
     }
+
+    #[test]
+    fn entry_and_exit_hits_work() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let ray = Ray2D::new(Vec2::new(2.0, 0.5), -Vec2::X * 2.0);
+
+        let RayAABB2DIntersection::Line { entry, exit } = ray.intersection(&aabb) else {
+            panic!("expected the ray to pass through the box");
+        };
+        assert_eq!(entry.t, 0.5);
+        assert_eq!(entry.normal, Vec2::X);
+        assert!(entry.front_face);
+        assert_eq!(exit.t, 1.0);
+        assert_eq!(exit.normal, Vec2::NEG_X);
+        assert!(!exit.front_face);
+    }
+
+    #[test]
+    fn corner_hit_has_diagonal_normal() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let ray = Ray2D::new(Vec2::Y - Vec2::X, -Vec2::Y + Vec2::X);
+
+        let RayAABB2DIntersection::Point(hit) = ray.intersection(&aabb) else {
+            panic!("expected a single intersection point");
+        };
+        assert!(hit.normal.abs_diff_eq(Vec2::NEG_ONE.normalize(), 0.000_1));
+    }
+
+    #[test]
+    fn try_intersection_agrees_for_valid_input() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let ray = Ray2D::new(Vec2::new(-1.0, 0.5), Vec2::X);
+        assert_eq!(ray.try_intersection(&aabb), Ok(ray.intersection(&aabb)));
+        assert_eq!(aabb.try_intersection(&ray), Ok(aabb.intersection(&ray)));
+    }
+
+    #[test]
+    fn try_intersection_rejects_bad_input() {
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        let ray = Ray2D::new(Vec2::new(f32::NAN, 0.5), Vec2::X);
+        assert_eq!(
+            ray.try_intersection(&aabb),
+            Err(NeoGeometryError::NonFinite)
+        );
+        let ray = Ray2D::new(Vec2::new(-1.0, 0.5), Vec2::ZERO);
+        assert!(matches!(
+            ray.try_intersection(&aabb),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::new(f32::INFINITY, 1.0));
+        let ray = Ray2D::new(Vec2::new(-1.0, 0.5), Vec2::X);
+        assert_eq!(
+            aabb.try_intersection(&ray),
+            Err(NeoGeometryError::NonFinite)
+        );
+    }
 }
@@ -0,0 +1,121 @@
+use neo_primitive::d2::capsule::Capsule2D;
+use neo_ray::d2::constants::NEO_LINE_RAY_2D_EPS;
+use neo_ray::d2::def::Ray2D;
+
+use crate::ray2d::circle::circle_hits;
+use crate::ray_hit::{RayHit2D, RaySolid2DIntersection};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Capsule2D> for Ray2D {
+    type Output = RaySolid2DIntersection;
+
+    fn intersection(&self, rhs: &Capsule2D) -> Self::Output {
+        let axis = rhs.axis;
+        let length = axis.length();
+        if length < NEO_LINE_RAY_2D_EPS {
+            return RaySolid2DIntersection::from_hits(circle_hits(self, axis.src, rhs.radius));
+        }
+        let dir = axis.direction_normalized();
+        let height_of = |hit: &RayHit2D| (hit.point - axis.src).dot(dir);
+
+        let normal = dir.perp();
+        let offset = (self.origin - axis.src).dot(normal);
+        let speed = self.direction.dot(normal);
+        let side_hits = [normal, -normal]
+            .into_iter()
+            .filter(|_| speed.abs() > NEO_LINE_RAY_2D_EPS)
+            .map(|side_normal| {
+                let t = (rhs.radius * side_normal.dot(normal) - offset) / speed;
+                RayHit2D::new(self, t, side_normal)
+            })
+            .filter(|hit| (0.0..=length).contains(&height_of(hit)));
+
+        let src_cap_hits = circle_hits(self, axis.src, rhs.radius)
+            .into_iter()
+            .filter(|hit| height_of(hit) <= 0.0);
+        let dst_cap_hits = circle_hits(self, axis.dst, rhs.radius)
+            .into_iter()
+            .filter(|hit| height_of(hit) >= length);
+
+        RaySolid2DIntersection::from_hits(
+            side_hits.chain(src_cap_hits).chain(dst_cap_hits).collect(),
+        )
+    }
+}
+
+impl NeoIntersects<Capsule2D> for Ray2D {
+    fn intersects(&self, rhs: &Capsule2D) -> bool {
+        if self.is_degenerated() {
+            return false;
+        }
+        // signed distances of the axis ends to the line of the ray, the axis crosses the line if
+        // they differ in sign and is closest to it at one of its ends otherwise
+        let normal = self.direction.perp().normalize();
+        let [src, dst] = rhs.axis.array().map(|p| (p - self.origin).dot(normal));
+        src * dst <= 0.0 || src.abs().min(dst.abs()) <= rhs.radius
+    }
+}
+
+mirror_intersectable!(Ray2D => Capsule2D);
+
+#[cfg(test)]
+mod ray_capsule {
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_primitive::d2::capsule::Capsule2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::ray_hit::RaySolid2DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    fn capsule() -> Capsule2D {
+        Capsule2D::new(LineSegment2D::new(Vec2::ZERO, Vec2::X * 2.0), 1.0)
+    }
+
+    #[test]
+    fn through_sides_works() {
+        let ray = Ray2D::new(Vec2::new(1.0, -3.0), Vec2::Y);
+        let intersection = ray.intersection(&capsule());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!((entry.t - 2.0).abs() < EPS);
+        assert!((exit.t - 4.0).abs() < EPS);
+        assert!(entry.normal.abs_diff_eq(-Vec2::Y, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec2::Y, EPS));
+    }
+
+    #[test]
+    fn along_axis_works() {
+        let ray = Ray2D::new(-Vec2::X * 2.0, Vec2::X);
+        let intersection = ray.intersection(&capsule());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(-Vec2::X, EPS));
+        assert!(exit.point.abs_diff_eq(Vec2::X * 3.0, EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec2::X, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec2::X, EPS));
+    }
+
+    #[test]
+    fn through_side_and_cap_works() {
+        let ray = Ray2D::new(Vec2::new(0.0, -2.0), Vec2::ONE);
+        let intersection = ray.intersection(&capsule());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec2::new(1.0, -1.0), EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec2::Y, EPS));
+        assert!(((exit.point - Vec2::X * 2.0).length() - 1.0).abs() < EPS);
+        assert!(exit.point.x > 2.0);
+    }
+
+    #[test]
+    fn miss_works() {
+        let ray = Ray2D::new(Vec2::new(3.5, -2.0), Vec2::Y);
+        assert_eq!(ray.intersection(&capsule()), RaySolid2DIntersection::None);
+    }
+}
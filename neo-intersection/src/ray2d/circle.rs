@@ -0,0 +1,105 @@
+use glam::Vec2;
+use neo_primitive::d2::circle::Circle2D;
+use neo_ray::d2::def::Ray2D;
+
+use crate::ray_hit::{has_quadratic_roots, quadratic_roots, RayHit2D, RaySolid2DIntersection};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Circle2D> for Ray2D {
+    type Output = RaySolid2DIntersection;
+
+    fn intersection(&self, rhs: &Circle2D) -> Self::Output {
+        RaySolid2DIntersection::from_hits(circle_hits(self, rhs.center, rhs.radius))
+    }
+}
+
+/// only checks the discriminant, without building the hits
+impl NeoIntersects<Circle2D> for Ray2D {
+    fn intersects(&self, rhs: &Circle2D) -> bool {
+        let (a, b, c) = circle_coefficients(self, rhs.center, rhs.radius);
+        self.direction != Vec2::ZERO && rhs.radius > 0.0 && has_quadratic_roots(a, b, c)
+    }
+}
+
+mirror_intersectable!(Ray2D => Circle2D);
+
+pub(crate) fn circle_hits(ray: &Ray2D, center: Vec2, radius: f32) -> Vec<RayHit2D> {
+    if ray.direction == Vec2::ZERO || radius <= 0.0 {
+        return vec![];
+    }
+    let (a, b, c) = circle_coefficients(ray, center, radius);
+    quadratic_roots(a, b, c)
+        .into_iter()
+        .map(|t| {
+            let point = ray.origin + ray.direction * t;
+            RayHit2D::new(ray, t, (point - center) / radius)
+        })
+        .collect()
+}
+
+/// coefficients of `a t² + b t + c = 0` for the parameters where the ray meets the circle
+fn circle_coefficients(ray: &Ray2D, center: Vec2, radius: f32) -> (f32, f32, f32) {
+    let offset = ray.origin - center;
+    let a = ray.direction.length_squared();
+    let b = 2.0 * offset.dot(ray.direction);
+    let c = offset.length_squared() - radius * radius;
+    (a, b, c)
+}
+
+#[cfg(test)]
+mod ray_circle {
+    use glam::Vec2;
+    use neo_primitive::d2::circle::Circle2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::ray_hit::RaySolid2DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    #[test]
+    fn entry_and_exit_work() {
+        let circle = Circle2D::new(Vec2::ZERO, 1.0);
+        let ray = Ray2D::new(-Vec2::X * 3.0, Vec2::X);
+        let intersection = ray.intersection(&circle);
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!((entry.t - 2.0).abs() < EPS);
+        assert!((exit.t - 4.0).abs() < EPS);
+        assert!(entry.point.abs_diff_eq(-Vec2::X, EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec2::X, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec2::X, EPS));
+    }
+
+    #[test]
+    fn origin_inside_has_negative_entry() {
+        let circle = Circle2D::new(Vec2::ZERO, 1.0);
+        let ray = Ray2D::new(Vec2::ZERO, Vec2::Y * 2.0);
+        let intersection = ray.intersection(&circle);
+
+        assert!((intersection.entry().unwrap().t + 0.5).abs() < EPS);
+        assert!((intersection.exit().unwrap().t - 0.5).abs() < EPS);
+    }
+
+    #[test]
+    fn tangent_works() {
+        let circle = Circle2D::new(Vec2::ZERO, 1.0);
+        let ray = Ray2D::new(Vec2::Y, Vec2::X);
+        let intersection = ray.intersection(&circle);
+
+        assert_eq!(intersection.entry(), intersection.exit());
+        assert!(intersection
+            .entry()
+            .unwrap()
+            .point
+            .abs_diff_eq(Vec2::Y, EPS));
+    }
+
+    #[test]
+    fn miss_works() {
+        let circle = Circle2D::new(Vec2::ZERO, 1.0);
+        let ray = Ray2D::new(Vec2::Y * 2.0, Vec2::X);
+        assert_eq!(ray.intersection(&circle), RaySolid2DIntersection::None);
+    }
+}
@@ -1,11 +1,19 @@
 use glam::Vec2;
 use neo_bounded::traits::NeoBounded2D;
+use neo_error::NeoGeometryError;
 use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::constants::NEO_LINE_RAY_2D_EPS;
 use neo_ray::d2::def::Ray2D;
 
+use crate::intersection_set::IntersectionDimension;
 use crate::ray2d::aabb::RayAABB2DIntersection;
+use crate::ray_hit::RayHit2D;
 use crate::results::LineLine2DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+    NeoTryIntersectable,
+};
+use crate::validation::{ensure_finite_2d, ensure_valid_ray_2d};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RayLine2DIntersection {
@@ -14,26 +22,101 @@ pub enum RayLine2DIntersection {
     CollinearOverlap(LineSegment2D),
     /// This is a real intersection where the intersection point is located in both lines that were
     /// intersected. If you're interested in the general intersection point which may be located
-    /// outside the line, consider using [`LineSegment2D::ray_intersection`]
-    Intersection(Vec2),
+    /// outside the line, consider using [`LineSegment2D::ray_intersection`]. The hit normal is the
+    /// normal of the line segment
+    Intersection(RayHit2D),
+}
+
+impl RayLine2DIntersection {
+    pub fn intersection_point(&self) -> Option<Vec2> {
+        match self {
+            RayLine2DIntersection::Intersection(hit) => Some(hit.point),
+            _ => None,
+        }
+    }
+}
+
+impl NeoIntersectionResult for RayLine2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayLine2DIntersection::None | RayLine2DIntersection::Parallel => None,
+            RayLine2DIntersection::CollinearOverlap(_) => Some(IntersectionDimension::Line),
+            RayLine2DIntersection::Intersection(_) => Some(IntersectionDimension::Point),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            RayLine2DIntersection::Intersection(hit) => vec![hit.point],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            RayLine2DIntersection::CollinearOverlap(l) => vec![*l],
+            _ => vec![],
+        }
+    }
 }
 
 impl NeoIntersectable<LineSegment2D> for Ray2D {
     type Output = RayLine2DIntersection;
 
     fn intersection(&self, rhs: &LineSegment2D) -> Self::Output {
-        let rhs_aabb = rhs.aabb();
-        let inter = self.intersection(&rhs_aabb);
-        match inter {
-            RayAABB2DIntersection::None => parallel_case_analysis(self, rhs),
-            RayAABB2DIntersection::Point(p) => aabb_point_case_analysis(self, p, rhs),
-            RayAABB2DIntersection::Line(l) => aabb_line_case_analysis(l, rhs),
+        let inter = self.intersection(&rhs.aabb());
+        aabb_case_analysis(self, inter, rhs).unwrap_or(RayLine2DIntersection::None)
+    }
+}
+
+impl NeoTryIntersectable<LineSegment2D> for Ray2D {
+    type Output = RayLine2DIntersection;
+
+    fn try_intersection(&self, rhs: &LineSegment2D) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_ray_2d(self)?;
+        ensure_finite_2d([rhs.src, rhs.dst])?;
+        let inter = self.try_intersection(&rhs.aabb())?;
+        aabb_case_analysis(self, inter, rhs)
+    }
+}
+
+/// the ends of the line lie on different sides of the ray or one of them lies on it
+impl NeoIntersects<LineSegment2D> for Ray2D {
+    fn intersects(&self, rhs: &LineSegment2D) -> bool {
+        if self.is_degenerated() {
+            return false;
+        }
+        let normal = self.direction.perp().normalize();
+        let [src, dst] = rhs.array().map(|p| (p - self.origin).dot(normal));
+        src * dst <= 0.0 || src.abs().min(dst.abs()) < NEO_LINE_RAY_2D_EPS
+    }
+}
+
+mirror_intersectable!(Ray2D => LineSegment2D);
+
+fn aabb_case_analysis(
+    ray: &Ray2D,
+    inter: RayAABB2DIntersection,
+    rhs: &LineSegment2D,
+) -> Result<RayLine2DIntersection, NeoGeometryError> {
+    match inter {
+        RayAABB2DIntersection::None => Ok(parallel_case_analysis(ray, rhs)),
+        RayAABB2DIntersection::Point(hit) => Ok(aabb_point_case_analysis(ray, hit.point, rhs)),
+        RayAABB2DIntersection::Line { entry, exit } => {
+            aabb_line_case_analysis(ray, LineSegment2D::new(entry.point, exit.point), rhs)
         }
     }
 }
 
 fn parallel_case_analysis(ray: &Ray2D, rhs: &LineSegment2D) -> RayLine2DIntersection {
-    if ray.is_parallel_to(&rhs.ray()) {
+    // lines parallel to an axis have flat bounding boxes which the ray can't intersect properly
+    if ray.is_parallel_to(&rhs.ray()) && ray.is_point_on_ray(rhs.src) {
+        RayLine2DIntersection::CollinearOverlap(*rhs)
+    } else if ray.is_parallel_to(&rhs.ray()) {
         RayLine2DIntersection::Parallel
     } else {
         RayLine2DIntersection::None
@@ -46,44 +129,48 @@ fn aabb_point_case_analysis(
     rhs: &LineSegment2D,
 ) -> RayLine2DIntersection {
     if rhs.is_point_on_line(aabb_intersection_point) {
-        RayLine2DIntersection::Intersection(aabb_intersection_point)
+        RayLine2DIntersection::Intersection(RayHit2D::at_point(
+            ray,
+            aabb_intersection_point,
+            rhs.normal(),
+        ))
     } else {
         parallel_case_analysis(ray, rhs)
     }
 }
 
 fn aabb_line_case_analysis(
+    ray: &Ray2D,
     aabb_intersection_line: LineSegment2D,
     rhs: &LineSegment2D,
-) -> RayLine2DIntersection {
+) -> Result<RayLine2DIntersection, NeoGeometryError> {
     let inter = aabb_intersection_line.intersection(rhs);
     match inter {
-        LineLine2DIntersection::None => RayLine2DIntersection::None,
-        LineLine2DIntersection::Parallel => RayLine2DIntersection::Parallel,
-        LineLine2DIntersection::CollinearNoOverlap => {
-            let text = [
-                "shouldn't be possible",
-                "since the ray is infinite",
-                "and since if there is overlap, it is always the whole line",
-            ]
-            .join(" ");
-            unreachable!("{text}")
-        }
+        LineLine2DIntersection::None => Ok(RayLine2DIntersection::None),
+        LineLine2DIntersection::Parallel => Ok(RayLine2DIntersection::Parallel),
+        // the clipped ray spans the bounding box of the line, so if they're collinear the whole
+        // line overlaps. Anything else means the clipping went wrong
+        LineLine2DIntersection::CollinearNoOverlap => Err(
+            NeoGeometryError::NumericalInconsistency("collinear ray misses the line it spans"),
+        ),
         LineLine2DIntersection::CollinearOverlap(_) => {
-            RayLine2DIntersection::CollinearOverlap(rhs.clone())
+            Ok(RayLine2DIntersection::CollinearOverlap(*rhs))
         }
-        LineLine2DIntersection::Intersection(i) => RayLine2DIntersection::Intersection(i),
+        LineLine2DIntersection::Intersection(i) => Ok(RayLine2DIntersection::Intersection(
+            RayHit2D::at_point(ray, i, rhs.normal()),
+        )),
     }
 }
 
 #[cfg(test)]
 mod ray_line {
     use glam::Vec2;
+    use neo_error::NeoGeometryError;
     use neo_line_segment::d2::def::LineSegment2D;
     use neo_ray::d2::def::Ray2D;
 
     use crate::ray2d::line::RayLine2DIntersection;
-    use crate::trait_def::NeoIntersectable;
+    use crate::trait_def::{NeoIntersectable, NeoTryIntersectable};
 
     #[test]
     fn parallel_ray_line_with_aabb_works() {
@@ -139,8 +226,8 @@ mod ray_line {
         let ray = Ray2D::new(Vec2::Y - Vec2::X, (Vec2::X - Vec2::Y) * 2.0);
 
         assert_eq!(
-            ray.intersection(&line),
-            RayLine2DIntersection::Intersection(Vec2::ZERO)
+            ray.intersection(&line).intersection_point(),
+            Some(Vec2::ZERO)
         );
     }
 
@@ -151,8 +238,8 @@ mod ray_line {
             Ray2D::new(Vec2::Y - Vec2::X, (Vec2::X - Vec2::Y) * 2.0).offset_origin_by(Vec2::ONE);
 
         assert_eq!(
-            ray.intersection(&line),
-            RayLine2DIntersection::Intersection(Vec2::ONE)
+            ray.intersection(&line).intersection_point(),
+            Some(Vec2::ONE)
         );
     }
 
@@ -182,8 +269,45 @@ mod ray_line {
         let ray = Ray2D::new(Vec2::X, Vec2::Y - Vec2::X);
 
         assert_eq!(
-            ray.intersection(&line),
-            RayLine2DIntersection::Intersection(Vec2::ONE * 0.5)
+            ray.intersection(&line).intersection_point(),
+            Some(Vec2::ONE * 0.5)
+        );
+    }
+
+    #[test]
+    fn intersection_hit_works() {
+        let line = LineSegment2D::new(Vec2::ZERO, Vec2::X * 2.0);
+        let ray = Ray2D::new(Vec2::new(1.0, 2.0), -Vec2::Y);
+        let RayLine2DIntersection::Intersection(hit) = ray.intersection(&line) else {
+            panic!("expected an intersection");
+        };
+
+        assert_eq!(hit.t, 2.0);
+        assert_eq!(hit.point, Vec2::X);
+        assert_eq!(hit.normal, Vec2::Y);
+        assert!(hit.front_face);
+    }
+
+    #[test]
+    fn axis_aligned_overlap_works() {
+        let line = LineSegment2D::new(Vec2::X * 3.0, Vec2::X * 4.0);
+        assert_eq!(
+            Ray2D::X.intersection(&line),
+            RayLine2DIntersection::CollinearOverlap(line)
+        );
+    }
+
+    #[test]
+    fn try_intersection_works() {
+        let line = LineSegment2D::new(Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0));
+        assert_eq!(
+            Ray2D::X.try_intersection(&line),
+            Ok(Ray2D::X.intersection(&line))
+        );
+        let broken = LineSegment2D::new(Vec2::NAN, Vec2::ONE);
+        assert_eq!(
+            Ray2D::X.try_intersection(&broken),
+            Err(NeoGeometryError::NonFinite)
         );
     }
 }
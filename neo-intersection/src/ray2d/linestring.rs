@@ -0,0 +1,138 @@
+use geo::LinesIter;
+use glam::Vec2;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::def::Ray2D;
+
+use crate::line_intersection_parts::Line2DIntersectionParts;
+use crate::ray2d::line::RayLine2DIntersection;
+use crate::ray_hit::RayPart2D;
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<geo::LineString<f32>> for Ray2D {
+    type Output = Vec<RayPart2D>;
+
+    /// the hit normals are the normals of the segments, see [`RayLine2DIntersection`]
+    fn intersection(&self, rhs: &geo::LineString<f32>) -> Self::Output {
+        RayPart2D::sorted_along(self, linestring_parts(self, rhs), |p| {
+            segment_normal(std::slice::from_ref(rhs), p)
+        })
+    }
+}
+
+impl NeoIntersectable<geo::MultiLineString<f32>> for Ray2D {
+    type Output = Vec<RayPart2D>;
+
+    fn intersection(&self, rhs: &geo::MultiLineString<f32>) -> Self::Output {
+        let parts = rhs
+            .iter()
+            .flat_map(|ls| linestring_parts(self, ls))
+            .collect();
+        RayPart2D::sorted_along(self, parts, |p| segment_normal(&rhs.0, p))
+    }
+}
+
+fn linestring_parts(
+    ray: &Ray2D,
+    linestring: &geo::LineString<f32>,
+) -> Vec<Line2DIntersectionParts> {
+    linestring
+        .lines_iter()
+        .filter_map(|l| match ray.intersection(&LineSegment2D::from(l)) {
+            RayLine2DIntersection::Intersection(hit) => {
+                Some(Line2DIntersectionParts::Point(hit.point))
+            }
+            RayLine2DIntersection::CollinearOverlap(line) => {
+                Some(Line2DIntersectionParts::Line(line))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// normal of the segments `point` is located on. Points on corners get the mean normal of the
+/// adjacent segments
+fn segment_normal(linestrings: &[geo::LineString<f32>], point: Vec2) -> Vec2 {
+    linestrings
+        .iter()
+        .flat_map(|ls| ls.lines_iter())
+        .map(LineSegment2D::from)
+        .filter(|line| !line.is_degenerated() && line.is_point_on_line(point))
+        .map(|line| line.normal())
+        .sum::<Vec2>()
+        .normalize_or_zero()
+}
+
+impl NeoIntersects<geo::LineString<f32>> for Ray2D {
+    fn intersects(&self, rhs: &geo::LineString<f32>) -> bool {
+        rhs.lines_iter()
+            .any(|l| self.intersects(&LineSegment2D::from(l)))
+    }
+}
+
+impl NeoIntersects<geo::MultiLineString<f32>> for Ray2D {
+    fn intersects(&self, rhs: &geo::MultiLineString<f32>) -> bool {
+        rhs.iter().any(|ls| self.intersects(ls))
+    }
+}
+
+mirror_intersectable!(Ray2D => geo::LineString<f32>, geo::MultiLineString<f32>);
+
+#[cfg(test)]
+mod ray_linestring {
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::line_intersection_parts::Line2DIntersectionParts;
+    use crate::ray_hit::RayPart2D;
+    use crate::trait_def::NeoIntersectable;
+
+    fn parts_of(parts: Vec<RayPart2D>) -> Vec<Line2DIntersectionParts> {
+        parts.iter().map(RayPart2D::part).collect()
+    }
+
+    #[test]
+    fn linestring_works() {
+        let linestring =
+            geo::LineString::from(vec![(0.0, -1.0), (1.0, 1.0), (2.0, -1.0), (2.0, -3.0)]);
+        let ray = Ray2D::new(Vec2::X * 5.0, -Vec2::X);
+        assert_eq!(
+            parts_of(ray.intersection(&linestring)),
+            vec![
+                Line2DIntersectionParts::Point(Vec2::X * 1.5),
+                Line2DIntersectionParts::Point(Vec2::X * 0.5),
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_linestring_works() {
+        let lines = geo::MultiLineString::new(vec![
+            geo::LineString::from(vec![(3.0, 0.0), (4.0, 0.0), (5.0, 0.0)]),
+            geo::LineString::from(vec![(1.0, -1.0), (1.0, 1.0)]),
+        ]);
+        let ray = Ray2D::new(Vec2::ZERO, Vec2::X);
+        assert_eq!(
+            parts_of(ray.intersection(&lines)),
+            vec![
+                Line2DIntersectionParts::Point(Vec2::X),
+                Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::X * 3.0, Vec2::X * 5.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn linestring_hits_have_segment_normals() {
+        let linestring = geo::LineString::from(vec![(0.0, -1.0), (1.0, 1.0), (2.0, -1.0)]);
+        let ray = Ray2D::new(Vec2::X * 5.0, -Vec2::X);
+        let parts = ray.intersection(&linestring);
+        assert_eq!(parts.len(), 2);
+        let hit = parts[0].entry;
+        assert_eq!(parts[0].exit, hit);
+        assert_eq!(hit.t, 3.5);
+        assert!(hit
+            .normal
+            .abs_diff_eq(Vec2::new(2.0, 1.0).normalize(), 0.000_1));
+        assert!(hit.front_face);
+    }
+}
@@ -1,4 +1,8 @@
 pub mod aabb;
+pub mod capsule;
+pub mod circle;
 pub mod line;
+pub mod linestring;
+pub mod multipolygon;
 pub mod polygon;
 pub mod ray;
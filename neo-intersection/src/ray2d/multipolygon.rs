@@ -0,0 +1,157 @@
+use glam::Vec2;
+use neo_ray::d2::def::Ray2D;
+
+use crate::ray2d::polygon::boundary_normal;
+use crate::ray_hit::RayPart2D;
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<geo::MultiPolygon<f32>> for Ray2D {
+    type Output = Vec<RayPart2D>;
+
+    /// the hit normals are the outward normals of the polygons boundaries
+    fn intersection(&self, rhs: &geo::MultiPolygon<f32>) -> Self::Output {
+        let parts = rhs
+            .iter()
+            .flat_map(|polygon| self.intersection(polygon).list_parts())
+            .collect();
+        RayPart2D::sorted_along(self, parts, |p| {
+            rhs.iter()
+                .map(|polygon| boundary_normal(polygon, p))
+                .sum::<Vec2>()
+                .normalize_or_zero()
+        })
+    }
+}
+
+impl NeoIntersectable<geo::Rect<f32>> for Ray2D {
+    type Output = Vec<RayPart2D>;
+
+    fn intersection(&self, rhs: &geo::Rect<f32>) -> Self::Output {
+        let polygon = rhs.to_polygon();
+        let parts = self.intersection(&polygon).list_parts();
+        RayPart2D::sorted_along(self, parts, |p| boundary_normal(&polygon, p))
+    }
+}
+
+impl NeoIntersectable<geo::Triangle<f32>> for Ray2D {
+    type Output = Vec<RayPart2D>;
+
+    fn intersection(&self, rhs: &geo::Triangle<f32>) -> Self::Output {
+        let polygon = rhs.to_polygon();
+        let parts = self.intersection(&polygon).list_parts();
+        RayPart2D::sorted_along(self, parts, |p| boundary_normal(&polygon, p))
+    }
+}
+
+impl NeoIntersects<geo::MultiPolygon<f32>> for Ray2D {
+    fn intersects(&self, rhs: &geo::MultiPolygon<f32>) -> bool {
+        rhs.iter().any(|polygon| self.intersects(polygon))
+    }
+}
+
+impl NeoIntersects<geo::Rect<f32>> for Ray2D {
+    fn intersects(&self, rhs: &geo::Rect<f32>) -> bool {
+        self.intersects(&rhs.to_polygon())
+    }
+}
+
+impl NeoIntersects<geo::Triangle<f32>> for Ray2D {
+    fn intersects(&self, rhs: &geo::Triangle<f32>) -> bool {
+        self.intersects(&rhs.to_polygon())
+    }
+}
+
+mirror_intersectable!(Ray2D => geo::MultiPolygon<f32>, geo::Rect<f32>, geo::Triangle<f32>);
+
+#[cfg(test)]
+mod ray_multipolygon {
+    use glam::Vec2;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d2::def::Ray2D;
+
+    use crate::line_intersection_parts::Line2DIntersectionParts;
+    use crate::ray_hit::RayPart2D;
+    use crate::trait_def::NeoIntersectable;
+
+    fn parts_of(parts: Vec<RayPart2D>) -> Vec<Line2DIntersectionParts> {
+        parts.iter().map(RayPart2D::part).collect()
+    }
+
+    fn unit_square_at(offset: Vec2) -> geo::Polygon<f32> {
+        geo::Rect::new(offset.to_geo(), (offset + Vec2::ONE).to_geo()).to_polygon()
+    }
+
+    #[test]
+    fn multipolygon_parts_are_sorted() {
+        let polygons = geo::MultiPolygon::new(vec![
+            unit_square_at(Vec2::ZERO),
+            unit_square_at(Vec2::X * 4.0),
+        ]);
+        let ray = Ray2D::new(Vec2::new(10.0, 0.5), -Vec2::X);
+        assert_eq!(
+            parts_of(ray.intersection(&polygons)),
+            vec![
+                Line2DIntersectionParts::Line(LineSegment2D::new(
+                    Vec2::new(5.0, 0.5),
+                    Vec2::new(4.0, 0.5)
+                )),
+                Line2DIntersectionParts::Line(LineSegment2D::new(
+                    Vec2::new(1.0, 0.5),
+                    Vec2::new(0.0, 0.5)
+                )),
+            ]
+        );
+    }
+
+    #[test]
+    fn multipolygon_hits_have_outward_normals() {
+        let polygons = geo::MultiPolygon::new(vec![
+            unit_square_at(Vec2::ZERO),
+            unit_square_at(Vec2::X * 4.0),
+        ]);
+        let ray = Ray2D::new(Vec2::new(10.0, 0.5), -Vec2::X);
+        let hits = ray
+            .intersection(&polygons)
+            .iter()
+            .flat_map(|part| [part.entry, part.exit])
+            .map(|hit| (hit.t, hit.normal, hit.front_face))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hits,
+            vec![
+                (5.0, Vec2::X, true),
+                (6.0, -Vec2::X, false),
+                (9.0, Vec2::X, true),
+                (10.0, -Vec2::X, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn rect_works() {
+        let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo());
+        let ray = Ray2D::new(Vec2::new(2.0, 1.0), Vec2::new(-1.0, -1.0));
+        assert_eq!(
+            parts_of(ray.intersection(&rect)),
+            vec![Line2DIntersectionParts::Point(Vec2::X)]
+        );
+    }
+
+    #[test]
+    fn triangle_works() {
+        let triangle = geo::Triangle::new(
+            Vec2::ZERO.to_geo(),
+            (Vec2::X * 2.0).to_geo(),
+            (Vec2::Y * 2.0).to_geo(),
+        );
+        let ray = Ray2D::new(Vec2::new(0.5, -1.0), Vec2::Y);
+        assert_eq!(
+            parts_of(ray.intersection(&triangle)),
+            vec![Line2DIntersectionParts::Line(LineSegment2D::new(
+                Vec2::new(0.5, 0.0),
+                Vec2::new(0.5, 1.5)
+            ))]
+        );
+    }
+}
@@ -1,34 +1,89 @@
-use geo::Intersects;
+use geo::{Contains, Intersects, LinesIter};
 use glam::Vec2;
 use neo_bounded::traits::NeoBounded2D;
 use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
 use neo_line_segment::d2::def::LineSegment2D;
+use neo_ray::d2::constants::NEO_LINE_RAY_2D_EPS;
 use neo_ray::d2::def::Ray2D;
 
+use crate::intersection_set::IntersectionDimension;
 use crate::line2d::polygon::LinePolygon2DIntersection;
 use crate::line_intersection_parts::Line2DIntersectionParts;
-use crate::ray2d::aabb::RayAABB2DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::ray2d::aabb::{slab_parameters, RayAABB2DIntersection};
+use crate::ray_hit::{RayHit2D, RayPart2D};
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
 
 #[derive(Debug, PartialEq)]
 pub enum RayPolygon2DIntersection {
     None,
-    Point(Vec2),
-    Line(LineSegment2D),
-    Parts(Vec<Line2DIntersectionParts>),
+    /// the ray only touches the polygon. The hit normal is the outward normal of the polygons
+    /// boundary
+    Point(RayHit2D),
+    /// the ray runs through the polygon. The hit normals are the outward normals of the polygons
+    /// boundary
+    Line {
+        entry: RayHit2D,
+        exit: RayHit2D,
+    },
+    /// parts are sorted along the ray direction
+    Parts(Vec<RayPart2D>),
 }
 
 impl RayPolygon2DIntersection {
     pub fn list_parts(self) -> Vec<Line2DIntersectionParts> {
+        self.list_ray_parts().iter().map(RayPart2D::part).collect()
+    }
+
+    /// like [`RayPolygon2DIntersection::list_parts`], but keeps the hits
+    pub fn list_ray_parts(self) -> Vec<RayPart2D> {
         match self {
             RayPolygon2DIntersection::None => vec![],
-            RayPolygon2DIntersection::Point(p) => vec![Line2DIntersectionParts::Point(p)],
-            RayPolygon2DIntersection::Line(l) => vec![Line2DIntersectionParts::Line(l)],
+            RayPolygon2DIntersection::Point(hit) => vec![RayPart2D {
+                entry: hit,
+                exit: hit,
+            }],
+            RayPolygon2DIntersection::Line { entry, exit } => vec![RayPart2D { entry, exit }],
             RayPolygon2DIntersection::Parts(ps) => ps,
         }
     }
 }
 
+impl NeoIntersectionResult for RayPolygon2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayPolygon2DIntersection::None => None,
+            RayPolygon2DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            RayPolygon2DIntersection::Line { .. } => Some(IntersectionDimension::Line),
+            RayPolygon2DIntersection::Parts(ps) => ps.dimension(),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            RayPolygon2DIntersection::Point(hit) => vec![hit.point],
+            RayPolygon2DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            RayPolygon2DIntersection::Line { entry, exit } => {
+                vec![LineSegment2D::new(entry.point, exit.point)]
+            }
+            RayPolygon2DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable<geo::Polygon<f32>> for Ray2D {
     type Output = RayPolygon2DIntersection;
 
@@ -37,33 +92,91 @@ impl NeoIntersectable<geo::Polygon<f32>> for Ray2D {
         let aabb_inter = self.intersection(&rhs_aabb);
         match aabb_inter {
             RayAABB2DIntersection::None => RayPolygon2DIntersection::None,
-            RayAABB2DIntersection::Point(p) => aabb_point_case_analysis(p, rhs),
-            RayAABB2DIntersection::Line(aabb_line) => aabb_line_case_analysis(aabb_line, rhs),
+            RayAABB2DIntersection::Point(hit) => aabb_point_case_analysis(self, hit.point, rhs),
+            RayAABB2DIntersection::Line { entry, exit } => {
+                aabb_line_case_analysis(self, LineSegment2D::new(entry.point, exit.point), rhs)
+            }
         }
     }
 }
 
-fn aabb_point_case_analysis(point: Vec2, rhs: &geo::Polygon<f32>) -> RayPolygon2DIntersection {
-    if rhs.intersects(&point.to_geo()) {
-        RayPolygon2DIntersection::Point(point)
+impl NeoIntersects<geo::Polygon<f32>> for Ray2D {
+    /// rays are unbounded, so they can't lie inside of the polygon without crossing its boundary
+    fn intersects(&self, rhs: &geo::Polygon<f32>) -> bool {
+        slab_parameters(self, &rhs.aabb()).is_some()
+            && rhs
+                .lines_iter()
+                .any(|l| self.intersects(&LineSegment2D::from(l)))
+    }
+}
+
+mirror_intersectable!(Ray2D => geo::Polygon<f32>);
+
+fn aabb_point_case_analysis(
+    ray: &Ray2D,
+    point: Vec2,
+    rhs: &geo::Polygon<f32>,
+) -> RayPolygon2DIntersection {
+    if Intersects::intersects(rhs, &point.to_geo()) {
+        RayPolygon2DIntersection::Point(touching_hit(ray, point, rhs))
     } else {
         RayPolygon2DIntersection::None
     }
 }
 
 fn aabb_line_case_analysis(
+    ray: &Ray2D,
     aabb_line: LineSegment2D,
     rhs: &geo::Polygon<f32>,
 ) -> RayPolygon2DIntersection {
     let inter = aabb_line.intersection(rhs);
     match inter {
         LinePolygon2DIntersection::None => RayPolygon2DIntersection::None,
-        LinePolygon2DIntersection::Point(p) => RayPolygon2DIntersection::Point(p),
-        LinePolygon2DIntersection::Line(l) => RayPolygon2DIntersection::Line(l),
-        LinePolygon2DIntersection::Parts(ps) => RayPolygon2DIntersection::Parts(ps),
+        LinePolygon2DIntersection::Point(p) => {
+            RayPolygon2DIntersection::Point(touching_hit(ray, p, rhs))
+        }
+        LinePolygon2DIntersection::Line(l) => {
+            let RayPart2D { entry, exit } = ray_part(ray, Line2DIntersectionParts::Line(l), rhs);
+            RayPolygon2DIntersection::Line { entry, exit }
+        }
+        LinePolygon2DIntersection::Parts(ps) => RayPolygon2DIntersection::Parts(
+            ps.into_iter()
+                .map(|part| ray_part(ray, part, rhs))
+                .collect(),
+        ),
     }
 }
 
+/// the parts of the clipped ray are sorted along the ray already
+fn ray_part(ray: &Ray2D, part: Line2DIntersectionParts, polygon: &geo::Polygon<f32>) -> RayPart2D {
+    RayPart2D::from_part(ray, part, |p| boundary_normal(polygon, p))
+}
+
+fn touching_hit(ray: &Ray2D, point: Vec2, polygon: &geo::Polygon<f32>) -> RayHit2D {
+    RayHit2D::at_point(ray, point, boundary_normal(polygon, point))
+}
+
+/// Outward normal of the polygon boundary at `point`. Points on corners get the mean normal of the
+/// adjacent edges
+pub(crate) fn boundary_normal(polygon: &geo::Polygon<f32>, point: Vec2) -> Vec2 {
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .flat_map(|ring| ring.lines())
+        .map(|line| LineSegment2D::new(line.start.to_glam(), line.end.to_glam()))
+        .filter(|line| !line.is_degenerated() && line.is_point_on_line(point))
+        .map(|line| {
+            let normal = line.normal();
+            let probe = line.center() + normal * NEO_LINE_RAY_2D_EPS;
+            if polygon.contains(&probe.to_geo()) {
+                -normal
+            } else {
+                normal
+            }
+        })
+        .sum::<Vec2>()
+        .normalize_or_zero()
+}
+
 #[cfg(test)]
 mod ray_polygon {
     use glam::Vec2;
@@ -75,6 +188,18 @@ mod ray_polygon {
     use crate::ray2d::polygon::RayPolygon2DIntersection;
     use crate::trait_def::NeoIntersectable;
 
+    fn segment_of(inter: RayPolygon2DIntersection) -> LineSegment2D {
+        let RayPolygon2DIntersection::Line { entry, exit } = inter else {
+            panic!("expected the ray to run through the polygon, got {inter:?}");
+        };
+        LineSegment2D::new(entry.point, exit.point)
+    }
+
+    fn parts_of(inter: RayPolygon2DIntersection) -> Vec<Line2DIntersectionParts> {
+        assert!(matches!(inter, RayPolygon2DIntersection::Parts(_)));
+        inter.list_parts()
+    }
+
     fn generate_rect_with_hole() -> geo::Polygon<f32> {
         let ext = geo::Rect::new(Vec2::ZERO.to_geo(), (Vec2::ONE * 3.0).to_geo())
             .to_polygon()
@@ -92,8 +217,8 @@ mod ray_polygon {
         let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
         let ray = Ray2D::new(Vec2::ZERO, Vec2::ONE * 0.5);
         assert_eq!(
-            ray.intersection(&rect),
-            RayPolygon2DIntersection::Line(LineSegment2D::new(Vec2::ZERO, Vec2::ONE))
+            segment_of(ray.intersection(&rect)),
+            LineSegment2D::new(Vec2::ZERO, Vec2::ONE)
         )
     }
 
@@ -102,8 +227,8 @@ mod ray_polygon {
         let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
         let ray = Ray2D::new(Vec2::ZERO, Vec2::ONE * 0.5).offset_origin_by(Vec2::ONE * 0.5);
         assert_eq!(
-            ray.intersection(&rect),
-            RayPolygon2DIntersection::Line(LineSegment2D::new(Vec2::ZERO, Vec2::ONE))
+            segment_of(ray.intersection(&rect)),
+            LineSegment2D::new(Vec2::ZERO, Vec2::ONE)
         )
     }
 
@@ -121,8 +246,8 @@ mod ray_polygon {
         let ray = Ray2D::new(Vec2::NEG_ONE, Vec2::ONE * 2.0);
 
         assert_eq!(
-            ray.intersection(&rect),
-            RayPolygon2DIntersection::Line(LineSegment2D::new(Vec2::ZERO, Vec2::ONE))
+            segment_of(ray.intersection(&rect)),
+            LineSegment2D::new(Vec2::ZERO, Vec2::ONE)
         )
     }
 
@@ -131,10 +256,14 @@ mod ray_polygon {
         let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
         let ray = Ray2D::new(Vec2::NEG_ONE, Vec2::ONE * 2.0).offset_origin_by(Vec2::Y);
 
-        assert_eq!(
-            ray.intersection(&rect),
-            RayPolygon2DIntersection::Point(Vec2::Y)
-        )
+        let RayPolygon2DIntersection::Point(hit) = ray.intersection(&rect) else {
+            panic!("expected the ray to touch the corner");
+        };
+        assert_eq!(hit.t, 0.5);
+        assert_eq!(hit.point, Vec2::Y);
+        assert!(hit
+            .normal
+            .abs_diff_eq((Vec2::Y - Vec2::X).normalize(), 0.000_1));
     }
 
     #[test]
@@ -143,13 +272,13 @@ mod ray_polygon {
         let ray = Ray2D::new(Vec2::ZERO, Vec2::X).offset_origin_by(Vec2::Y);
 
         assert_eq!(
-            ray.intersection(&rect_with_hole),
-            RayPolygon2DIntersection::Parts(vec![
+            parts_of(ray.intersection(&rect_with_hole)),
+            vec![
                 Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::Y, Vec2::Y + Vec2::X)),
                 Line2DIntersectionParts::Line(
                     LineSegment2D::new(Vec2::Y, Vec2::Y + Vec2::X).offset_line_by(Vec2::X * 2.0)
                 ),
-            ])
+            ]
         )
     }
 
@@ -159,13 +288,72 @@ mod ray_polygon {
         let ray = Ray2D::new(Vec2::ZERO, Vec2::X * 2.0).offset_origin_by(Vec2::Y);
 
         assert_eq!(
-            ray.intersection(&rect_with_hole),
-            RayPolygon2DIntersection::Parts(vec![
+            parts_of(ray.intersection(&rect_with_hole)),
+            vec![
                 Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::Y, Vec2::Y + Vec2::X)),
                 Line2DIntersectionParts::Line(
                     LineSegment2D::new(Vec2::Y, Vec2::Y + Vec2::X).offset_line_by(Vec2::X * 2.0)
                 ),
-            ])
+            ]
+        )
+    }
+
+    #[test]
+    fn intersection_parts_are_sorted_along_ray() {
+        let rect_with_hole = generate_rect_with_hole();
+        let ray = Ray2D::new(Vec2::new(5.0, 1.0), -Vec2::X);
+
+        assert_eq!(
+            parts_of(ray.intersection(&rect_with_hole)),
+            vec![
+                Line2DIntersectionParts::Line(LineSegment2D::new(
+                    Vec2::new(3.0, 1.0),
+                    Vec2::new(2.0, 1.0)
+                )),
+                Line2DIntersectionParts::Line(LineSegment2D::new(Vec2::Y + Vec2::X, Vec2::Y)),
+            ]
         )
     }
+
+    #[test]
+    fn intersection_parts_have_hits() {
+        let rect_with_hole = generate_rect_with_hole();
+        let ray = Ray2D::new(Vec2::new(-1.0, 1.5), Vec2::X);
+
+        let RayPolygon2DIntersection::Parts(parts) = ray.intersection(&rect_with_hole) else {
+            panic!("expected the ray to cross the hole");
+        };
+        let hits = parts
+            .iter()
+            .flat_map(|part| [part.entry, part.exit])
+            .map(|hit| (hit.t, hit.normal, hit.front_face))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hits,
+            vec![
+                (1.0, -Vec2::X, true),
+                (2.0, Vec2::X, false),
+                (3.0, -Vec2::X, true),
+                (4.0, Vec2::X, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_has_hits() {
+        let rect = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
+        let ray = Ray2D::new(Vec2::new(2.0, 0.5), -Vec2::X);
+
+        let RayPolygon2DIntersection::Line { entry, exit } = ray.intersection(&rect) else {
+            panic!("expected the ray to run through the polygon");
+        };
+        assert_eq!(
+            (entry.t, entry.normal, entry.front_face),
+            (1.0, Vec2::X, true)
+        );
+        assert_eq!(
+            (exit.t, exit.normal, exit.front_face),
+            (2.0, -Vec2::X, false)
+        );
+    }
 }
@@ -1,24 +1,50 @@
 use glam::Vec2;
+use neo_line_segment::d2::def::LineSegment2D;
 use neo_ray::d2::def::Ray2D;
 
-use crate::trait_def::NeoIntersectable;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::RayHit2D;
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
 
 #[derive(Debug, PartialEq)]
 pub enum RayRay2DIntersection {
     Parallel,
     Collinear,
-    Intersection(Vec2),
+    /// the hit normal is the normal of the other ray
+    Intersection(RayHit2D),
 }
 
 impl RayRay2DIntersection {
     pub fn intersection_point(&self) -> Option<Vec2> {
         match self {
-            RayRay2DIntersection::Intersection(p) => Some(*p),
+            RayRay2DIntersection::Intersection(hit) => Some(hit.point),
             _ => None,
         }
     }
 }
 
+impl NeoIntersectionResult for RayRay2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    /// collinear rays overlap on an unbounded line, which isn't listed
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayRay2DIntersection::Parallel => None,
+            RayRay2DIntersection::Collinear => Some(IntersectionDimension::Line),
+            RayRay2DIntersection::Intersection(_) => Some(IntersectionDimension::Point),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            RayRay2DIntersection::Intersection(hit) => vec![hit.point],
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable for Ray2D {
     type Output = RayRay2DIntersection;
 
@@ -31,6 +57,13 @@ impl NeoIntersectable for Ray2D {
     }
 }
 
+/// only parallel rays which aren't collinear miss each other, no hit is built
+impl NeoIntersects for Ray2D {
+    fn intersects(&self, rhs: &Self) -> bool {
+        !self.is_parallel_to(rhs) || self.is_point_on_ray(rhs.origin)
+    }
+}
+
 pub(crate) fn classify_parallel_relation_to(r1: &Ray2D, r2: &Ray2D) -> RayRay2DIntersection {
     if r1.is_point_on_ray(r2.origin) {
         RayRay2DIntersection::Collinear
@@ -40,11 +73,12 @@ pub(crate) fn classify_parallel_relation_to(r1: &Ray2D, r2: &Ray2D) -> RayRay2DI
 }
 
 pub(crate) fn classify_intersecting_relation_to(r1: &Ray2D, r2: &Ray2D) -> RayRay2DIntersection {
-    let intersection_point = calculate_intersection_point(r1, r2);
-    RayRay2DIntersection::Intersection(intersection_point)
+    let t = calculate_intersection_parameter(r1, r2);
+    let normal = r2.direction().perp().normalize_or_zero();
+    RayRay2DIntersection::Intersection(RayHit2D::new(r1, t, normal))
 }
 
-pub(crate) fn calculate_intersection_point(r1: &Ray2D, r2: &Ray2D) -> Vec2 {
+pub(crate) fn calculate_intersection_parameter(r1: &Ray2D, r2: &Ray2D) -> f32 {
     // Calculate the intersection point using line-line intersection formula
     let d = r1.direction();
     let e = r2.direction();
@@ -53,19 +87,14 @@ pub(crate) fn calculate_intersection_point(r1: &Ray2D, r2: &Ray2D) -> Vec2 {
     let cross_de = d.perp_dot(e);
     let cross_ef = e.perp_dot(f);
 
-    let t = cross_ef / cross_de;
-
-    r1.origin + d * t
+    cross_ef / cross_de
 }
 
 #[test]
 fn intersection_first_works() {
     let l1 = Ray2D::new(Vec2::ONE, -Vec2::Y * 0.5);
     let l2 = Ray2D::new(Vec2::ZERO, Vec2::X * 2.0);
-    assert_eq!(
-        l2.intersection(&l1),
-        RayRay2DIntersection::Intersection(Vec2::X)
-    )
+    assert_eq!(l2.intersection(&l1).intersection_point(), Some(Vec2::X))
 }
 
 #[test]
@@ -73,11 +102,23 @@ fn intersection_both_works() {
     let l1 = Ray2D::new(Vec2::ZERO, Vec2::ONE);
     let l2 = Ray2D::new(Vec2::X, -Vec2::X + Vec2::Y);
     assert_eq!(
-        classify_intersecting_relation_to(&l1, &l2),
-        RayRay2DIntersection::Intersection(Vec2::ONE * 0.5)
+        classify_intersecting_relation_to(&l1, &l2).intersection_point(),
+        Some(Vec2::ONE * 0.5)
     )
 }
 
+#[test]
+fn intersection_hit_works() {
+    let l1 = Ray2D::new(Vec2::new(1.0, 2.0), -Vec2::Y * 2.0);
+    let RayRay2DIntersection::Intersection(hit) = l1.intersection(&Ray2D::X) else {
+        panic!("expected an intersection");
+    };
+    assert_eq!(hit.t, 1.0);
+    assert_eq!(hit.point, Vec2::X);
+    assert_eq!(hit.normal, Vec2::Y);
+    assert!(hit.front_face);
+}
+
 #[test]
 fn parallel_works() {
     let l1 = Ray2D::X;
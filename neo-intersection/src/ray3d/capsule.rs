@@ -0,0 +1,103 @@
+use neo_primitive::d3::capsule::Capsule3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+
+use crate::ray3d::cylinder::cylinder_side_hits;
+use crate::ray3d::sphere::sphere_hits;
+use crate::ray_hit::{RayHit3D, RaySolid3DIntersection};
+use crate::trait_def::{mirror_intersectable, NeoDistance, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Capsule3D> for Ray3D {
+    type Output = RaySolid3DIntersection;
+
+    fn intersection(&self, rhs: &Capsule3D) -> Self::Output {
+        let axis = rhs.axis;
+        let length = axis.length();
+        if length < NEO_LINE_RAY_3D_EPS {
+            return RaySolid3DIntersection::from_hits(sphere_hits(self, axis.src, rhs.radius));
+        }
+        let dir = axis.direction_normalized();
+        let height_of = |hit: &RayHit3D| (hit.point - axis.src).dot(dir);
+
+        let src_cap_hits = sphere_hits(self, axis.src, rhs.radius)
+            .into_iter()
+            .filter(|hit| height_of(hit) <= 0.0);
+        let dst_cap_hits = sphere_hits(self, axis.dst, rhs.radius)
+            .into_iter()
+            .filter(|hit| height_of(hit) >= length);
+
+        let hits = cylinder_side_hits(self, &axis, rhs.radius)
+            .into_iter()
+            .chain(src_cap_hits)
+            .chain(dst_cap_hits)
+            .collect();
+        RaySolid3DIntersection::from_hits(hits)
+    }
+}
+
+impl NeoIntersects<Capsule3D> for Ray3D {
+    fn intersects(&self, rhs: &Capsule3D) -> bool {
+        !self.is_degenerated() && rhs.axis.distance(self).distance <= rhs.radius
+    }
+}
+
+mirror_intersectable!(Ray3D => Capsule3D);
+
+#[cfg(test)]
+mod ray_capsule {
+    use glam::Vec3;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_primitive::d3::capsule::Capsule3D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::ray_hit::RaySolid3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    fn pipe() -> Capsule3D {
+        Capsule3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::X * 4.0), 1.0)
+    }
+
+    #[test]
+    fn through_side_works() {
+        let ray = Ray3D::new(Vec3::new(2.0, 0.0, 3.0), -Vec3::Z);
+        let intersection = ray.intersection(&pipe());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!((entry.t - 2.0).abs() < EPS);
+        assert!((exit.t - 4.0).abs() < EPS);
+        assert!(entry.normal.abs_diff_eq(Vec3::Z, EPS));
+        assert!(exit.normal.abs_diff_eq(-Vec3::Z, EPS));
+    }
+
+    #[test]
+    fn along_axis_works() {
+        let ray = Ray3D::new(-Vec3::X * 3.0, Vec3::X);
+        let intersection = ray.intersection(&pipe());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(-Vec3::X, EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::X * 5.0, EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec3::X, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec3::X, EPS));
+    }
+
+    #[test]
+    fn degenerated_axis_is_sphere() {
+        let ball = Capsule3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::ZERO), 1.0);
+        let ray = Ray3D::new(-Vec3::Y * 2.0, Vec3::Y);
+        let intersection = ray.intersection(&ball);
+
+        assert!((intersection.entry().unwrap().t - 1.0).abs() < EPS);
+        assert!((intersection.exit().unwrap().t - 3.0).abs() < EPS);
+    }
+
+    #[test]
+    fn miss_works() {
+        let ray = Ray3D::new(Vec3::new(5.0, 0.0, 1.0), Vec3::Y);
+        assert_eq!(ray.intersection(&pipe()), RaySolid3DIntersection::None);
+    }
+}
@@ -0,0 +1,135 @@
+use glam::Vec3;
+use neo_primitive::d3::cone::Cone3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+
+use crate::ray3d::cylinder::{axis_slab_parameters, disk_hit};
+use crate::ray_hit::{
+    has_quadratic_non_positive_in, quadratic_roots, RayHit3D, RaySolid3DIntersection,
+};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Cone3D> for Ray3D {
+    type Output = RaySolid3DIntersection;
+
+    fn intersection(&self, rhs: &Cone3D) -> Self::Output {
+        let height = rhs.height();
+        if self.is_degenerated() || height < NEO_LINE_RAY_3D_EPS {
+            return RaySolid3DIntersection::None;
+        }
+        let dir = rhs.axis().direction_normalized();
+        let slope = rhs.radius / height;
+        let (a, b, c) = cone_coefficients(self, rhs);
+
+        let side_hits = quadratic_roots(a, b, c)
+            .into_iter()
+            .map(|t| {
+                let point = self.origin + self.direction * t;
+                let to_point = point - rhs.apex;
+                let radial = (to_point - dir * to_point.dot(dir)).normalize_or_zero();
+                let normal = if radial == Vec3::ZERO {
+                    -dir
+                } else {
+                    (radial - dir * slope).normalize()
+                };
+                RayHit3D::new(self, t, normal)
+            })
+            .filter(|hit| (0.0..=height).contains(&(hit.point - rhs.apex).dot(dir)));
+        let base_hit = disk_hit(self, rhs.base_center, dir, rhs.radius);
+
+        RaySolid3DIntersection::from_hits(side_hits.chain(base_hit).collect())
+    }
+}
+
+impl NeoIntersects<Cone3D> for Ray3D {
+    fn intersects(&self, rhs: &Cone3D) -> bool {
+        let height = rhs.height();
+        if self.is_degenerated() || height < NEO_LINE_RAY_3D_EPS {
+            return false;
+        }
+        let dir = rhs.axis().direction_normalized();
+        let apex_height = (self.origin - rhs.apex).dot(dir);
+        let Some(range) = axis_slab_parameters(self, dir, apex_height, height) else {
+            return false;
+        };
+        let (a, b, c) = cone_coefficients(self, rhs);
+        has_quadratic_non_positive_in(a, b, c, range)
+    }
+}
+
+mirror_intersectable!(Ray3D => Cone3D);
+
+/// coefficients of `a t² + b t + c` for the parameters where the ray meets the infinite double
+/// cone, the polynomial is negative inside of it
+fn cone_coefficients(ray: &Ray3D, cone: &Cone3D) -> (f32, f32, f32) {
+    let dir = cone.axis().direction_normalized();
+    let slope = cone.radius / cone.height();
+    let steepness = 1.0 + slope * slope;
+
+    // points on the infinite double cone satisfy |p - apex|^2 = (1 + slope^2) * h^2 with h
+    // being the height of p along the axis
+    let offset = ray.origin - cone.apex;
+    let a = ray.direction.length_squared() - steepness * ray.direction.dot(dir).powi(2);
+    let b =
+        2.0 * (offset.dot(ray.direction) - steepness * offset.dot(dir) * ray.direction.dot(dir));
+    let c = offset.length_squared() - steepness * offset.dot(dir).powi(2);
+    (a, b, c)
+}
+
+#[cfg(test)]
+mod ray_cone {
+    use glam::Vec3;
+    use neo_primitive::d3::cone::Cone3D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::ray_hit::RaySolid3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    fn cone() -> Cone3D {
+        Cone3D::new(Vec3::Z * 2.0, Vec3::ZERO, 1.0)
+    }
+
+    #[test]
+    fn through_side_works() {
+        let ray = Ray3D::new(Vec3::new(-2.0, 0.0, 1.0), Vec3::X);
+        let intersection = ray.intersection(&cone());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::new(-0.5, 0.0, 1.0), EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::new(0.5, 0.0, 1.0), EPS));
+        let expected_normal = Vec3::new(-2.0, 0.0, 1.0).normalize();
+        assert!(entry.normal.abs_diff_eq(expected_normal, EPS));
+    }
+
+    #[test]
+    fn through_apex_and_base_works() {
+        let ray = Ray3D::new(Vec3::Z * 3.0, -Vec3::Z);
+        let intersection = ray.intersection(&cone());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::Z * 2.0, EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::ZERO, EPS));
+        assert!(exit.normal.abs_diff_eq(-Vec3::Z, EPS));
+    }
+
+    #[test]
+    fn through_side_and_base_works() {
+        let ray = Ray3D::new(Vec3::new(0.5, 0.0, 2.0), -Vec3::Z);
+        let intersection = ray.intersection(&cone());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::new(0.5, 0.0, 1.0), EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::new(0.5, 0.0, 0.0), EPS));
+    }
+
+    #[test]
+    fn mirrored_cone_is_ignored() {
+        let ray = Ray3D::new(Vec3::new(-2.0, 0.0, 3.0), Vec3::X);
+        assert_eq!(ray.intersection(&cone()), RaySolid3DIntersection::None);
+    }
+}
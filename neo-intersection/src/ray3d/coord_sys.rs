@@ -1,17 +1,55 @@
 use glam::Vec3;
 use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
 use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
 
-use crate::trait_def::NeoIntersectable;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::RayHit3D;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RayCoordSys3DIntersection {
     Parallel,
-    Point(Vec3),
+    /// the hit normal is the normal of the coordinate systems plane
+    Point(RayHit3D),
     Ray(Ray3D),
 }
 
+impl RayCoordSys3DIntersection {
+    pub fn intersection_point(&self) -> Option<Vec3> {
+        match self {
+            RayCoordSys3DIntersection::Point(hit) => Some(hit.point),
+            _ => None,
+        }
+    }
+}
+
+impl NeoIntersectionResult for RayCoordSys3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    /// a ray in the plane is unbounded, so it isn't listed
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayCoordSys3DIntersection::Parallel => None,
+            RayCoordSys3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            RayCoordSys3DIntersection::Ray(_) => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            RayCoordSys3DIntersection::Point(hit) => vec![hit.point],
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable<CoordinateSystem> for Ray3D {
     type Output = RayCoordSys3DIntersection;
     fn intersection(&self, rhs: &CoordinateSystem) -> Self::Output {
@@ -23,16 +61,29 @@ impl NeoIntersectable<CoordinateSystem> for Ray3D {
     }
 }
 
+/// only checks the direction against the plane, without locating the hit
+impl NeoIntersects<CoordinateSystem> for Ray3D {
+    fn intersects(&self, rhs: &CoordinateSystem) -> bool {
+        is_ray_on_coord_sys(self, rhs) || !is_ray_parallel_to_coord_sys(self, rhs)
+    }
+}
+
+mirror_intersectable!(Ray3D => CoordinateSystem);
+
 pub(crate) fn is_ray_on_coord_sys(ray: &Ray3D, coordinate_system: &CoordinateSystem) -> bool {
     coordinate_system.is_point_in_coordinate_system(ray.origin)
         && coordinate_system.is_point_in_coordinate_system(ray.origin + ray.direction)
 }
 
+fn is_ray_parallel_to_coord_sys(ray: &Ray3D, coordinate_system: &CoordinateSystem) -> bool {
+    ray.direction.dot(coordinate_system.plane.normal).abs() < NEO_LINE_RAY_3D_EPS
+}
+
 pub(crate) fn classify_intersection_cases(
     ray: &Ray3D,
     coordinate_system: &CoordinateSystem,
 ) -> RayCoordSys3DIntersection {
-    if ray.direction.dot(coordinate_system.plane.normal) < NEO_LINE_RAY_3D_EPS {
+    if is_ray_parallel_to_coord_sys(ray, coordinate_system) {
         RayCoordSys3DIntersection::Parallel
     } else {
         calculate_intersection_point_with_plane(ray, coordinate_system)
@@ -48,7 +99,8 @@ pub(crate) fn calculate_intersection_point_with_plane(
     let dot = ray.direction.dot(coordinate_system.plane.normal);
     let w = ray.origin - coordinate_system.origin;
     let fac = -coordinate_system.plane.normal.dot(w) / dot;
-    RayCoordSys3DIntersection::Point(ray.origin + ray.direction * fac)
+    let normal = coordinate_system.plane.normal.normalize();
+    RayCoordSys3DIntersection::Point(RayHit3D::new(ray, fac, normal))
 }
 
 #[test]
@@ -71,10 +123,38 @@ fn intersection_in_coordinate_system_works() {
     let ray = Ray3D::new(Vec3::ONE - Vec3::Y, Vec3::ONE);
     assert_eq!(
         ray.intersection(&c),
-        RayCoordSys3DIntersection::Point(Vec3::ONE - Vec3::Y)
+        RayCoordSys3DIntersection::Point(RayHit3D {
+            t: 0.0,
+            point: Vec3::ONE - Vec3::Y,
+            normal: Vec3::Y,
+            front_face: false,
+        })
     );
 }
 
+#[test]
+fn intersection_against_normal_works() {
+    let c = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Y);
+    let ray = Ray3D::new(Vec3::Y * 2.0, -Vec3::Y * 4.0);
+    let RayCoordSys3DIntersection::Point(hit) = ray.intersection(&c) else {
+        panic!("expected a hit");
+    };
+    assert_eq!(hit.t, 0.5);
+    assert_eq!(hit.point, Vec3::ZERO);
+    assert!(hit.front_face);
+}
+
+#[test]
+fn intersection_behind_origin_works() {
+    let c = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Y);
+    let ray = Ray3D::new(Vec3::Y, Vec3::Y);
+    let RayCoordSys3DIntersection::Point(hit) = ray.intersection(&c) else {
+        panic!("expected a hit");
+    };
+    assert_eq!(hit.t, -1.0);
+    assert!(hit.is_behind_origin());
+}
+
 #[test]
 fn ray_not_on_coordinate_system_works() {
     let dir = Vec3::Y * 0.43 + Vec3::X * 34.0;
@@ -0,0 +1,177 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_primitive::d3::cylinder::Cylinder3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+
+use crate::ray_hit::{
+    has_quadratic_non_positive_in, quadratic_roots, RayHit3D, RaySolid3DIntersection,
+};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Cylinder3D> for Ray3D {
+    type Output = RaySolid3DIntersection;
+
+    fn intersection(&self, rhs: &Cylinder3D) -> Self::Output {
+        if self.is_degenerated() || rhs.height() < NEO_LINE_RAY_3D_EPS {
+            return RaySolid3DIntersection::None;
+        }
+        let dir = rhs.axis.direction_normalized();
+        let caps = [
+            disk_hit(self, rhs.axis.src, -dir, rhs.radius),
+            disk_hit(self, rhs.axis.dst, dir, rhs.radius),
+        ];
+        let hits = cylinder_side_hits(self, &rhs.axis, rhs.radius)
+            .into_iter()
+            .chain(caps.into_iter().flatten())
+            .collect();
+        RaySolid3DIntersection::from_hits(hits)
+    }
+}
+
+impl NeoIntersects<Cylinder3D> for Ray3D {
+    fn intersects(&self, rhs: &Cylinder3D) -> bool {
+        if self.is_degenerated() || rhs.height() < NEO_LINE_RAY_3D_EPS {
+            return false;
+        }
+        let dir = rhs.axis.direction_normalized();
+        let reject = |v: Vec3| v - dir * v.dot(dir);
+        let offset = self.origin - rhs.axis.src;
+        let Some(range) = axis_slab_parameters(self, dir, offset.dot(dir), rhs.height()) else {
+            return false;
+        };
+        // squared distance to the axis minus the squared radius, negative inside the cylinder
+        let (offset, speed) = (reject(offset), reject(self.direction));
+        let a = speed.length_squared();
+        let b = 2.0 * offset.dot(speed);
+        let c = offset.length_squared() - rhs.radius * rhs.radius;
+        has_quadratic_non_positive_in(a, b, c, range)
+    }
+}
+
+mirror_intersectable!(Ray3D => Cylinder3D);
+
+/// Hits with the curved side of the cylinder around `axis`, restricted to the length of the axis
+pub(crate) fn cylinder_side_hits(ray: &Ray3D, axis: &LineSegment3D, radius: f32) -> Vec<RayHit3D> {
+    let dir = axis.direction_normalized();
+    let length = axis.length();
+    let reject = |v: Vec3| v - dir * v.dot(dir);
+
+    let offset = reject(ray.origin - axis.src);
+    let speed = reject(ray.direction);
+    if speed.length() < NEO_LINE_RAY_3D_EPS {
+        return vec![];
+    }
+    let a = speed.length_squared();
+    let b = 2.0 * offset.dot(speed);
+    let c = offset.length_squared() - radius * radius;
+    quadratic_roots(a, b, c)
+        .into_iter()
+        .map(|t| {
+            let point = ray.origin + ray.direction * t;
+            RayHit3D::new(ray, t, reject(point - axis.src).normalize_or_zero())
+        })
+        .filter(|hit| (0.0..=length).contains(&(hit.point - axis.src).dot(dir)))
+        .collect()
+}
+
+/// parameters `(t_entry, t_exit)` of the part of the ray whose height along the normalized axis
+/// `dir` lies in `[0, length]`. `height` is the height of the rays origin
+pub(crate) fn axis_slab_parameters(
+    ray: &Ray3D,
+    dir: Vec3,
+    height: f32,
+    length: f32,
+) -> Option<(f32, f32)> {
+    let speed = ray.direction.dot(dir);
+    if speed == 0.0 {
+        return (0.0..=length)
+            .contains(&height)
+            .then_some((f32::NEG_INFINITY, f32::INFINITY));
+    }
+    let (t0, t1) = (-height / speed, (length - height) / speed);
+    Some((t0.min(t1), t0.max(t1)))
+}
+
+/// Hit with the flat disk around `center`. The `normal` is the outward facing normal of the disk
+pub(crate) fn disk_hit(ray: &Ray3D, center: Vec3, normal: Vec3, radius: f32) -> Option<RayHit3D> {
+    let speed = ray.direction.dot(normal);
+    (speed.abs() > NEO_LINE_RAY_3D_EPS)
+        .then(|| {
+            let t = (center - ray.origin).dot(normal) / speed;
+            RayHit3D::new(ray, t, normal)
+        })
+        .filter(|hit| hit.point.distance_squared(center) <= radius * radius)
+}
+
+#[cfg(test)]
+mod ray_cylinder {
+    use glam::Vec3;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_primitive::d3::cylinder::Cylinder3D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::ray_hit::RaySolid3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    fn column() -> Cylinder3D {
+        Cylinder3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::Z * 3.0), 0.5)
+    }
+
+    #[test]
+    fn through_side_works() {
+        let ray = Ray3D::new(Vec3::new(-2.0, 0.0, 1.0), Vec3::X);
+        let intersection = ray.intersection(&column());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!((entry.t - 1.5).abs() < EPS);
+        assert!((exit.t - 2.5).abs() < EPS);
+        assert!(entry.normal.abs_diff_eq(-Vec3::X, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec3::X, EPS));
+    }
+
+    #[test]
+    fn through_caps_works() {
+        let ray = Ray3D::new(Vec3::new(0.25, 0.0, 5.0), -Vec3::Z);
+        let intersection = ray.intersection(&column());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::new(0.25, 0.0, 3.0), EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::new(0.25, 0.0, 0.0), EPS));
+        assert!(entry.normal.abs_diff_eq(Vec3::Z, EPS));
+        assert!(exit.normal.abs_diff_eq(-Vec3::Z, EPS));
+    }
+
+    #[test]
+    fn through_side_and_cap_works() {
+        let ray = Ray3D::new(Vec3::new(-1.5, 0.0, 2.0), Vec3::new(1.0, 0.0, 1.0));
+        let intersection = ray.intersection(&column());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::new(-0.5, 0.0, 3.0), EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::new(-0.5, 0.0, 3.0), EPS));
+
+        let ray = Ray3D::new(Vec3::new(-1.0, 0.0, 2.0), Vec3::new(1.0, 0.0, 1.0));
+        let intersection = ray.intersection(&column());
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!(entry.point.abs_diff_eq(Vec3::new(-0.5, 0.0, 2.5), EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec3::X, EPS));
+        assert!(exit.point.abs_diff_eq(Vec3::new(0.0, 0.0, 3.0), EPS));
+        assert!(exit.normal.abs_diff_eq(Vec3::Z, EPS));
+    }
+
+    #[test]
+    fn miss_works() {
+        let above = Ray3D::new(Vec3::Z * 4.0, Vec3::X);
+        let beside = Ray3D::new(Vec3::X, Vec3::Z);
+        assert_eq!(above.intersection(&column()), RaySolid3DIntersection::None);
+        assert_eq!(beside.intersection(&column()), RaySolid3DIntersection::None);
+    }
+}
@@ -1,2 +1,8 @@
+pub mod capsule;
+pub mod cone;
 pub mod coord_sys;
+pub mod cylinder;
+pub mod plane;
 pub mod ray;
+pub mod sphere;
+pub mod triangle;
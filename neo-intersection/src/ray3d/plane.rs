@@ -0,0 +1,39 @@
+use glam::Vec3;
+use neo_coordinate_system::CoordinateSystem;
+use neo_plane::Plane;
+use neo_ray::d3::def::Ray3D;
+
+use crate::results::RayCoordSys3DIntersection;
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+/// Planes pass through the origin, so this is the intersection with the coordinate system at the
+/// origin
+impl NeoIntersectable<Plane> for Ray3D {
+    type Output = RayCoordSys3DIntersection;
+
+    fn intersection(&self, rhs: &Plane) -> Self::Output {
+        let coord_sys_plane = CoordinateSystem::from_origin_and_plane(Vec3::ZERO, *rhs);
+        self.intersection(&coord_sys_plane)
+    }
+}
+
+impl NeoIntersects<Plane> for Ray3D {
+    fn intersects(&self, rhs: &Plane) -> bool {
+        let coord_sys_plane = CoordinateSystem::from_origin_and_plane(Vec3::ZERO, *rhs);
+        self.intersects(&coord_sys_plane)
+    }
+}
+
+mirror_intersectable!(Ray3D => Plane);
+
+#[test]
+fn plane_hit_works() {
+    let plane = Plane::from_normal(Vec3::Z);
+    let ray = Ray3D::new(Vec3::ONE, -Vec3::Z);
+    let RayCoordSys3DIntersection::Point(hit) = ray.intersection(&plane) else {
+        panic!("expected the ray to hit the plane");
+    };
+    assert_eq!(hit.point, Vec3::ONE - Vec3::Z);
+    assert_eq!(hit.t, 1.0);
+    assert!(hit.front_face);
+}
@@ -1,141 +1,150 @@
-#![allow(non_snake_case)]
-use glam::{Mat2, Vec2, Vec3};
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
 use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
 
-use crate::trait_def::NeoIntersectable;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::RayHit3D;
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RayRay3DIntersection {
     Parallel,
     Collinear,
-    Intersection(Vec3),
+    /// the hit is located on the left hand side ray. Its normal is orthogonal to the other ray and
+    /// faces the ray, in the plane spanned by both rays
+    Intersection(RayHit3D),
     Skewed,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-enum ZeroDim {
-    X,
-    Y,
-    Z,
-}
+impl NeoIntersectionResult for RayRay3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
 
-impl ZeroDim {
-    fn make_getter(&self) -> fn(Vec3) -> f32 {
+    /// collinear rays overlap on an unbounded line, which isn't listed
+    fn dimension(&self) -> Option<IntersectionDimension> {
         match self {
-            ZeroDim::X => |vec| vec.x,
-            ZeroDim::Y => |vec| vec.y,
-            ZeroDim::Z => |vec| vec.z,
+            RayRay3DIntersection::Parallel | RayRay3DIntersection::Skewed => None,
+            RayRay3DIntersection::Collinear => Some(IntersectionDimension::Line),
+            RayRay3DIntersection::Intersection(_) => Some(IntersectionDimension::Point),
         }
     }
 
-    fn others(&self) -> [Self; 2] {
+    fn points(&self) -> Vec<Vec3> {
         match self {
-            ZeroDim::X => [Self::Y, Self::Z],
-            ZeroDim::Y => [Self::X, Self::Z],
-            ZeroDim::Z => [Self::X, Self::Y],
+            RayRay3DIntersection::Intersection(hit) => vec![hit.point],
+            _ => vec![],
         }
     }
 }
 
-impl NeoIntersectable for Ray3D {
-    type Output = RayRay3DIntersection;
+/// closest approach of two non parallel rays. The rays are treated as unbounded lines, so the
+/// parameters may be negative
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayRay3DApproach {
+    /// parameter on the left hand side ray, `lhs_point = lhs.origin + t * lhs.direction`
+    pub t: f32,
+    /// parameter on the right hand side ray, `rhs_point = rhs.origin + s * rhs.direction`
+    pub s: f32,
+    pub lhs_point: Vec3,
+    pub rhs_point: Vec3,
+    /// distance between the closest points, it's zero for intersecting rays
+    pub gap: f32,
+}
 
-    fn intersection(&self, rhs: &Self) -> Self::Output {
-        if self.is_parallel_to(rhs) {
-            classify_parallel_relation_to(self, rhs)
-        } else {
-            classify_intersecting_relation_to(self, rhs)
+impl RayRay3DApproach {
+    /// closed form solution for the parameters where the connection of both rays is orthogonal
+    /// to both directions. Rays whose directions enclose an angle with a sine below
+    /// `angle_tolerance` count as parallel and don't have a unique closest approach
+    pub fn between(lhs: &Ray3D, rhs: &Ray3D, angle_tolerance: f32) -> Option<Self> {
+        let w = lhs.origin - rhs.origin;
+        let a = lhs.direction.dot(lhs.direction);
+        let b = lhs.direction.dot(rhs.direction);
+        let c = rhs.direction.dot(rhs.direction);
+        let d = lhs.direction.dot(w);
+        let e = rhs.direction.dot(w);
+
+        // `a * c - b * b = |lhs.direction x rhs.direction|^2`
+        let denom = a * c - b * b;
+        if denom <= angle_tolerance * angle_tolerance * a * c {
+            return None;
         }
+        let t = (b * e - c * d) / denom;
+        let s = (a * e - b * d) / denom;
+        let lhs_point = lhs.origin + t * lhs.direction;
+        let rhs_point = rhs.origin + s * rhs.direction;
+        Some(Self {
+            t,
+            s,
+            lhs_point,
+            rhs_point,
+            gap: lhs_point.distance(rhs_point),
+        })
     }
 }
 
-pub(crate) fn classify_parallel_relation_to(r1: &Ray3D, r2: &Ray3D) -> RayRay3DIntersection {
-    if r1.is_point_on_ray(r2.origin) {
-        RayRay3DIntersection::Collinear
-    } else {
-        RayRay3DIntersection::Parallel
+impl RayRay3DIntersection {
+    pub fn intersection_point(&self) -> Option<Vec3> {
+        match self {
+            RayRay3DIntersection::Intersection(hit) => Some(hit.point),
+            _ => None,
+        }
     }
-}
 
-pub(crate) fn classify_intersecting_relation_to(r1: &Ray3D, r2: &Ray3D) -> RayRay3DIntersection {
-    match calculate_intersection_point(r1, r2) {
-        Some(intersection_point) => RayRay3DIntersection::Intersection(intersection_point),
-        None => RayRay3DIntersection::Skewed,
+    /// classifies the relation of two rays. `angle_tolerance` is the sine of the angle below
+    /// which rays count as parallel, `distance_tolerance` the gap below which they meet, in the
+    /// units of the coordinates. The intersection point is located on `lhs`
+    pub fn classify(
+        lhs: &Ray3D,
+        rhs: &Ray3D,
+        angle_tolerance: f32,
+        distance_tolerance: f32,
+    ) -> Self {
+        match RayRay3DApproach::between(lhs, rhs, angle_tolerance) {
+            Some(approach) if approach.gap <= distance_tolerance => {
+                // `rhs x (lhs x rhs)` is orthogonal to `rhs` and points along `lhs`
+                let normal = -rhs
+                    .direction
+                    .cross(lhs.direction.cross(rhs.direction))
+                    .normalize_or_zero();
+                Self::Intersection(RayHit3D {
+                    point: approach.lhs_point,
+                    ..RayHit3D::new(lhs, approach.t, normal)
+                })
+            }
+            Some(_) => Self::Skewed,
+            None if are_collinear(lhs, rhs, distance_tolerance) => Self::Collinear,
+            None => Self::Parallel,
+        }
     }
 }
 
-pub(crate) fn calculate_intersection_point(r1: &Ray3D, r2: &Ray3D) -> Option<Vec3> {
-    let dir1 = r1.direction;
-    let dir2 = r2.direction;
-
-    let dirs: [[f32; 2]; 3] = [[dir1.x, dir2.x], [dir1.y, dir2.y], [dir1.z, dir2.z]];
-    let near_zero = |x: f32| x.abs() < NEO_LINE_RAY_3D_EPS;
-
-    let maybe_scalar = match dirs {
-        // in these cases it is more like a 2D intersection
-        [[a, b], _, _] if near_zero(a) && near_zero(b) => {
-            solve_intersection_equations_dim_zero(r1, r2, ZeroDim::X)
-        }
-        [_, [a, b], _] if near_zero(a) && near_zero(b) => {
-            solve_intersection_equations_dim_zero(r1, r2, ZeroDim::Y)
-        }
-        [_, _, [a, b]] if near_zero(a) && near_zero(b) => {
-            solve_intersection_equations_dim_zero(r1, r2, ZeroDim::Z)
-        }
-        _ => solve_overdetermined_intersection_system(r1, r2),
-    };
-
-    maybe_scalar.map(|s| r1.origin + s * r1.direction)
+/// whether the origin of `rhs` lies on the unbounded line of `lhs`. Only meaningful for parallel
+/// rays
+pub(crate) fn are_collinear(lhs: &Ray3D, rhs: &Ray3D, distance_tolerance: f32) -> bool {
+    let w = rhs.origin - lhs.origin;
+    let along = w.dot(lhs.direction) / lhs.direction.length_squared();
+    (w - along * lhs.direction).length() <= distance_tolerance
 }
 
-// from https://stackoverflow.com/a/34604574
-pub(crate) fn solve_overdetermined_intersection_system(r1: &Ray3D, r2: &Ray3D) -> Option<f32> {
-    let dir1 = r1.direction;
-    let dir2 = r2.direction;
-    let origin_diff = r2.origin - r1.origin;
-
-    let a = dir1.dot(dir1);
-    let b = dir1.dot(dir2);
-    let c = dir2.dot(dir2);
-    let d = dir1.dot(origin_diff);
-    let e = dir2.dot(origin_diff);
+impl NeoIntersectable for Ray3D {
+    type Output = RayRay3DIntersection;
 
-    let dd = a * c - b * b;
-    (dd.abs() > NEO_LINE_RAY_3D_EPS).then(|| {
-        let s = (b * e - c * d) / dd;
-        // make the factors point in the right direction, tbh I don't know why they were inverted
-        // here but negating them lead to the same results as before
-        -s
-    })
+    fn intersection(&self, rhs: &Self) -> Self::Output {
+        RayRay3DIntersection::classify(self, rhs, NEO_LINE_RAY_3D_EPS, NEO_LINE_RAY_3D_EPS)
+    }
 }
 
-fn solve_intersection_equations_dim_zero(r1: &Ray3D, r2: &Ray3D, d: ZeroDim) -> Option<f32> {
-    let p1 = r1.origin;
-    let p2 = r2.origin;
-    let dir1 = r1.direction;
-    let dir2 = r2.direction;
-
-    let (get0, [get1, get2]) = (d.make_getter(), d.others().map(|d| d.make_getter()));
-
-    let is_valid = (get0(p1) - get0(p2)).abs() < NEO_LINE_RAY_3D_EPS;
-
-    is_valid
-        .then(|| {
-            Mat2::from_cols(
-                Vec2::new(get1(dir1), get2(dir1)),
-                -Vec2::new(get1(dir2), get2(dir2)),
-            )
-        })
-        // check if we can calculate an inverse of the matrix (aka if it is solveable)
-        .filter(|a| a.determinant().abs() >= NEO_LINE_RAY_3D_EPS)
-        // inverse is valid since determinant was valid
-        .map(|a| a.inverse())
-        .map(|inv_A| {
-            let b = Vec2::new(get1(p2) - get1(p1), get2(p2) - get2(p1));
-            inv_A.mul_vec2(b)
-        })
-        .map(|x| x.x)
+/// only compares the gap of the closest approach, without building the hit
+impl NeoIntersects for Ray3D {
+    fn intersects(&self, rhs: &Self) -> bool {
+        match RayRay3DApproach::between(self, rhs, NEO_LINE_RAY_3D_EPS) {
+            Some(approach) => approach.gap <= NEO_LINE_RAY_3D_EPS,
+            None => are_collinear(self, rhs, NEO_LINE_RAY_3D_EPS),
+        }
+    }
 }
 
 #[test]
@@ -156,20 +165,14 @@ fn collinear_no_overlap_works() {
 fn intersection_works() {
     let l1 = Ray3D::Y;
     let l2 = Ray3D::X.offset_origin_by(Vec3::X);
-    assert_eq!(
-        l1.intersection(&l2),
-        RayRay3DIntersection::Intersection(Vec3::ZERO)
-    );
+    assert_eq!(l1.intersection(&l2).intersection_point(), Some(Vec3::ZERO));
 }
 
 #[test]
 fn intersection_swapped_args_works() {
     let l1 = Ray3D::Y;
     let l2 = Ray3D::X.offset_origin_by(Vec3::X);
-    assert_eq!(
-        l2.intersection(&l1),
-        RayRay3DIntersection::Intersection(Vec3::ZERO)
-    );
+    assert_eq!(l2.intersection(&l1).intersection_point(), Some(Vec3::ZERO));
 }
 
 #[test]
@@ -181,5 +184,133 @@ fn name() {
     let l2 = Ray3D::new(origin + d2 * 12314.31234, d2);
 
     let intersection = l1.intersection(&l2);
-    assert_eq!(intersection, RayRay3DIntersection::Intersection(origin))
+    assert_eq!(intersection.intersection_point(), Some(origin))
+}
+
+#[test]
+fn intersection_hit_works() {
+    let l1 = Ray3D::new(Vec3::new(-2.0, 0.0, 0.0), Vec3::X * 2.0);
+    let l2 = Ray3D::new(Vec3::new(0.0, -1.0, -1.0), Vec3::Y + Vec3::Z);
+    let RayRay3DIntersection::Intersection(hit) = l1.intersection(&l2) else {
+        panic!("expected an intersection");
+    };
+    assert!((hit.t - 1.0).abs() < NEO_LINE_RAY_3D_EPS);
+    assert!(hit.point.abs_diff_eq(Vec3::ZERO, NEO_LINE_RAY_3D_EPS));
+    assert!(hit.normal.abs_diff_eq(-Vec3::X, NEO_LINE_RAY_3D_EPS));
+    assert!(hit.front_face);
+}
+
+#[test]
+fn skewed_works() {
+    let l1 = Ray3D::X;
+    let l2 = Ray3D::Y.offset_origin_by(Vec3::Z);
+    assert_eq!(l1.intersection(&l2), RayRay3DIntersection::Skewed);
+
+    let approach = RayRay3DApproach::between(&l1, &l2, NEO_LINE_RAY_3D_EPS).expect("not parallel");
+    assert_eq!((approach.t, approach.s), (0.0, 0.0));
+    assert_eq!(approach.gap, 1.0);
+}
+
+#[test]
+fn approach_parameters_work() {
+    let l1 = Ray3D::new(Vec3::new(-3.0, 0.0, 0.0), Vec3::X * 2.0);
+    let l2 = Ray3D::new(Vec3::new(1.0, 5.0, 0.5), Vec3::NEG_Y);
+    let approach = RayRay3DApproach::between(&l1, &l2, NEO_LINE_RAY_3D_EPS).expect("not parallel");
+    assert!((approach.t - 2.0).abs() < NEO_LINE_RAY_3D_EPS);
+    assert!((approach.s - 5.0).abs() < NEO_LINE_RAY_3D_EPS);
+    assert!((approach.gap - 0.5).abs() < NEO_LINE_RAY_3D_EPS);
+    assert!(approach
+        .lhs_point
+        .abs_diff_eq(Vec3::new(1.0, 0.0, 0.0), NEO_LINE_RAY_3D_EPS));
+    assert!(approach
+        .rhs_point
+        .abs_diff_eq(Vec3::new(1.0, 0.0, 0.5), NEO_LINE_RAY_3D_EPS));
+
+    // the gap decides with the configured distance tolerance
+    assert_eq!(
+        RayRay3DIntersection::classify(&l1, &l2, NEO_LINE_RAY_3D_EPS, 0.1),
+        RayRay3DIntersection::Skewed
+    );
+    assert!(matches!(
+        RayRay3DIntersection::classify(&l1, &l2, NEO_LINE_RAY_3D_EPS, 0.5),
+        RayRay3DIntersection::Intersection(_)
+    ));
+}
+
+#[test]
+fn angle_and_distance_tolerances_are_independent() {
+    // the rays enclose an angle with a sine of about 0.01 and meet at the origin, their origins
+    // are 1 apart
+    let l1 = Ray3D::new(Vec3::X * 100.0, -Vec3::X);
+    let l2 = Ray3D::new(Vec3::new(100.0, 1.0, 0.0), -Vec3::new(100.0, 1.0, 0.0));
+    let Some(p) = RayRay3DIntersection::classify(&l1, &l2, 0.001, 0.001).intersection_point()
+    else {
+        panic!("expected an intersection");
+    };
+    assert!(p.abs_diff_eq(Vec3::ZERO, 0.001), "{p:?}");
+    // a coarse angle treats them as parallel, the distance decides about collinearity
+    assert_eq!(
+        RayRay3DIntersection::classify(&l1, &l2, 0.05, 0.1),
+        RayRay3DIntersection::Parallel
+    );
+    assert_eq!(
+        RayRay3DIntersection::classify(&l1, &l2, 0.05, 2.0),
+        RayRay3DIntersection::Collinear
+    );
+}
+
+#[test]
+fn nearly_axis_aligned_rays_work() {
+    // both directions are almost, but not exactly, in the X-Y plane
+    let l1 = Ray3D::new(Vec3::new(0.0, 0.0, 3.0), Vec3::new(1.0, 0.0, 0.000_01));
+    let l2 = Ray3D::new(Vec3::new(5.0, -5.0, 3.0), Vec3::new(0.0, 1.0, 0.000_01));
+    let Some(p) = l1.intersection(&l2).intersection_point() else {
+        panic!("expected an intersection");
+    };
+    assert!(l1.is_point_on_ray(p) && l2.is_point_on_ray(p), "{p:?}");
+}
+
+#[test]
+fn consistent_with_ray_distance() {
+    use crate::trait_def::NeoDistance;
+
+    let origins = [
+        Vec3::ZERO,
+        Vec3::new(3.0, -1.0, 2.0),
+        Vec3::new(-50.0, 20.0, 7.5),
+    ];
+    let directions = [
+        Vec3::X,
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(0.3, -2.0, 0.7),
+        Vec3::new(0.0, 0.000_01, 1.0),
+    ];
+    for (o1, o2) in origins.iter().zip(origins.iter().rev()) {
+        for d1 in directions {
+            for d2 in directions {
+                let l1 = Ray3D::new(*o1, d1);
+                let l2 = Ray3D::new(*o2 + Vec3::Y, d2);
+                let Some(approach) = RayRay3DApproach::between(&l1, &l2, NEO_LINE_RAY_3D_EPS)
+                else {
+                    assert!(l1.is_parallel_to(&l2));
+                    continue;
+                };
+                let distance = NeoDistance::distance(&l1, &l2);
+                assert!((approach.gap - distance.distance).abs() < 0.001);
+                // the connection of the closest points is orthogonal to both rays
+                let connection = approach.rhs_point - approach.lhs_point;
+                assert!(connection.dot(d1).abs() < 0.001 && connection.dot(d2).abs() < 0.001);
+                // an intersection point lies on both rays
+                if let Some(p) = l1.intersection(&l2).intersection_point() {
+                    assert!(l1.is_point_on_ray(p) && l2.is_point_on_ray(p));
+                }
+                // the classification doesn't depend on the argument order
+                let swapped = l2.intersection(&l1);
+                assert_eq!(
+                    std::mem::discriminant(&swapped),
+                    std::mem::discriminant(&l1.intersection(&l2))
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,91 @@
+use glam::Vec3;
+use neo_primitive::d3::sphere::Sphere3D;
+use neo_ray::d3::def::Ray3D;
+
+use crate::ray_hit::{has_quadratic_roots, quadratic_roots, RayHit3D, RaySolid3DIntersection};
+use crate::trait_def::{mirror_intersectable, NeoIntersectable, NeoIntersects};
+
+impl NeoIntersectable<Sphere3D> for Ray3D {
+    type Output = RaySolid3DIntersection;
+
+    fn intersection(&self, rhs: &Sphere3D) -> Self::Output {
+        RaySolid3DIntersection::from_hits(sphere_hits(self, rhs.center, rhs.radius))
+    }
+}
+
+/// only checks the discriminant, without building the hits
+impl NeoIntersects<Sphere3D> for Ray3D {
+    fn intersects(&self, rhs: &Sphere3D) -> bool {
+        let (a, b, c) = sphere_coefficients(self, rhs.center, rhs.radius);
+        !self.is_degenerated() && rhs.radius > 0.0 && has_quadratic_roots(a, b, c)
+    }
+}
+
+mirror_intersectable!(Ray3D => Sphere3D);
+
+pub(crate) fn sphere_hits(ray: &Ray3D, center: Vec3, radius: f32) -> Vec<RayHit3D> {
+    if ray.is_degenerated() || radius <= 0.0 {
+        return vec![];
+    }
+    let (a, b, c) = sphere_coefficients(ray, center, radius);
+    quadratic_roots(a, b, c)
+        .into_iter()
+        .map(|t| {
+            let point = ray.origin + ray.direction * t;
+            RayHit3D::new(ray, t, (point - center) / radius)
+        })
+        .collect()
+}
+
+/// coefficients of `a t² + b t + c = 0` for the parameters where the ray meets the sphere
+fn sphere_coefficients(ray: &Ray3D, center: Vec3, radius: f32) -> (f32, f32, f32) {
+    let offset = ray.origin - center;
+    let a = ray.direction.length_squared();
+    let b = 2.0 * offset.dot(ray.direction);
+    let c = offset.length_squared() - radius * radius;
+    (a, b, c)
+}
+
+#[cfg(test)]
+mod ray_sphere {
+    use glam::Vec3;
+    use neo_primitive::d3::sphere::Sphere3D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::ray_hit::RaySolid3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    #[test]
+    fn entry_and_exit_work() {
+        let sphere = Sphere3D::new(Vec3::Z, 1.0);
+        let ray = Ray3D::new(Vec3::new(0.0, -4.0, 1.0), Vec3::Y * 2.0);
+        let intersection = ray.intersection(&sphere);
+        let entry = intersection.entry().unwrap();
+        let exit = intersection.exit().unwrap();
+
+        assert!((entry.t - 1.5).abs() < EPS);
+        assert!((exit.t - 2.5).abs() < EPS);
+        assert!(entry.point.abs_diff_eq(Vec3::Z - Vec3::Y, EPS));
+        assert!(entry.normal.abs_diff_eq(-Vec3::Y, EPS));
+        assert!(exit.normal.abs_diff_eq(Vec3::Y, EPS));
+    }
+
+    #[test]
+    fn behind_origin_has_negative_t() {
+        let sphere = Sphere3D::new(Vec3::ZERO, 1.0);
+        let ray = Ray3D::new(Vec3::X * 3.0, Vec3::X);
+        let intersection = ray.intersection(&sphere);
+
+        assert!((intersection.entry().unwrap().t + 4.0).abs() < EPS);
+        assert!((intersection.exit().unwrap().t + 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn miss_works() {
+        let sphere = Sphere3D::new(Vec3::ZERO, 1.0);
+        let ray = Ray3D::new(Vec3::Z * 1.5, Vec3::X);
+        assert_eq!(ray.intersection(&sphere), RaySolid3DIntersection::None);
+    }
+}
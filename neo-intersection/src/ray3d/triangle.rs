@@ -0,0 +1,297 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_ray::d3::constants::NEO_LINE_RAY_3D_EPS;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
+use neo_surface::triangle3d::def::Triangle3D;
+
+use crate::culling::{CulledTriangle3D, TriangleCulling};
+use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::RayHit3D;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayTriangle3DHit {
+    /// the hit reports the triangle normal, `front_face` tells which side of the triangle got hit
+    pub hit: RayHit3D,
+    /// barycentric weights of the triangle corners `a`, `b` and `c`
+    pub barycentric: Vec3,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RayTriangle3DIntersection {
+    None,
+    /// the ray is parallel to the plane of the triangle. This includes rays located in the plane
+    Parallel,
+    Hit(RayTriangle3DHit),
+}
+
+impl RayTriangle3DIntersection {
+    pub fn hit(&self) -> Option<RayTriangle3DHit> {
+        match self {
+            RayTriangle3DIntersection::Hit(hit) => Some(*hit),
+            _ => None,
+        }
+    }
+}
+
+impl NeoIntersectionResult for RayTriangle3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RayTriangle3DIntersection::None | RayTriangle3DIntersection::Parallel => None,
+            RayTriangle3DIntersection::Hit(_) => Some(IntersectionDimension::Point),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            RayTriangle3DIntersection::Hit(hit) => vec![hit.hit.point],
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectable<Triangle3D> for Ray3D {
+    type Output = RayTriangle3DIntersection;
+
+    fn intersection(&self, rhs: &Triangle3D) -> Self::Output {
+        moeller_trumbore(self, rhs, TriangleCulling::None)
+    }
+}
+
+impl NeoIntersectable<CulledTriangle3D> for Ray3D {
+    type Output = RayTriangle3DIntersection;
+
+    fn intersection(&self, rhs: &CulledTriangle3D) -> Self::Output {
+        moeller_trumbore(self, &rhs.triangle, rhs.culling)
+    }
+}
+
+/// Intersects the ray with every triangle, e.g. the ones from [`NeoSurface::tessellate`]. The hits
+/// are sorted by `t`. Hits on edges shared by neighboring triangles are only reported once. Every
+/// triangle is tested, use [`TriangleBvh3D`] to cast many rays against large meshes
+///
+/// [`NeoSurface::tessellate`]: neo_surface::surface::def::NeoSurface::tessellate
+/// [`TriangleBvh3D`]: crate::bvh::TriangleBvh3D
+impl NeoIntersectable<[Triangle3D]> for Ray3D {
+    type Output = Vec<RayTriangle3DHit>;
+
+    fn intersection(&self, rhs: &[Triangle3D]) -> Self::Output {
+        sorted_unique_hits(self, rhs)
+    }
+}
+
+/// hits of the ray with the triangles sorted by `t`, hits at the same point are only kept once
+pub(crate) fn sorted_unique_hits<'a>(
+    ray: &Ray3D,
+    triangles: impl IntoIterator<Item = &'a Triangle3D>,
+) -> Vec<RayTriangle3DHit> {
+    let mut hits = triangles
+        .into_iter()
+        .filter_map(|tri| ray.intersection(tri).hit())
+        .collect::<Vec<_>>();
+    hits.sort_by(|a, b| float_ord_cmp(&a.hit.t, &b.hit.t));
+    hits.dedup_by(|a, b| a.hit.point.abs_diff_eq(b.hit.point, NEO_LINE_RAY_3D_EPS));
+    hits
+}
+
+impl NeoIntersects<Triangle3D> for Ray3D {
+    fn intersects(&self, rhs: &Triangle3D) -> bool {
+        self.intersection(rhs).hit().is_some()
+    }
+}
+
+impl NeoIntersects<CulledTriangle3D> for Ray3D {
+    fn intersects(&self, rhs: &CulledTriangle3D) -> bool {
+        self.intersection(rhs).hit().is_some()
+    }
+}
+
+impl NeoIntersects<[Triangle3D]> for Ray3D {
+    fn intersects(&self, rhs: &[Triangle3D]) -> bool {
+        rhs.iter().any(|tri| self.intersects(tri))
+    }
+}
+
+mirror_intersectable!(Ray3D => Triangle3D, CulledTriangle3D, [Triangle3D]);
+
+// https://en.wikipedia.org/wiki/M%C3%B6ller%E2%80%93Trumbore_intersection_algorithm
+pub(crate) fn moeller_trumbore(
+    ray: &Ray3D,
+    triangle: &Triangle3D,
+    culling: TriangleCulling,
+) -> RayTriangle3DIntersection {
+    if triangle.is_degenerated() || ray.is_degenerated() {
+        return RayTriangle3DIntersection::None;
+    }
+
+    let edge1 = triangle.b - triangle.a;
+    let edge2 = triangle.c - triangle.a;
+    let normal = triangle.normal();
+
+    let cos = ray.direction_normalized().dot(normal);
+    if cos.abs() < NEO_LINE_RAY_3D_EPS {
+        return RayTriangle3DIntersection::Parallel;
+    }
+
+    let front_face = cos < 0.0;
+    let culled = match culling {
+        TriangleCulling::None => false,
+        TriangleCulling::BackFace => !front_face,
+        TriangleCulling::FrontFace => front_face,
+    };
+    if culled {
+        return RayTriangle3DIntersection::None;
+    }
+
+    let p = ray.direction.cross(edge2);
+    let inv_det = 1.0 / edge1.dot(p);
+
+    let s = ray.origin - triangle.a;
+    let u = s.dot(p) * inv_det;
+    let q = s.cross(edge1);
+    let v = ray.direction.dot(q) * inv_det;
+
+    let in_triangle = u >= -NEO_LINE_RAY_3D_EPS
+        && v >= -NEO_LINE_RAY_3D_EPS
+        && u + v <= 1.0 + NEO_LINE_RAY_3D_EPS;
+    if !in_triangle {
+        return RayTriangle3DIntersection::None;
+    }
+
+    let t = edge2.dot(q) * inv_det;
+    RayTriangle3DIntersection::Hit(RayTriangle3DHit {
+        hit: RayHit3D::new(ray, t, normal),
+        barycentric: Vec3::new(1.0 - u - v, u, v),
+    })
+}
+
+#[cfg(test)]
+mod ray_triangle {
+    use glam::{Vec2, Vec3};
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_ray::d3::def::Ray3D;
+    use neo_surface::surface::def::NeoSurface;
+    use neo_surface::triangle3d::def::Triangle3D;
+
+    use crate::culling::{CulledTriangle3D, TriangleCulling};
+    use crate::ray3d::triangle::RayTriangle3DIntersection;
+    use crate::trait_def::NeoIntersectable;
+
+    const EPS: f32 = 0.000_1;
+
+    fn unit_triangle() -> Triangle3D {
+        Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y)
+    }
+
+    #[test]
+    fn hit_works() {
+        let ray = Ray3D::new(Vec3::new(0.25, 0.25, -1.0), Vec3::Z);
+        let hit = ray.intersection(&unit_triangle()).hit().unwrap();
+
+        assert!((hit.hit.t - 1.0).abs() < EPS);
+        assert!(hit.hit.point.abs_diff_eq(Vec3::new(0.25, 0.25, 0.0), EPS));
+        assert!(hit.barycentric.abs_diff_eq(Vec3::new(0.5, 0.25, 0.25), EPS));
+        assert!(!hit.hit.front_face);
+        assert!(unit_triangle()
+            .inject_barycentric(hit.barycentric)
+            .abs_diff_eq(hit.hit.point, EPS));
+    }
+
+    #[test]
+    fn hit_behind_origin_has_negative_t() {
+        let ray = Ray3D::new(Vec3::new(0.25, 0.25, 2.0), Vec3::Z * 2.0);
+        let hit = ray.intersection(&unit_triangle()).hit().unwrap();
+
+        assert!((hit.hit.t + 1.0).abs() < EPS);
+        assert!(!hit.hit.front_face);
+    }
+
+    #[test]
+    fn miss_works() {
+        let ray = Ray3D::new(Vec3::new(0.75, 0.75, -1.0), Vec3::Z);
+        assert_eq!(
+            ray.intersection(&unit_triangle()),
+            RayTriangle3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn corner_hit_works() {
+        let ray = Ray3D::new(Vec3::X - Vec3::Z, Vec3::Z);
+        let hit = ray.intersection(&unit_triangle()).hit().unwrap();
+        assert!(hit.barycentric.abs_diff_eq(Vec3::Y, EPS));
+    }
+
+    #[test]
+    fn parallel_works() {
+        let ray = Ray3D::new(Vec3::Z, Vec3::X);
+        assert_eq!(
+            ray.intersection(&unit_triangle()),
+            RayTriangle3DIntersection::Parallel
+        );
+        let ray_in_plane = Ray3D::new(Vec3::ZERO, Vec3::ONE - Vec3::Z);
+        assert_eq!(
+            ray_in_plane.intersection(&unit_triangle()),
+            RayTriangle3DIntersection::Parallel
+        );
+    }
+
+    #[test]
+    fn backface_culling_works() {
+        let from_below = Ray3D::new(Vec3::new(0.25, 0.25, -1.0), Vec3::Z);
+        let from_above = Ray3D::new(Vec3::new(0.25, 0.25, 1.0), -Vec3::Z);
+        let culled = CulledTriangle3D::new(unit_triangle(), TriangleCulling::BackFace);
+
+        assert_eq!(
+            from_below.intersection(&culled),
+            RayTriangle3DIntersection::None
+        );
+        assert!(
+            from_above
+                .intersection(&culled)
+                .hit()
+                .unwrap()
+                .hit
+                .front_face
+        );
+    }
+
+    #[test]
+    fn frontface_culling_works() {
+        let from_below = Ray3D::new(Vec3::new(0.25, 0.25, -1.0), Vec3::Z);
+        let from_above = Ray3D::new(Vec3::new(0.25, 0.25, 1.0), -Vec3::Z);
+        let culled = CulledTriangle3D::new(unit_triangle(), TriangleCulling::FrontFace);
+
+        assert!(from_below.intersection(&culled).hit().is_some());
+        assert_eq!(
+            from_above.intersection(&culled),
+            RayTriangle3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn tessellated_surface_hits_are_sorted_and_unique() {
+        let line = LineSegment2D::new(Vec2::ZERO, Vec2::X * 4.0);
+        let wall = NeoSurface::from_line_and_heights_vertical(line, 0.0, 3.0);
+        let triangles = [
+            wall.tessellate(),
+            wall.translate_by(Vec3::Y * 2.0).tessellate(),
+        ]
+        .concat();
+
+        let ray = Ray3D::new(Vec3::new(2.0, 5.0, 1.5), -Vec3::Y);
+        let hits = ray.intersection(triangles.as_slice());
+
+        assert_eq!(hits.len(), 2);
+        assert!((hits[0].hit.t - 3.0).abs() < EPS);
+        assert!((hits[1].hit.t - 5.0).abs() < EPS);
+    }
+}
@@ -0,0 +1,391 @@
+use glam::{Vec2, Vec3};
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_ray::d2::def::Ray2D;
+use neo_ray::d3::def::Ray3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
+use crate::line_intersection_parts::{
+    sort_parts_along, Line2DIntersectionParts, Line3DIntersectionParts,
+};
+use crate::trait_def::NeoIntersectionResult;
+
+/// A point where a ray crosses the boundary of a shape. Every ray intersection reports its points
+/// as hits. Rays are treated as unbounded lines here, hits behind the origin are reported too and
+/// can be filtered with [`RayHit2D::is_behind_origin`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit2D {
+    /// ray parameter of the hit, the hit point is `ray.origin + t * ray.direction`. It's negative
+    /// for hits behind the origin
+    pub t: f32,
+    pub point: Vec2,
+    /// unit normal of the shape at the hit point. Solids report their outward facing normal, flat
+    /// shapes report their own normal no matter which side got hit
+    pub normal: Vec2,
+    /// whether the ray hit the side the normal points to. For solids this is `true` when the ray
+    /// enters the solid
+    pub front_face: bool,
+}
+
+/// A point where a ray crosses the boundary of a shape. See [`RayHit2D`] for the semantics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit3D {
+    /// ray parameter of the hit, the hit point is `ray.origin + t * ray.direction`. It's negative
+    /// for hits behind the origin
+    pub t: f32,
+    pub point: Vec3,
+    /// unit normal of the shape at the hit point. Solids report their outward facing normal, flat
+    /// shapes report their own normal no matter which side got hit
+    pub normal: Vec3,
+    /// whether the ray hit the side the normal points to. For solids this is `true` when the ray
+    /// enters the solid
+    pub front_face: bool,
+}
+
+impl RayHit2D {
+    pub fn new(ray: &Ray2D, t: f32, normal: Vec2) -> Self {
+        Self {
+            t,
+            point: ray.origin + ray.direction * t,
+            normal,
+            front_face: ray.direction.dot(normal) < 0.0,
+        }
+    }
+
+    /// Hit at a `point` which is already known to be located on the ray
+    pub(crate) fn at_point(ray: &Ray2D, point: Vec2, normal: Vec2) -> Self {
+        let t = (point - ray.origin).dot(ray.direction) / ray.direction.length_squared();
+        Self {
+            point,
+            ..Self::new(ray, t, normal)
+        }
+    }
+
+    /// Whether the hit is located on the backwards extension of the ray
+    pub fn is_behind_origin(&self) -> bool {
+        self.t < 0.0
+    }
+}
+
+impl RayHit3D {
+    pub fn new(ray: &Ray3D, t: f32, normal: Vec3) -> Self {
+        Self {
+            t,
+            point: ray.origin + ray.direction * t,
+            normal,
+            front_face: ray.direction.dot(normal) < 0.0,
+        }
+    }
+
+    /// Hit at a `point` which is already known to be located on the ray
+    pub(crate) fn at_point(ray: &Ray3D, point: Vec3, normal: Vec3) -> Self {
+        let t = (point - ray.origin).dot(ray.direction) / ray.direction.length_squared();
+        Self {
+            point,
+            ..Self::new(ray, t, normal)
+        }
+    }
+
+    /// Whether the hit is located on the backwards extension of the ray
+    pub fn is_behind_origin(&self) -> bool {
+        self.t < 0.0
+    }
+}
+
+/// Intersection of a ray with a convex solid shape. Tangential hits have `entry == exit`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RaySolid2DIntersection {
+    None,
+    Hit { entry: RayHit2D, exit: RayHit2D },
+}
+
+/// Intersection of a ray with a convex solid shape. Tangential hits have `entry == exit`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RaySolid3DIntersection {
+    None,
+    Hit { entry: RayHit3D, exit: RayHit3D },
+}
+
+impl RaySolid2DIntersection {
+    /// Builds the intersection from all the points where the ray crosses the shapes surface
+    pub(crate) fn from_hits(hits: Vec<RayHit2D>) -> Self {
+        let entry = hits.iter().min_by(|a, b| float_ord_cmp(&a.t, &b.t));
+        let exit = hits.iter().max_by(|a, b| float_ord_cmp(&a.t, &b.t));
+        entry
+            .zip(exit)
+            .map_or(Self::None, |(&entry, &exit)| Self::Hit { entry, exit })
+    }
+
+    pub fn entry(&self) -> Option<RayHit2D> {
+        match self {
+            Self::None => None,
+            Self::Hit { entry, .. } => Some(*entry),
+        }
+    }
+
+    pub fn exit(&self) -> Option<RayHit2D> {
+        match self {
+            Self::None => None,
+            Self::Hit { exit, .. } => Some(*exit),
+        }
+    }
+}
+
+impl RaySolid3DIntersection {
+    /// Builds the intersection from all the points where the ray crosses the shapes surface
+    pub(crate) fn from_hits(hits: Vec<RayHit3D>) -> Self {
+        let entry = hits.iter().min_by(|a, b| float_ord_cmp(&a.t, &b.t));
+        let exit = hits.iter().max_by(|a, b| float_ord_cmp(&a.t, &b.t));
+        entry
+            .zip(exit)
+            .map_or(Self::None, |(&entry, &exit)| Self::Hit { entry, exit })
+    }
+
+    pub fn entry(&self) -> Option<RayHit3D> {
+        match self {
+            Self::None => None,
+            Self::Hit { entry, .. } => Some(*entry),
+        }
+    }
+
+    pub fn exit(&self) -> Option<RayHit3D> {
+        match self {
+            Self::None => None,
+            Self::Hit { exit, .. } => Some(*exit),
+        }
+    }
+}
+
+impl NeoIntersectionResult for RaySolid2DIntersection {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    /// tangential hits are points, all other hits are the chord through the solid
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RaySolid2DIntersection::None => None,
+            RaySolid2DIntersection::Hit { entry, exit } if entry == exit => {
+                Some(IntersectionDimension::Point)
+            }
+            RaySolid2DIntersection::Hit { .. } => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        match self {
+            RaySolid2DIntersection::Hit { entry, exit } if entry == exit => vec![entry.point],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        match self {
+            RaySolid2DIntersection::Hit { entry, exit } if entry != exit => {
+                vec![LineSegment2D::new(entry.point, exit.point)]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectionResult for RaySolid3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    /// tangential hits are points, all other hits are the chord through the solid
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            RaySolid3DIntersection::None => None,
+            RaySolid3DIntersection::Hit { entry, exit } if entry == exit => {
+                Some(IntersectionDimension::Point)
+            }
+            RaySolid3DIntersection::Hit { .. } => Some(IntersectionDimension::Line),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            RaySolid3DIntersection::Hit { entry, exit } if entry == exit => vec![entry.point],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            RaySolid3DIntersection::Hit { entry, exit } if entry != exit => {
+                vec![LineSegment3D::new(entry.point, exit.point)]
+            }
+            _ => vec![],
+        }
+    }
+}
+
+/// A part of the intersection of a ray with a shape it runs through or along, from the hit where
+/// the ray enters the part to the hit where it leaves it, so `entry.t <= exit.t`. Parts where the
+/// ray only touches the shape have `entry == exit`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayPart2D {
+    pub entry: RayHit2D,
+    pub exit: RayHit2D,
+}
+
+/// A part of the intersection of a ray with a shape it runs through or along. See [`RayPart2D`]
+/// for the semantics
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayPart3D {
+    pub entry: RayHit3D,
+    pub exit: RayHit3D,
+}
+
+impl RayPart2D {
+    /// Builds the part from a part located on the ray, `normal_at` gives the normal of the shape
+    /// at the ends of the part
+    pub(crate) fn from_part(
+        ray: &Ray2D,
+        part: Line2DIntersectionParts,
+        normal_at: impl Fn(Vec2) -> Vec2,
+    ) -> Self {
+        let (src, dst) = match part {
+            Line2DIntersectionParts::Point(p) => (p, p),
+            Line2DIntersectionParts::Line(l) => (l.src, l.dst),
+        };
+        let src = RayHit2D::at_point(ray, src, normal_at(src));
+        let dst = RayHit2D::at_point(ray, dst, normal_at(dst));
+        if src.t <= dst.t {
+            Self {
+                entry: src,
+                exit: dst,
+            }
+        } else {
+            Self {
+                entry: dst,
+                exit: src,
+            }
+        }
+    }
+
+    /// Sorts and merges the parts along the ray, see [`sort_parts_along`], and builds their hits
+    pub(crate) fn sorted_along(
+        ray: &Ray2D,
+        parts: Vec<Line2DIntersectionParts>,
+        normal_at: impl Fn(Vec2) -> Vec2,
+    ) -> Vec<Self> {
+        sort_parts_along(parts, ray.origin, ray.direction)
+            .into_iter()
+            .map(|part| Self::from_part(ray, part, &normal_at))
+            .collect()
+    }
+
+    pub fn part(&self) -> Line2DIntersectionParts {
+        if self.entry.point == self.exit.point {
+            Line2DIntersectionParts::Point(self.entry.point)
+        } else {
+            Line2DIntersectionParts::Line(LineSegment2D::new(self.entry.point, self.exit.point))
+        }
+    }
+}
+
+impl RayPart3D {
+    pub fn part(&self) -> Line3DIntersectionParts {
+        if self.entry.point == self.exit.point {
+            Line3DIntersectionParts::Point(self.entry.point)
+        } else {
+            Line3DIntersectionParts::Line(LineSegment3D::new(self.entry.point, self.exit.point))
+        }
+    }
+}
+
+impl NeoIntersectionResult for RayPart2D {
+    type Point = Vec2;
+    type Segment = LineSegment2D;
+    type Surface = geo::Polygon<f32>;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        self.part().dimension()
+    }
+
+    fn points(&self) -> Vec<Vec2> {
+        self.part().points()
+    }
+
+    fn segments(&self) -> Vec<LineSegment2D> {
+        self.part().segments()
+    }
+}
+
+impl NeoIntersectionResult for RayPart3D {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        self.part().dimension()
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        self.part().points()
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        self.part().segments()
+    }
+}
+
+/// Real roots of `a * t^2 + b * t + c`, in ascending order
+pub(crate) fn quadratic_roots(a: f32, b: f32, c: f32) -> Vec<f32> {
+    if !has_quadratic_roots(a, b, c) {
+        return vec![];
+    }
+    if a.abs() < f32::EPSILON {
+        return vec![-c / b];
+    }
+    let discriminant = b * b - 4.0 * a * c;
+    let sqrt = discriminant.sqrt();
+    let [t0, t1] = [(-b - sqrt) / (2.0 * a), (-b + sqrt) / (2.0 * a)];
+    vec![t0.min(t1), t0.max(t1)]
+}
+
+/// whether [`quadratic_roots`] finds any root, without taking the square root
+pub(crate) fn has_quadratic_roots(a: f32, b: f32, c: f32) -> bool {
+    if a.abs() < f32::EPSILON {
+        b.abs() >= f32::EPSILON
+    } else {
+        b * b - 4.0 * a * c >= 0.0
+    }
+}
+
+/// whether `a * t^2 + b * t + c` drops to zero or below somewhere in `[lo, hi]`, which only needs
+/// the sign at the ends of the range and at the vertex
+pub(crate) fn has_quadratic_non_positive_in(a: f32, b: f32, c: f32, (lo, hi): (f32, f32)) -> bool {
+    let value = |t: f32| (a * t + b) * t + c;
+    let vertex = -b / (2.0 * a);
+    value(lo) <= 0.0
+        || value(hi) <= 0.0
+        || (a > 0.0 && (lo..=hi).contains(&vertex) && value(vertex) <= 0.0)
+}
+
+#[test]
+fn quadratic_roots_works() {
+    assert_eq!(quadratic_roots(1.0, 0.0, -1.0), vec![-1.0, 1.0]);
+    assert_eq!(quadratic_roots(-1.0, 0.0, 1.0), vec![-1.0, 1.0]);
+    assert_eq!(quadratic_roots(1.0, 0.0, 1.0), vec![]);
+    assert_eq!(quadratic_roots(0.0, 2.0, -1.0), vec![0.5]);
+}
+
+#[test]
+fn has_quadratic_non_positive_in_works() {
+    assert!(has_quadratic_non_positive_in(1.0, 0.0, -0.5, (-1.0, 1.0)));
+    assert!(has_quadratic_non_positive_in(1.0, 0.0, -1.0, (0.5, 3.0)));
+    assert!(!has_quadratic_non_positive_in(1.0, 0.0, -1.0, (2.0, 3.0)));
+    assert!(!has_quadratic_non_positive_in(1.0, 0.0, 1.0, (-1.0, 1.0)));
+    assert!(has_quadratic_non_positive_in(
+        -1.0,
+        0.0,
+        1.0,
+        (2.0, f32::INFINITY)
+    ));
+}
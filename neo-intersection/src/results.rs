@@ -1,7 +1,32 @@
+pub use crate::coord_sys::coord_sys::CoordSysCoordSysIntersection;
+pub use crate::distance::result::{Distance2D, Distance3D};
+pub use crate::intersection_set::{
+    IntersectionDimension, IntersectionSet, IntersectionSet2D, IntersectionSet3D,
+};
 pub use crate::line2d::line::Line2DOverlap;
 pub use crate::line2d::line::LineLine2DIntersection;
+pub use crate::line2d::polygon::LinePolygon2DIntersection;
+pub use crate::line3d::coord_sys::LineCoordSys3DIntersection;
 pub use crate::line3d::line::LineLine3DIntersection;
+pub use crate::line3d::surface::LineSurface3DIntersection;
 pub use crate::plane::plane::PlanePlaneIntersection;
+pub use crate::polygon2d::polygon::PolygonPolygon2DIntersection;
+pub use crate::ray2d::aabb::RayAABB2DIntersection;
+pub use crate::ray2d::line::RayLine2DIntersection;
+pub use crate::ray2d::polygon::RayPolygon2DIntersection;
 pub use crate::ray2d::ray::RayRay2DIntersection;
 pub use crate::ray3d::coord_sys::RayCoordSys3DIntersection;
-pub use crate::ray3d::ray::RayRay3DIntersection;
+pub use crate::ray3d::ray::{RayRay3DApproach, RayRay3DIntersection};
+pub use crate::ray3d::triangle::{RayTriangle3DHit, RayTriangle3DIntersection};
+pub use crate::ray_hit::{
+    RayHit2D, RayHit3D, RayPart2D, RayPart3D, RaySolid2DIntersection, RaySolid3DIntersection,
+};
+pub use crate::surface::coord_sys::SurfaceCoordSys3DIntersection;
+pub use crate::surface::plane::SurfacePlane3DIntersection;
+pub use crate::surface::ray::SurfaceRay3DIntersection;
+pub use crate::surface::surface::{
+    BoundaryRing, SurfaceBoundaryEdge, SurfaceSide, SurfaceSurface3DIntersection,
+    SurfaceSurface3DPart,
+};
+pub use crate::time_of_impact::result::{TimeOfImpact2D, TimeOfImpact3D};
+pub use crate::triangle3d::triangle::TriangleTriangle3DIntersection;
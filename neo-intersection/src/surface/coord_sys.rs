@@ -1,12 +1,20 @@
 use glam::Vec3;
 use neo_coordinate_system::CoordinateSystem;
+use neo_error::NeoGeometryError;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_ray::d3::def::Ray3D;
 use neo_surface::surface::def::NeoSurface;
 
 use crate::coord_sys::coord_sys::CoordSysCoordSysIntersection;
+use crate::intersection_set::IntersectionDimension;
 use crate::line_intersection_parts::Line3DIntersectionParts;
+use crate::ray_hit::RayPart3D;
 use crate::surface::ray::SurfaceRay3DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    mirror_intersectable, mirror_try_intersectable, NeoIntersectable, NeoIntersectionResult,
+    NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+};
+use crate::validation::ensure_valid_surface;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SurfaceCoordSys3DIntersection {
@@ -16,18 +24,92 @@ pub enum SurfaceCoordSys3DIntersection {
     Surface(NeoSurface),
 }
 
+impl NeoIntersectionResult for SurfaceCoordSys3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            SurfaceCoordSys3DIntersection::None => None,
+            SurfaceCoordSys3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            SurfaceCoordSys3DIntersection::Parts(ps) => ps.dimension(),
+            SurfaceCoordSys3DIntersection::Surface(_) => Some(IntersectionDimension::Surface),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            SurfaceCoordSys3DIntersection::Point(p) => vec![*p],
+            SurfaceCoordSys3DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            SurfaceCoordSys3DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+
+    fn surfaces(&self) -> Vec<NeoSurface> {
+        match self {
+            SurfaceCoordSys3DIntersection::Surface(s) => vec![s.clone()],
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable<CoordinateSystem> for NeoSurface {
     type Output = SurfaceCoordSys3DIntersection;
 
     fn intersection(&self, rhs: &CoordinateSystem) -> Self::Output {
-        let inter = self.coordinate_system.intersection(rhs);
-        match inter {
-            CoordSysCoordSysIntersection::Same => {
-                SurfaceCoordSys3DIntersection::Surface(self.clone())
-            }
-            CoordSysCoordSysIntersection::Parallel { .. } => SurfaceCoordSys3DIntersection::None,
-            CoordSysCoordSysIntersection::Ray(ray) => ray_intersection_case_analysis(self, ray),
+        self.try_intersection(rhs)
+            .unwrap_or(SurfaceCoordSys3DIntersection::None)
+    }
+}
+
+impl NeoTryIntersectable<CoordinateSystem> for NeoSurface {
+    type Output = SurfaceCoordSys3DIntersection;
+
+    fn try_intersection(&self, rhs: &CoordinateSystem) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_surface(self)?;
+        let inter = self.coordinate_system.try_intersection(rhs)?;
+        Ok(classify_coordinate_system_intersection(self, inter))
+    }
+}
+
+impl NeoIntersects<CoordinateSystem> for NeoSurface {
+    fn intersects(&self, rhs: &CoordinateSystem) -> bool {
+        self.try_intersects(rhs).unwrap_or(false)
+    }
+}
+
+impl NeoTryIntersects<CoordinateSystem> for NeoSurface {
+    fn try_intersects(&self, rhs: &CoordinateSystem) -> Result<bool, NeoGeometryError> {
+        ensure_valid_surface(self)?;
+        Ok(match self.coordinate_system.try_intersection(rhs)? {
+            CoordSysCoordSysIntersection::Same => true,
+            CoordSysCoordSysIntersection::Parallel { .. } => false,
+            CoordSysCoordSysIntersection::Ray(ray) => self.intersects(&ray),
+        })
+    }
+}
+
+mirror_intersectable!(NeoSurface => CoordinateSystem);
+mirror_try_intersectable!(NeoSurface => CoordinateSystem);
+
+fn classify_coordinate_system_intersection(
+    surface: &NeoSurface,
+    inter: CoordSysCoordSysIntersection,
+) -> SurfaceCoordSys3DIntersection {
+    match inter {
+        CoordSysCoordSysIntersection::Same => {
+            SurfaceCoordSys3DIntersection::Surface(surface.clone())
         }
+        CoordSysCoordSysIntersection::Parallel { .. } => SurfaceCoordSys3DIntersection::None,
+        CoordSysCoordSysIntersection::Ray(ray) => ray_intersection_case_analysis(surface, ray),
     }
 }
 
@@ -39,8 +121,10 @@ fn ray_intersection_case_analysis(
     match inter {
         SurfaceRay3DIntersection::Skewed => SurfaceCoordSys3DIntersection::None,
         SurfaceRay3DIntersection::Parallel => SurfaceCoordSys3DIntersection::None,
-        SurfaceRay3DIntersection::Point(p) => SurfaceCoordSys3DIntersection::Point(p),
-        SurfaceRay3DIntersection::Parts(ps) => SurfaceCoordSys3DIntersection::Parts(ps),
+        SurfaceRay3DIntersection::Point(hit) => SurfaceCoordSys3DIntersection::Point(hit.point),
+        SurfaceRay3DIntersection::Parts(ps) => {
+            SurfaceCoordSys3DIntersection::Parts(ps.iter().map(RayPart3D::part).collect())
+        }
     }
 }
 
@@ -48,13 +132,16 @@ fn ray_intersection_case_analysis(
 mod surface_coord_sys {
     use glam::{Vec2, Vec3};
     use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
     use neo_geo_glam_interop::to_geo::ConvertToGeo;
     use neo_plane::Plane;
     use neo_surface::surface::def::NeoSurface;
 
     use crate::line_intersection_parts::Line3DIntersectionParts;
     use crate::surface::coord_sys::SurfaceCoordSys3DIntersection;
-    use crate::trait_def::NeoIntersectable;
+    use crate::trait_def::{
+        NeoIntersectable, NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+    };
 
     fn standard_surface(shape: geo::Polygon<f32>) -> NeoSurface {
         let local_x = Vec3::X + Vec3::Z;
@@ -127,4 +214,40 @@ mod surface_coord_sys {
         let inter = surface.intersection(&c);
         assert_eq!(inter, SurfaceCoordSys3DIntersection::Surface(surface));
     }
+
+    #[test]
+    fn try_intersection_works() {
+        let shape = geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
+        let surface = standard_surface(shape);
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::X);
+        assert_eq!(surface.try_intersection(&c), Ok(surface.intersection(&c)));
+        assert_eq!(c.try_intersection(&surface), Ok(c.intersection(&surface)));
+
+        let nan =
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::new(f32::NAN, 1.0, 0.0));
+        assert_eq!(
+            surface.try_intersection(&nan),
+            Err(NeoGeometryError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn try_intersects_works() {
+        let surface = standard_surface_rect();
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::X);
+        assert_eq!(surface.try_intersects(&c), Ok(surface.intersects(&c)));
+        assert_eq!(c.try_intersects(&surface), Ok(true));
+
+        let nan =
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::new(f32::NAN, 1.0, 0.0));
+        assert_eq!(
+            surface.try_intersects(&nan),
+            Err(NeoGeometryError::NonFinite)
+        );
+        assert!(!surface.intersects(&nan));
+        assert_eq!(
+            surface.intersection(&nan),
+            SurfaceCoordSys3DIntersection::None
+        );
+    }
 }
@@ -1,11 +1,15 @@
 use glam::Vec3;
 use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_plane::Plane;
 use neo_surface::surface::def::NeoSurface;
 
+use crate::intersection_set::IntersectionDimension;
 use crate::line_intersection_parts::Line3DIntersectionParts;
 use crate::surface::coord_sys::SurfaceCoordSys3DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SurfacePlane3DIntersection {
@@ -15,6 +19,43 @@ pub enum SurfacePlane3DIntersection {
     Surface(NeoSurface),
 }
 
+impl NeoIntersectionResult for SurfacePlane3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            SurfacePlane3DIntersection::None => None,
+            SurfacePlane3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            SurfacePlane3DIntersection::Parts(ps) => ps.dimension(),
+            SurfacePlane3DIntersection::Surface(_) => Some(IntersectionDimension::Surface),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            SurfacePlane3DIntersection::Point(p) => vec![*p],
+            SurfacePlane3DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            SurfacePlane3DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+
+    fn surfaces(&self) -> Vec<NeoSurface> {
+        match self {
+            SurfacePlane3DIntersection::Surface(s) => vec![s.clone()],
+            _ => vec![],
+        }
+    }
+}
+
 impl NeoIntersectable<Plane> for NeoSurface {
     type Output = SurfacePlane3DIntersection;
 
@@ -29,3 +70,11 @@ impl NeoIntersectable<Plane> for NeoSurface {
         }
     }
 }
+
+impl NeoIntersects<Plane> for NeoSurface {
+    fn intersects(&self, rhs: &Plane) -> bool {
+        self.intersects(&CoordinateSystem::from_origin_and_plane(Vec3::ZERO, *rhs))
+    }
+}
+
+mirror_intersectable!(NeoSurface => Plane);
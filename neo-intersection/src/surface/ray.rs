@@ -1,19 +1,64 @@
 use glam::Vec3;
 use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_line_segment::d3::def::LineSegment3D;
 use neo_ray::d2::def::Ray2D;
 use neo_ray::d3::def::Ray3D;
 use neo_surface::surface::def::NeoSurface;
 
-use crate::line_intersection_parts::Line3DIntersectionParts;
+use crate::intersection_set::IntersectionDimension;
+use crate::ray_hit::{RayHit2D, RayHit3D, RayPart3D};
 use crate::results::RayCoordSys3DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::trait_def::{
+    mirror_intersectable, NeoIntersectable, NeoIntersectionResult, NeoIntersects,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SurfaceRay3DIntersection {
     Skewed,
     Parallel,
-    Point(Vec3),
-    Parts(Vec<Line3DIntersectionParts>),
+    /// the hit normal is the facing direction of the surface
+    Point(RayHit3D),
+    /// the ray lies in the plane of the surface, parts are sorted along the ray direction. The hit
+    /// normals are the outward normals of the surface boundary, located in the plane
+    Parts(Vec<RayPart3D>),
+}
+
+impl SurfaceRay3DIntersection {
+    pub fn intersection_point(&self) -> Option<Vec3> {
+        match self {
+            SurfaceRay3DIntersection::Point(hit) => Some(hit.point),
+            _ => None,
+        }
+    }
+}
+
+impl NeoIntersectionResult for SurfaceRay3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            SurfaceRay3DIntersection::Skewed | SurfaceRay3DIntersection::Parallel => None,
+            SurfaceRay3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            SurfaceRay3DIntersection::Parts(ps) => ps.dimension(),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            SurfaceRay3DIntersection::Point(hit) => vec![hit.point],
+            SurfaceRay3DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            SurfaceRay3DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
 }
 
 impl NeoIntersectable<Ray3D> for NeoSurface {
@@ -22,15 +67,27 @@ impl NeoIntersectable<Ray3D> for NeoSurface {
         let inter = self.coordinate_system.intersection(rhs);
         match inter {
             RayCoordSys3DIntersection::Parallel => SurfaceRay3DIntersection::Parallel,
-            RayCoordSys3DIntersection::Point(p) => point_case_analysis(self, p),
+            RayCoordSys3DIntersection::Point(hit) => point_case_analysis(self, hit),
             RayCoordSys3DIntersection::Ray(ray) => contained_ray_case_analysis(self, ray),
         }
     }
 }
 
-fn point_case_analysis(surface: &NeoSurface, point: Vec3) -> SurfaceRay3DIntersection {
-    if surface.is_point_in_surface(point) {
-        SurfaceRay3DIntersection::Point(point)
+impl NeoIntersects<Ray3D> for NeoSurface {
+    fn intersects(&self, rhs: &Ray3D) -> bool {
+        match self.coordinate_system.intersection(rhs) {
+            RayCoordSys3DIntersection::Parallel => false,
+            RayCoordSys3DIntersection::Point(hit) => self.is_point_in_surface(hit.point),
+            RayCoordSys3DIntersection::Ray(ray) => project_ray(self, &ray).intersects(&self.shape),
+        }
+    }
+}
+
+mirror_intersectable!(NeoSurface => Ray3D);
+
+fn point_case_analysis(surface: &NeoSurface, hit: RayHit3D) -> SurfaceRay3DIntersection {
+    if surface.is_point_in_surface(hit.point) {
+        SurfaceRay3DIntersection::Point(hit)
     } else {
         SurfaceRay3DIntersection::Skewed
     }
@@ -38,22 +95,29 @@ fn point_case_analysis(surface: &NeoSurface, point: Vec3) -> SurfaceRay3DInterse
 
 fn project_ray(surface: &NeoSurface, ray: &Ray3D) -> Ray2D {
     let offset_ray_origin = surface.project_point_xy(ray.origin).to_glam();
-    let offset_ray_direction = surface.project_point_xy(ray.direction).to_glam();
+    let offset_ray_direction = surface.project_vector_xy(ray.direction);
     let ray2d = Ray2D::new(offset_ray_origin, offset_ray_direction);
     ray2d
 }
 
 fn contained_ray_case_analysis(surface: &NeoSurface, ray: Ray3D) -> SurfaceRay3DIntersection {
     let ray2d = project_ray(surface, &ray);
-    let inter_parts = ray2d.intersection(&surface.shape).list_parts();
+    let inter_parts = ray2d.intersection(&surface.shape).list_ray_parts();
     if inter_parts.is_empty() {
         SurfaceRay3DIntersection::Skewed
     } else {
         let injection_func = surface.injection_function();
+        let inject_hit = |hit: RayHit2D| {
+            let normal = surface.inject_vector(hit.normal).normalize_or_zero();
+            RayHit3D::at_point(&ray, injection_func(hit.point), normal)
+        };
 
         let injected_parts = inter_parts
             .into_iter()
-            .map(|part| part.inject_with(&injection_func))
+            .map(|part| RayPart3D {
+                entry: inject_hit(part.entry),
+                exit: inject_hit(part.exit),
+            })
             .collect::<Vec<_>>();
 
         SurfaceRay3DIntersection::Parts(injected_parts)
@@ -69,7 +133,9 @@ mod surface_ray {
     use neo_ray::d3::def::Ray3D;
     use neo_surface::surface::def::NeoSurface;
 
-    use crate::surface::ray::{Line3DIntersectionParts, SurfaceRay3DIntersection};
+    use crate::line_intersection_parts::Line3DIntersectionParts;
+    use crate::ray_hit::RayPart3D;
+    use crate::surface::ray::SurfaceRay3DIntersection;
     use crate::trait_def::NeoIntersectable;
 
     fn standard_surface(shape: geo::Polygon<f32>) -> NeoSurface {
@@ -106,8 +172,8 @@ mod surface_ray {
         let ray = Ray3D::Z.offset_origin_by(Vec3::ONE);
 
         assert_eq!(
-            surface.intersection(&ray),
-            SurfaceRay3DIntersection::Point(Vec3::ONE)
+            surface.intersection(&ray).intersection_point(),
+            Some(Vec3::ONE)
         )
     }
 
@@ -129,8 +195,10 @@ mod surface_ray {
 
         // on border
         assert_eq!(
-            surface.intersection(&ray.offset_origin_by(Vec3::X)),
-            SurfaceRay3DIntersection::Point(Vec3::ONE + Vec3::X)
+            surface
+                .intersection(&ray.offset_origin_by(Vec3::X))
+                .intersection_point(),
+            Some(Vec3::ONE + Vec3::X)
         );
 
         // just outside
@@ -159,7 +227,7 @@ mod surface_ray {
         match inter {
             SurfaceRay3DIntersection::Parts(parts) => {
                 assert_eq!(parts.len(), 1);
-                assert!(matches!(parts[0], Line3DIntersectionParts::Line(_)));
+                assert!(matches!(parts[0].part(), Line3DIntersectionParts::Line(_)));
             }
             _ => panic!("expected {inter:?} to be intersection parts containing one line"),
         }
@@ -170,13 +238,14 @@ mod surface_ray {
         let surface = standard_surface_rect();
         let local_x = surface.coordinate_system.plane.local_x;
         let local_y = surface.coordinate_system.plane.local_y;
-        let ray = Ray3D::new(Vec3::ONE + local_x + local_y, local_x + local_y);
+        // the ray only touches the corner, it runs orthogonal to the diagonal through it
+        let ray = Ray3D::new(Vec3::ONE + local_x + local_y, local_x - local_y);
 
         let inter = surface.intersection(&ray);
         match inter {
             SurfaceRay3DIntersection::Parts(parts) => {
                 assert_eq!(parts.len(), 1);
-                assert!(matches!(parts[0], Line3DIntersectionParts::Point(_)));
+                assert!(matches!(parts[0].part(), Line3DIntersectionParts::Point(_)));
             }
             _ => panic!("expected {inter:?} to be intersection parts containing one point"),
         }
@@ -204,10 +273,62 @@ mod surface_ray {
         match inter {
             SurfaceRay3DIntersection::Parts(parts) => {
                 assert_eq!(parts.len(), 2);
-                assert!(matches!(parts[0], Line3DIntersectionParts::Line(_)));
-                assert!(matches!(parts[1], Line3DIntersectionParts::Line(_)));
+                assert!(matches!(parts[0].part(), Line3DIntersectionParts::Line(_)));
+                assert!(matches!(parts[1].part(), Line3DIntersectionParts::Line(_)));
             }
             _ => panic!("expected {inter:?} to be intersection parts containing two lines"),
         }
     }
+
+    #[test]
+    fn point_intersection_hit_works() {
+        let surface = standard_surface_rect();
+        let normal = surface.facing_direction().normalize();
+        let ray = Ray3D::new(Vec3::ONE - normal * 2.0, normal);
+
+        let SurfaceRay3DIntersection::Point(hit) = ray.intersection(&surface) else {
+            panic!("expected the ray to hit the surface");
+        };
+        assert!((hit.t - 2.0).abs() < 0.000_1);
+        assert!(hit.point.abs_diff_eq(Vec3::ONE, 0.000_1));
+        assert!(hit.normal.abs_diff_eq(normal, 0.000_1));
+        assert!(!hit.front_face);
+    }
+
+    #[test]
+    fn ray_in_surface_far_from_origin_keeps_its_direction() {
+        let p = Plane::from_local_axis(Vec3::X, Vec3::Y);
+        let c = CoordinateSystem::from_origin_and_plane(Vec3::new(500.0, -300.0, 40.0), p);
+        let shape = geo::Rect::<f32>::new(Vec2::NEG_ONE.to_geo(), Vec2::ONE.to_geo()).to_polygon();
+        let surface = NeoSurface::new(c, geo::Coord { x: 10.0, y: 10.0 }, shape);
+        // the shape spans [489, 491] x [-311, -309] in world space
+        let ray = Ray3D::new(Vec3::new(480.0, -310.0, 40.0), Vec3::X);
+
+        let SurfaceRay3DIntersection::Parts(parts) = surface.intersection(&ray) else {
+            panic!("expected the ray to cross the surface");
+        };
+        let [RayPart3D { entry, exit }] = parts.as_slice() else {
+            panic!("expected a single part, got {parts:?}");
+        };
+        assert!((entry.t - 9.0).abs() < 0.001 && (exit.t - 11.0).abs() < 0.001);
+        assert!(entry.normal.abs_diff_eq(-Vec3::X, 0.000_1) && entry.front_face);
+        assert!(exit.normal.abs_diff_eq(Vec3::X, 0.000_1) && !exit.front_face);
+        let parts = parts.iter().map(RayPart3D::part).collect::<Vec<_>>();
+        let [Line3DIntersectionParts::Line(line)] = parts.as_slice() else {
+            panic!("expected a single line, got {parts:?}");
+        };
+        let [src, dst] = if line.src.x < line.dst.x {
+            [line.src, line.dst]
+        } else {
+            [line.dst, line.src]
+        };
+        assert!(
+            src.abs_diff_eq(Vec3::new(489.0, -310.0, 40.0), 0.001),
+            "{src:?}"
+        );
+        assert!(
+            dst.abs_diff_eq(Vec3::new(491.0, -310.0, 40.0), 0.001),
+            "{dst:?}"
+        );
+    }
 }
@@ -1,57 +1,597 @@
-use geo::MapCoords;
+use geo::{Contains, InteriorPoint};
 use glam::Vec3;
+use neo_error::NeoGeometryError;
 use neo_geo_boolops::NeoGeoBoolops;
-use neo_surface::surface::def::NeoSurface;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::{NeoSurface, SURFACE_EPS};
 
+use crate::coord_sys::coord_sys::CoordSysCoordSysIntersection;
+use crate::intersection_set::IntersectionDimension;
+use crate::line3d::surface::{contained_line_case_analysis, LineSurface3DIntersection};
 use crate::line_intersection_parts::Line3DIntersectionParts;
 use crate::surface::coord_sys::SurfaceCoordSys3DIntersection;
-use crate::trait_def::NeoIntersectable;
+use crate::surface::ray::SurfaceRay3DIntersection;
+use crate::trait_def::{
+    NeoIntersectable, NeoIntersectionResult, NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+};
+use crate::validation::ensure_valid_surface;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum SurfaceSurface3DIntersection {
     None,
     Point(Vec3),
-    Parts(Vec<Line3DIntersectionParts>),
+    Parts(Vec<SurfaceSurface3DPart>),
     Surface(NeoSurface),
     MultiSurface(Vec<NeoSurface>),
 }
 
+impl NeoIntersectionResult for SurfaceSurface3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            SurfaceSurface3DIntersection::None => None,
+            SurfaceSurface3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            SurfaceSurface3DIntersection::Parts(ps) => ps.dimension(),
+            SurfaceSurface3DIntersection::Surface(_)
+            | SurfaceSurface3DIntersection::MultiSurface(_) => Some(IntersectionDimension::Surface),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            SurfaceSurface3DIntersection::Point(p) => vec![*p],
+            SurfaceSurface3DIntersection::Parts(ps) => ps.points(),
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            SurfaceSurface3DIntersection::Parts(ps) => ps.segments(),
+            _ => vec![],
+        }
+    }
+
+    fn surfaces(&self) -> Vec<NeoSurface> {
+        match self {
+            SurfaceSurface3DIntersection::Surface(s) => vec![s.clone()],
+            SurfaceSurface3DIntersection::MultiSurface(ss) => ss.clone(),
+            _ => vec![],
+        }
+    }
+}
+
+/// which of the two intersected surfaces a boundary edge belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SurfaceSide {
+    Lhs,
+    Rhs,
+}
+
+/// which ring of a surface shape a boundary edge belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundaryRing {
+    Exterior,
+    /// index into the interiors of the shape
+    Interior(usize),
+}
+
+/// a boundary edge of one of the intersected surfaces. The edge with index `i` runs from the
+/// `i`th to the `i + 1`th coordinate of the ring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SurfaceBoundaryEdge {
+    pub side: SurfaceSide,
+    pub ring: BoundaryRing,
+    pub index: usize,
+}
+
+/// a part of the intersection of two non coplanar surfaces together with the boundary edges its
+/// endpoints are located on. For a point part, `src_edges` and `dst_edges` are the same
+#[derive(Debug, Clone, PartialEq)]
+pub struct SurfaceSurface3DPart {
+    pub part: Line3DIntersectionParts,
+    pub src_edges: Vec<SurfaceBoundaryEdge>,
+    pub dst_edges: Vec<SurfaceBoundaryEdge>,
+}
+
+impl NeoIntersectionResult for SurfaceSurface3DPart {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        self.part.dimension()
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        self.part.points()
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        self.part.segments()
+    }
+}
+
 impl NeoIntersectable for NeoSurface {
     type Output = SurfaceSurface3DIntersection;
 
     fn intersection(&self, rhs: &Self) -> Self::Output {
-        let inter = self.intersection(&rhs.coordinate_system);
-        match inter {
-            SurfaceCoordSys3DIntersection::None => SurfaceSurface3DIntersection::None,
-            // the next two imply that the intersection is happening because of coordinate systems
-            // which are not parallel so we can just pass them through
-            SurfaceCoordSys3DIntersection::Point(p) => SurfaceSurface3DIntersection::Point(p),
-            SurfaceCoordSys3DIntersection::Parts(ps) => SurfaceSurface3DIntersection::Parts(ps),
-            SurfaceCoordSys3DIntersection::Surface(_) => {
-                let intersection = surface_intersection_case_analysis(self, rhs);
-                match intersection.len() {
-                    0 => SurfaceSurface3DIntersection::None,
-                    1 => SurfaceSurface3DIntersection::Surface(intersection[0].clone()),
-                    _ => SurfaceSurface3DIntersection::MultiSurface(intersection),
-                }
+        // if the input is broken or the boolean operation fails, we just return no intersection at
+        // all which might be wrong. Use `try_intersection` to notice it
+        self.try_intersection(rhs)
+            .unwrap_or(SurfaceSurface3DIntersection::None)
+    }
+}
+
+impl NeoTryIntersectable for NeoSurface {
+    type Output = SurfaceSurface3DIntersection;
+
+    fn try_intersection(&self, rhs: &Self) -> Result<Self::Output, NeoGeometryError> {
+        ensure_valid_surface(self)?;
+        ensure_valid_surface(rhs)?;
+        let inter = self.try_intersection(&rhs.coordinate_system)?;
+        classify_surface_intersection(self, rhs, inter)
+    }
+}
+
+fn classify_surface_intersection(
+    surface: &NeoSurface,
+    rhs: &NeoSurface,
+    inter: SurfaceCoordSys3DIntersection,
+) -> Result<SurfaceSurface3DIntersection, NeoGeometryError> {
+    let intersection = match inter {
+        SurfaceCoordSys3DIntersection::None => SurfaceSurface3DIntersection::None,
+        // the next two imply that the intersection is happening because of coordinate systems
+        // which are not parallel, so the parts of `surface` only have to be clipped with `rhs`
+        SurfaceCoordSys3DIntersection::Point(p) => {
+            if rhs.is_point_in_surface(p) {
+                SurfaceSurface3DIntersection::Point(p)
+            } else {
+                SurfaceSurface3DIntersection::None
+            }
+        }
+        SurfaceCoordSys3DIntersection::Parts(ps) => {
+            let parts = clip_parts_with_surface(surface, rhs, ps);
+            if parts.is_empty() {
+                SurfaceSurface3DIntersection::None
+            } else {
+                SurfaceSurface3DIntersection::Parts(parts)
+            }
+        }
+        SurfaceCoordSys3DIntersection::Surface(_) => {
+            let intersection = surface_intersection_case_analysis(surface, rhs)?;
+            match intersection.len() {
+                0 => SurfaceSurface3DIntersection::None,
+                1 => SurfaceSurface3DIntersection::Surface(intersection[0].clone()),
+                _ => SurfaceSurface3DIntersection::MultiSurface(intersection),
             }
         }
+    };
+    Ok(intersection)
+}
+
+impl NeoIntersects for NeoSurface {
+    /// like [`NeoIntersectable::intersection`], broken input counts as no intersection. Use
+    /// [`NeoTryIntersects::try_intersects`] to notice it
+    fn intersects(&self, rhs: &Self) -> bool {
+        self.try_intersects(rhs).unwrap_or(false)
     }
 }
 
-fn surface_intersection_case_analysis(surface: &NeoSurface, rhs: &NeoSurface) -> Vec<NeoSurface> {
-    // we are working in the coordinate system of the `surface` argument
-    let origin_2d_diff = surface.shape_origin - rhs.shape_origin;
-    let rhs_translated_shape = rhs.shape.map_coords(|c| c + origin_2d_diff);
-    let intersection = surface.shape.neo_intersection(&rhs_translated_shape);
+impl NeoTryIntersects for NeoSurface {
+    fn try_intersects(&self, rhs: &Self) -> Result<bool, NeoGeometryError> {
+        ensure_valid_surface(self)?;
+        ensure_valid_surface(rhs)?;
+        let intersects = match self
+            .coordinate_system
+            .try_intersection(&rhs.coordinate_system)?
+        {
+            CoordSysCoordSysIntersection::Same => coplanar_surfaces_overlap(self, rhs)?,
+            CoordSysCoordSysIntersection::Parallel { .. } => false,
+            // the parts of `self` in the plane of `rhs` only have to be checked against `rhs`
+            CoordSysCoordSysIntersection::Ray(ray) => match self.intersection(&ray) {
+                SurfaceRay3DIntersection::Point(hit) => rhs.is_point_in_surface(hit.point),
+                SurfaceRay3DIntersection::Parts(parts) => {
+                    parts.iter().any(|part| match part.part() {
+                        Line3DIntersectionParts::Point(p) => rhs.is_point_in_surface(p),
+                        Line3DIntersectionParts::Line(l) => !matches!(
+                            contained_line_case_analysis(rhs, &l),
+                            LineSurface3DIntersection::None
+                        ),
+                    })
+                }
+                SurfaceRay3DIntersection::Skewed | SurfaceRay3DIntersection::Parallel => false,
+            },
+        };
+        Ok(intersects)
+    }
+}
+
+/// disjoint shapes and shapes containing an interior point of the other one are decided without
+/// the boolean operation. Only shapes which touch or partly overlap need it, since touching
+/// boundaries don't count as an intersection
+fn coplanar_surfaces_overlap(
+    surface: &NeoSurface,
+    rhs: &NeoSurface,
+) -> Result<bool, NeoGeometryError> {
+    let rhs_shape = rhs.express_in(surface);
+    if !geo::Intersects::intersects(&surface.shape, &rhs_shape) {
+        return Ok(false);
+    }
+    let contains_interior_of = |outer: &geo::Polygon<f32>, inner: &geo::Polygon<f32>| {
+        inner.interior_point().is_some_and(|p| outer.contains(&p))
+    };
+    if contains_interior_of(&rhs_shape, &surface.shape)
+        || contains_interior_of(&surface.shape, &rhs_shape)
+    {
+        return Ok(true);
+    }
+    Ok(!surface_intersection_case_analysis(surface, rhs)?.is_empty())
+}
 
-    // if it fails, we just return no intersection at all which might be wrong
-    intersection
+/// clips the parts of `surface`, which are located in the plane of `rhs`, with the shape of `rhs`
+fn clip_parts_with_surface(
+    surface: &NeoSurface,
+    rhs: &NeoSurface,
+    parts: Vec<Line3DIntersectionParts>,
+) -> Vec<SurfaceSurface3DPart> {
+    parts
+        .into_iter()
+        .flat_map(|part| match part {
+            Line3DIntersectionParts::Point(p) => {
+                if rhs.is_point_in_surface(p) {
+                    vec![part]
+                } else {
+                    vec![]
+                }
+            }
+            // the line is located in the plane of `rhs` already, so we skip the plane check
+            Line3DIntersectionParts::Line(l) => match contained_line_case_analysis(rhs, &l) {
+                LineSurface3DIntersection::None => vec![],
+                LineSurface3DIntersection::Point(p) => vec![Line3DIntersectionParts::Point(p)],
+                LineSurface3DIntersection::Parts(ps) => ps,
+            },
+        })
+        .map(|part| {
+            let (src, dst) = match part {
+                Line3DIntersectionParts::Point(p) => (p, p),
+                Line3DIntersectionParts::Line(l) => l.tuple(),
+            };
+            let edges_at = |p: Vec3| {
+                let mut edges = boundary_edges_at(surface, SurfaceSide::Lhs, p);
+                edges.extend(boundary_edges_at(rhs, SurfaceSide::Rhs, p));
+                edges
+            };
+            SurfaceSurface3DPart {
+                part,
+                src_edges: edges_at(src),
+                dst_edges: edges_at(dst),
+            }
+        })
+        .collect()
+}
+
+/// all boundary edges of the surface shape on which the point is located
+fn boundary_edges_at(
+    surface: &NeoSurface,
+    side: SurfaceSide,
+    point: Vec3,
+) -> Vec<SurfaceBoundaryEdge> {
+    let point_2d = surface.project_point_xy(point).to_glam();
+    std::iter::once((BoundaryRing::Exterior, surface.shape.exterior()))
+        .chain(
+            surface
+                .shape
+                .interiors()
+                .iter()
+                .enumerate()
+                .map(|(i, ring)| (BoundaryRing::Interior(i), ring)),
+        )
+        .flat_map(|(ring, ls)| {
+            ls.lines()
+                .enumerate()
+                .filter(move |(_, line)| {
+                    let line = LineSegment2D::new(line.start.to_glam(), line.end.to_glam());
+                    line.distance_to_point(point_2d) < SURFACE_EPS
+                })
+                .map(move |(index, _)| SurfaceBoundaryEdge { side, ring, index })
+        })
+        .collect()
+}
+
+fn surface_intersection_case_analysis(
+    surface: &NeoSurface,
+    rhs: &NeoSurface,
+) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+    // we are working in the coordinate system of the `surface` argument
+    let rhs_shape = rhs.express_in(surface);
+    let intersection = surface.shape.neo_intersection(&rhs_shape).ok_or(
+        NeoGeometryError::NumericalInconsistency(
+            "boolean intersection of the coplanar shapes failed",
+        ),
+    )?;
+    Ok(intersection
         .into_iter()
-        .flat_map(|mp| mp.into_iter())
         .map(|p| NeoSurface {
             shape: p,
             ..surface.clone()
         })
-        .collect::<Vec<_>>()
+        .collect::<Vec<_>>())
+}
+
+#[cfg(test)]
+mod surface_surface {
+    use geo::Area;
+    use glam::{Quat, Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_plane::Plane;
+    use neo_surface::polygon3d::def::NeoPolygon3D;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::line_intersection_parts::Line3DIntersectionParts;
+    use crate::surface::surface::{
+        BoundaryRing, SurfaceBoundaryEdge, SurfaceSide, SurfaceSurface3DIntersection,
+    };
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::{
+        NeoIntersectable, NeoIntersects, NeoTryIntersectable, NeoTryIntersects,
+    };
+
+    fn intersection_area_3d(inter: &SurfaceSurface3DIntersection) -> f32 {
+        match inter {
+            SurfaceSurface3DIntersection::Surface(s) => s.shape.unsigned_area(),
+            SurfaceSurface3DIntersection::MultiSurface(ss) => {
+                ss.iter().map(|s| s.shape.unsigned_area()).sum()
+            }
+            _ => 0.0,
+        }
+    }
+
+    fn all_points_in_z0_square(inter: &SurfaceSurface3DIntersection, min: Vec2, max: Vec2) {
+        let SurfaceSurface3DIntersection::Surface(s) = inter else {
+            panic!("expected {inter:?} to be a single surface");
+        };
+        for p in s.as_polygon_3d().exterior {
+            assert!(p.z.abs() < 0.000_1, "{p:?} not in plane");
+            assert!(
+                p.x > min.x - 0.000_1
+                    && p.y > min.y - 0.000_1
+                    && p.x < max.x + 0.000_1
+                    && p.y < max.y + 0.000_1,
+                "{p:?} outside of expected region"
+            );
+        }
+    }
+
+    #[test]
+    fn offset_coplanar_surfaces_intersect() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let b = unit_square(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Y);
+
+        let inter = a.intersection(&b);
+        assert!((intersection_area_3d(&inter) - 0.25).abs() < 0.000_1);
+        all_points_in_z0_square(&inter, Vec2::splat(0.5), Vec2::ONE);
+    }
+
+    #[test]
+    fn offset_coplanar_surfaces_disjoint() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let b = unit_square(Vec3::new(2.0, 0.0, 0.0), Vec3::X, Vec3::Y);
+
+        let inter = a.intersection(&b);
+        assert_eq!(inter, SurfaceSurface3DIntersection::None);
+    }
+
+    #[test]
+    fn rotated_coplanar_surfaces_intersect() {
+        // b spans [0, 1] x [0, 1] in world space too, but its frame is rotated by 90 degrees
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let b = unit_square(Vec3::X, Vec3::Y, Vec3::NEG_X);
+
+        let inter = a.intersection(&b);
+        assert!((intersection_area_3d(&inter) - 1.0).abs() < 0.000_1);
+        all_points_in_z0_square(&inter, Vec2::ZERO, Vec2::ONE);
+    }
+
+    #[test]
+    fn rotated_coplanar_surfaces_partial_overlap() {
+        // b is rotated by 45 degrees around the shared corner at the origin
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let local_x = Quat::from_rotation_z(std::f32::consts::FRAC_PI_4) * Vec3::X;
+        let b = unit_square(Vec3::ZERO, local_x, Vec3::Z.cross(local_x));
+
+        let inter = a.intersection(&b);
+        // upper half of a (area 0.5) minus the corner beyond x + y = sqrt2 (area 1.5 - sqrt2)
+        let expected = 2.0_f32.sqrt() - 1.0;
+        let area = intersection_area_3d(&inter);
+        assert!((area - expected).abs() < 0.001, "{area} == {expected} ?");
+    }
+
+    #[test]
+    fn flipped_coplanar_surfaces_intersect() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let b = unit_square(Vec3::new(0.5, 0.0, 0.0), Vec3::X, Vec3::Y).flip();
+        assert!(b
+            .facing_direction()
+            .abs_diff_eq(-a.facing_direction(), 0.000_1));
+
+        let inter = a.intersection(&b);
+        assert!((intersection_area_3d(&inter) - 0.5).abs() < 0.000_1);
+        all_points_in_z0_square(&inter, Vec2::new(0.5, 0.0), Vec2::ONE);
+    }
+
+    #[test]
+    fn from_polygon_3d_with_different_start_vertex_intersects() {
+        let outline = [
+            Vec3::ZERO,
+            Vec3::X * 2.0,
+            Vec3::new(2.0, 2.0, 0.0),
+            Vec3::Y * 2.0,
+        ];
+        let mut shifted = outline.to_vec();
+        shifted.rotate_left(2);
+        let shifted = shifted
+            .into_iter()
+            .map(|p| p + Vec3::new(1.0, 1.0, 0.0))
+            .collect::<Vec<_>>();
+
+        let a = NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(
+            outline.to_vec(),
+            Vec3::Z,
+        ));
+        let b =
+            NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(shifted, Vec3::Z));
+
+        let inter = a.intersection(&b);
+        assert!((intersection_area_3d(&inter) - 1.0).abs() < 0.000_1);
+        all_points_in_z0_square(&inter, Vec2::ONE, Vec2::splat(2.0));
+    }
+
+    #[test]
+    fn crossing_planes_with_distant_shapes_dont_intersect() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        // the planes cross along the x axis, but b is far away along it
+        let b = unit_square(Vec3::new(10.0, 0.0, -0.5), Vec3::X, Vec3::Z);
+
+        let inter = a.intersection(&b);
+        assert_eq!(inter, SurfaceSurface3DIntersection::None);
+    }
+
+    #[test]
+    fn crossing_surfaces_are_clipped_by_both_shapes() {
+        let a = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        // b crosses a at y = 0.5 and only covers x in [0.25, 1.25]
+        let b = unit_square(Vec3::new(0.25, 0.5, -0.5), Vec3::X, Vec3::Z);
+
+        let inter = a.intersection(&b);
+        let SurfaceSurface3DIntersection::Parts(parts) = inter else {
+            panic!("expected {inter:?} to be parts");
+        };
+        assert_eq!(parts.len(), 1);
+        let Line3DIntersectionParts::Line(line) = parts[0].part else {
+            panic!("expected {:?} to be a line", parts[0]);
+        };
+        let (min, max) = if line.src.x < line.dst.x {
+            (line.src, line.dst)
+        } else {
+            (line.dst, line.src)
+        };
+        assert!(
+            min.abs_diff_eq(Vec3::new(0.25, 0.5, 0.0), 0.000_1),
+            "{min:?}"
+        );
+        assert!(
+            max.abs_diff_eq(Vec3::new(1.0, 0.5, 0.0), 0.000_1),
+            "{max:?}"
+        );
+
+        let (min_edges, max_edges) = if line.src.x < line.dst.x {
+            (&parts[0].src_edges, &parts[0].dst_edges)
+        } else {
+            (&parts[0].dst_edges, &parts[0].src_edges)
+        };
+        // the start comes from the left edge of b, the end from the right edge of a, which is the
+        // first edge of a rect polygon
+        assert!(min_edges.iter().all(|e| e.side == SurfaceSide::Rhs));
+        assert_eq!(min_edges.len(), 1);
+        assert_eq!(
+            max_edges,
+            &vec![SurfaceBoundaryEdge {
+                side: SurfaceSide::Lhs,
+                ring: BoundaryRing::Exterior,
+                index: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn crossing_surfaces_report_interior_edges() {
+        let shape = geo::Polygon::new(
+            geo::LineString::from(vec![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)]),
+            vec![geo::LineString::from(vec![
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (2.0, 2.0),
+                (1.0, 2.0),
+            ])],
+        );
+        let p = Plane::from_local_axis(Vec3::X, Vec3::Y);
+        let c = CoordinateSystem::from_origin_and_plane(Vec3::ZERO, p);
+        let a = NeoSurface::new(c, geo::Coord::zero(), shape);
+        let p = Plane::from_local_axis(Vec3::X, Vec3::Z);
+        let c = CoordinateSystem::from_origin_and_plane(Vec3::new(-1.0, 1.5, -1.0), p);
+        let shape = geo::Rect::<f32>::new(Vec2::ZERO.to_geo(), Vec2::splat(5.0).to_geo());
+        let b = NeoSurface::new(c, geo::Coord::zero(), shape.to_polygon());
+
+        let inter = a.intersection(&b);
+        let SurfaceSurface3DIntersection::Parts(parts) = inter else {
+            panic!("expected {inter:?} to be parts");
+        };
+        assert_eq!(parts.len(), 2);
+        let interior_edges = parts
+            .iter()
+            .flat_map(|p| p.src_edges.iter().chain(p.dst_edges.iter()))
+            .filter(|e| e.ring == BoundaryRing::Interior(0))
+            .count();
+        assert_eq!(interior_edges, 2);
+    }
+
+    #[test]
+    fn try_intersection_agrees_for_valid_surfaces() {
+        let s1 = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let s2 = unit_square(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Y);
+        let s3 = unit_square(Vec3::new(0.0, 0.5, -0.5), Vec3::X, Vec3::Z);
+        for (a, b) in [(&s1, &s2), (&s1, &s3), (&s3, &s1)] {
+            assert_eq!(a.try_intersection(b), Ok(a.intersection(b)));
+        }
+    }
+
+    #[test]
+    fn try_intersection_rejects_bad_surfaces() {
+        let s1 = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let nan_origin = unit_square(Vec3::new(f32::NAN, 0.0, 0.0), Vec3::X, Vec3::Y);
+        assert_eq!(
+            s1.try_intersection(&nan_origin),
+            Err(NeoGeometryError::NonFinite)
+        );
+
+        let mut nan_shape = s1.clone();
+        nan_shape
+            .shape
+            .exterior_mut(|ring| ring.0[1].x = f32::INFINITY);
+        assert_eq!(
+            nan_shape.try_intersection(&s1),
+            Err(NeoGeometryError::NonFinite)
+        );
+
+        let mut flat = s1.clone();
+        flat.coordinate_system.plane.normal = Vec3::ZERO;
+        assert!(matches!(
+            s1.try_intersection(&flat),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+    }
+
+    #[test]
+    fn intersects_doesnt_panic_on_bad_surfaces() {
+        let s1 = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let s2 = unit_square(Vec3::new(0.5, 0.5, 0.0), Vec3::X, Vec3::Y);
+        assert_eq!(s1.try_intersects(&s2), Ok(true));
+
+        let mut flat = s1.clone();
+        flat.coordinate_system.plane.normal = Vec3::ZERO;
+        assert!(!s1.intersects(&flat));
+        assert_eq!(s1.intersection(&flat), SurfaceSurface3DIntersection::None);
+        assert!(matches!(
+            s1.try_intersects(&flat),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+    }
 }
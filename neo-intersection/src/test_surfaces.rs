@@ -0,0 +1,13 @@
+use glam::{Vec2, Vec3};
+use neo_coordinate_system::CoordinateSystem;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_plane::Plane;
+use neo_surface::surface::def::NeoSurface;
+
+/// unit square with its corner at `origin`, spanned by `local_x` and `local_y`
+pub(crate) fn unit_square(origin: Vec3, local_x: Vec3, local_y: Vec3) -> NeoSurface {
+    let p = Plane::from_local_axis(local_x, local_y);
+    let c = CoordinateSystem::from_origin_and_plane(origin, p);
+    let shape = geo::Rect::<f32>::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon();
+    NeoSurface::new(c, geo::Coord::zero(), shape)
+}
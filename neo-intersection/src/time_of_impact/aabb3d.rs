@@ -0,0 +1,225 @@
+use glam::Vec3;
+use neo_aabb::d3::def::AABB3D;
+use neo_line_segment::d3::constants::NEO_LINE_SEGMENT_3D_EPS;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::aabb3d::{aabb_corners, aabb_edges};
+use crate::time_of_impact::line3d::{point_enters_aabb, point_hits_surface, segment_hits_segment};
+use crate::time_of_impact::result::TimeOfImpact3D;
+use crate::trait_def::{NeoDistance, NeoTimeOfImpact};
+
+impl NeoTimeOfImpact for AABB3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &Self) -> Option<Self::Output> {
+        // slab test of the relative movement, the boxes touch while they overlap on all axis
+        let (mut t_enter, mut t_exit) = (f32::NEG_INFINITY, f32::INFINITY);
+        let mut normal = Vec3::ZERO;
+        for axis in 0..3 {
+            let d = velocity[axis];
+            let (gap_start, gap_end) = (
+                rhs.min[axis] - self.max[axis],
+                rhs.max[axis] - self.min[axis],
+            );
+            if d.abs() < f32::EPSILON {
+                if gap_start > 0.0 || gap_end < 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let [a, b] = [gap_start, gap_end].map(|gap| gap / d);
+            if a.min(b) > t_enter {
+                t_enter = a.min(b);
+                normal = Vec3::ZERO;
+                normal[axis] = -d.signum();
+            }
+            t_exit = t_exit.min(a.max(b));
+        }
+        if t_enter > t_exit || t_enter > 1.0 || t_exit < 0.0 {
+            return None;
+        }
+        // boxes overlapping at the start have a contact at `t = 0`
+        let t = t_enter.max(0.0);
+        if t == 0.0 {
+            normal = Vec3::ZERO;
+        }
+        let moved = AABB3D {
+            min: self.min + velocity * t,
+            max: self.max + velocity * t,
+        };
+        let contact = AABB3D {
+            min: moved.min.max(rhs.min),
+            max: moved.max.min(rhs.max),
+        };
+        Some(TimeOfImpact3D::new(t, contact.center(), normal, velocity))
+    }
+}
+
+impl NeoTimeOfImpact<LineSegment3D> for AABB3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &LineSegment3D) -> Option<Self::Output> {
+        // seen from the box, the segment moves with the negated velocity
+        rhs.time_of_impact(-velocity, self)
+            .map(|toi| toi.swapped(velocity))
+    }
+}
+
+impl NeoTimeOfImpact<NeoSurface> for AABB3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &NeoSurface) -> Option<Self::Output> {
+        let start = NeoDistance::distance(self, rhs);
+        if start.distance < NEO_LINE_SEGMENT_3D_EPS {
+            return Some(TimeOfImpact3D::new(
+                0.0,
+                start.lhs_point,
+                rhs.coordinate_system.plane.normal,
+                velocity,
+            ));
+        }
+        if velocity == Vec3::ZERO {
+            return None;
+        }
+        let polygon = rhs.as_polygon_3d();
+        // a corner of the box hits the inside of the surface ...
+        let corners = aabb_corners(self).into_iter().filter_map(|p| {
+            point_hits_surface(p, velocity, rhs)
+                .map(|(t, normal)| TimeOfImpact3D::new(t, p + velocity * t, normal, velocity))
+        });
+        // ... or a corner of the surface hits a face of the box ...
+        let surface_corners = polygon.iter_all_points().filter_map(|p| {
+            point_enters_aabb(*p, -velocity, self)
+                .map(|(t, normal)| TimeOfImpact3D::new(t, *p, normal, velocity))
+        });
+        // ... or an edge of the box hits an edge of the surface
+        let edges = aabb_edges(self).into_iter().flat_map(|edge| {
+            polygon.iter_all_lines().filter_map(move |surface_edge| {
+                segment_hits_segment(&edge, velocity, &surface_edge)
+            })
+        });
+        TimeOfImpact3D::first(corners.chain(surface_corners).chain(edges))
+    }
+}
+
+#[cfg(test)]
+mod aabb3d_time_of_impact {
+    use glam::{Vec2, Vec3};
+    use neo_aabb::d3::def::AABB3D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::trait_def::NeoTimeOfImpact;
+
+    const EPS: f32 = 0.000_1;
+
+    fn assert_vec_eq(a: Vec3, b: Vec3) {
+        assert!(a.distance(b) < EPS, "{a:?} != {b:?}");
+    }
+
+    /// square floor in the plane `z = 0` spanning `[-2, 2]` with a square hole spanning
+    /// `[-hole, hole]`
+    fn floor_with_hole(hole: f32) -> NeoSurface {
+        let c = CoordinateSystem::from_origin_and_plane(
+            Vec3::ZERO,
+            Plane::from_local_axis(Vec3::X, Vec3::Y),
+        );
+        let ring = |size: f32| {
+            geo::Rect::new(Vec2::splat(-size).to_geo(), Vec2::splat(size).to_geo())
+                .to_polygon()
+                .exterior()
+                .clone()
+        };
+        NeoSurface::new(
+            c,
+            geo::Coord::zero(),
+            geo::Polygon::new(ring(2.0), vec![ring(hole)]),
+        )
+    }
+
+    fn unit_box_at(min: Vec3) -> AABB3D {
+        AABB3D::new(min, min + Vec3::ONE)
+    }
+
+    #[test]
+    fn box_hits_box() {
+        let moving = unit_box_at(Vec3::ZERO);
+        let obstacle = AABB3D::new(Vec3::new(3.0, 0.5, -2.0), Vec3::new(4.0, 3.0, 2.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(4.0, 0.0, 0.0), &obstacle)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec3::new(3.0, 0.75, 0.5));
+        assert_vec_eq(toi.normal, Vec3::NEG_X);
+
+        assert_eq!(
+            moving.time_of_impact(Vec3::new(1.0, 0.0, 0.0), &obstacle),
+            None
+        );
+        assert_eq!(
+            moving.time_of_impact(Vec3::new(-4.0, 0.0, 0.0), &obstacle),
+            None
+        );
+        assert_eq!(
+            moving.time_of_impact(Vec3::new(4.0, 0.0, 8.0), &obstacle),
+            None
+        );
+
+        let overlapping = unit_box_at(Vec3::splat(0.5));
+        let toi = moving.time_of_impact(Vec3::X, &overlapping).expect("hit");
+        assert_eq!(toi.t, 0.0);
+        assert_vec_eq(toi.point, Vec3::splat(0.75));
+    }
+
+    #[test]
+    fn box_hits_segment() {
+        let moving = unit_box_at(Vec3::ZERO);
+        let rope = LineSegment3D::new(Vec3::new(0.5, -2.0, 3.0), Vec3::new(0.5, 2.0, 3.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(0.0, 0.0, 4.0), &rope)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!((toi.point.z - 3.0).abs() < EPS);
+        assert!((0.0..=1.0).contains(&toi.point.y));
+        assert_vec_eq(toi.normal, Vec3::NEG_Z);
+    }
+
+    #[test]
+    fn box_falls_on_floor() {
+        let floor = floor_with_hole(0.25);
+        let moving = unit_box_at(Vec3::new(0.5, 0.5, 1.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!(toi.point.z.abs() < EPS);
+        assert_vec_eq(toi.normal, Vec3::Z);
+    }
+
+    #[test]
+    fn box_catches_on_the_rim_of_a_hole() {
+        let floor = floor_with_hole(0.75);
+        let moving = AABB3D::new(Vec3::new(-0.5, -0.5, 1.0), Vec3::new(1.0, 0.5, 2.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!(toi.point.z.abs() < EPS);
+        assert!(toi.point.x >= 0.75 - EPS);
+        assert_vec_eq(toi.normal, Vec3::Z);
+
+        // a box smaller than the hole falls through
+        let small = unit_box_at(Vec3::new(-0.5, -0.5, 1.0));
+        assert_eq!(
+            small.time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor),
+            None
+        );
+    }
+}
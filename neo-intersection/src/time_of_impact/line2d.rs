@@ -0,0 +1,138 @@
+use glam::Vec2;
+use neo_line_segment::d2::constants::NEO_LINE_SEGMENT_2D_EPS;
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::time_of_impact::result::TimeOfImpact2D;
+use crate::trait_def::{NeoDistance, NeoTimeOfImpact};
+
+impl NeoTimeOfImpact for LineSegment2D {
+    type Velocity = Vec2;
+    type Output = TimeOfImpact2D;
+
+    fn time_of_impact(&self, velocity: Vec2, rhs: &Self) -> Option<Self::Output> {
+        let start = NeoDistance::distance(self, rhs);
+        if start.distance < NEO_LINE_SEGMENT_2D_EPS {
+            return Some(TimeOfImpact2D::new(
+                0.0,
+                start.lhs_point,
+                rhs.normal(),
+                velocity,
+            ));
+        }
+        if velocity == Vec2::ZERO {
+            return None;
+        }
+        // the first contact always involves an endpoint of one of the segments
+        let moving_endpoints = self.array().into_iter().filter_map(|p| {
+            point_hits_segment(p, velocity, rhs)
+                .map(|(t, normal)| TimeOfImpact2D::new(t, p + velocity * t, normal, velocity))
+        });
+        // seen from `self`, the endpoints of `rhs` move with the negated velocity
+        let static_endpoints = rhs.array().into_iter().filter_map(|p| {
+            point_hits_segment(p, -velocity, self)
+                .map(|(t, normal)| TimeOfImpact2D::new(t, p, normal, velocity))
+        });
+        TimeOfImpact2D::first(moving_endpoints.chain(static_endpoints))
+    }
+}
+
+/// first time `t` in `[0, 1]` at which `point + t * velocity` is located on the segment together
+/// with the normal of the segment. Points moving along the segment don't have a normal, it's zero
+/// then
+pub(crate) fn point_hits_segment(
+    point: Vec2,
+    velocity: Vec2,
+    segment: &LineSegment2D,
+) -> Option<(f32, Vec2)> {
+    let direction = segment.direction();
+    let w = segment.src - point;
+    let denom = velocity.perp_dot(direction);
+    if denom.abs() > f32::EPSILON * velocity.length() * direction.length() {
+        let t = w.perp_dot(direction) / denom;
+        let u = w.perp_dot(velocity) / denom;
+        let u_eps = NEO_LINE_SEGMENT_2D_EPS / direction.length();
+        return ((0.0..=1.0).contains(&t) && (-u_eps..=1.0 + u_eps).contains(&u))
+            .then_some((t, segment.normal()));
+    }
+    // parallel movement only hits the segment if the point slides along its line
+    if w.perp_dot(direction).abs() > NEO_LINE_SEGMENT_2D_EPS * direction.length() {
+        return None;
+    }
+    let [t_src, t_dst] = segment
+        .array()
+        .map(|p| (p - point).dot(velocity) / velocity.length_squared());
+    let t = t_src.min(t_dst).max(0.0);
+    (t <= t_src.max(t_dst) && t <= 1.0).then_some((t, Vec2::ZERO))
+}
+
+#[cfg(test)]
+mod line2d_time_of_impact {
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::trait_def::NeoTimeOfImpact;
+
+    const EPS: f32 = 0.000_1;
+
+    fn assert_vec_eq(a: Vec2, b: Vec2) {
+        assert!(a.distance(b) < EPS, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn endpoint_hits_segment() {
+        let moving = LineSegment2D::new(Vec2::ZERO, Vec2::new(-1.0, 0.0));
+        let wall = LineSegment2D::new(Vec2::new(2.0, -1.0), Vec2::new(2.0, 1.0));
+        let toi = moving
+            .time_of_impact(Vec2::new(4.0, 0.0), &wall)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec2::new(2.0, 0.0));
+        assert_vec_eq(toi.normal, Vec2::NEG_X);
+    }
+
+    #[test]
+    fn segment_hits_static_endpoint() {
+        let moving = LineSegment2D::new(Vec2::new(0.0, -1.0), Vec2::new(0.0, 1.0));
+        let obstacle = LineSegment2D::new(Vec2::new(1.0, 0.5), Vec2::new(3.0, 0.5));
+        let toi = moving
+            .time_of_impact(Vec2::new(2.0, 0.0), &obstacle)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec2::new(1.0, 0.5));
+        assert_vec_eq(toi.normal, Vec2::NEG_X);
+    }
+
+    #[test]
+    fn collinear_segments_slide_into_each_other() {
+        let moving = LineSegment2D::new(Vec2::ZERO, Vec2::X);
+        let obstacle = LineSegment2D::new(Vec2::new(3.0, 0.0), Vec2::new(4.0, 0.0));
+        let toi = moving
+            .time_of_impact(Vec2::new(4.0, 0.0), &obstacle)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec2::new(3.0, 0.0));
+        assert_vec_eq(toi.normal, Vec2::NEG_X);
+    }
+
+    #[test]
+    fn misses_and_too_slow() {
+        let moving = LineSegment2D::new(Vec2::ZERO, Vec2::Y);
+        let obstacle = LineSegment2D::new(Vec2::new(2.0, 2.0), Vec2::new(2.0, 3.0));
+        assert_eq!(moving.time_of_impact(Vec2::new(4.0, 0.0), &obstacle), None);
+        let obstacle = LineSegment2D::new(Vec2::new(2.0, 0.0), Vec2::new(2.0, 1.0));
+        assert_eq!(moving.time_of_impact(Vec2::new(1.0, 0.0), &obstacle), None);
+        assert_eq!(moving.time_of_impact(Vec2::ZERO, &obstacle), None);
+        assert_eq!(moving.time_of_impact(Vec2::new(-4.0, 0.0), &obstacle), None);
+    }
+
+    #[test]
+    fn touching_segments_hit_at_start() {
+        let moving = LineSegment2D::new(Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0));
+        let obstacle = LineSegment2D::new(Vec2::new(0.0, -1.0), Vec2::new(0.0, 1.0));
+        let toi = moving
+            .time_of_impact(Vec2::new(0.0, 1.0), &obstacle)
+            .expect("hit");
+        assert_eq!(toi.t, 0.0);
+        assert_vec_eq(toi.point, Vec2::ZERO);
+    }
+}
@@ -0,0 +1,311 @@
+use glam::{Vec2, Vec3};
+use neo_aabb::d3::def::AABB3D;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::constants::NEO_LINE_SEGMENT_3D_EPS;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+
+use crate::distance::aabb3d::aabb_edges;
+use crate::distance::point3d::signed_plane_distance;
+use crate::time_of_impact::result::TimeOfImpact3D;
+use crate::trait_def::{NeoDistance, NeoTimeOfImpact};
+
+impl NeoTimeOfImpact for LineSegment3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &Self) -> Option<Self::Output> {
+        let start = NeoDistance::distance(self, rhs);
+        if start.distance < NEO_LINE_SEGMENT_3D_EPS {
+            return Some(TimeOfImpact3D::new(
+                0.0,
+                start.lhs_point,
+                self.direction().cross(rhs.direction()),
+                velocity,
+            ));
+        }
+        if velocity == Vec3::ZERO {
+            return None;
+        }
+        segment_hits_segment(self, velocity, rhs)
+    }
+}
+
+impl NeoTimeOfImpact<AABB3D> for LineSegment3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &AABB3D) -> Option<Self::Output> {
+        let start = NeoDistance::distance(self, rhs);
+        if start.distance < NEO_LINE_SEGMENT_3D_EPS {
+            return Some(TimeOfImpact3D::new(
+                0.0,
+                start.lhs_point,
+                Vec3::ZERO,
+                velocity,
+            ));
+        }
+        if velocity == Vec3::ZERO {
+            return None;
+        }
+        // either an endpoint hits a face of the box or the segment hits one of its edges
+        let endpoints = self.array().into_iter().filter_map(|p| {
+            point_enters_aabb(p, velocity, rhs)
+                .map(|(t, normal)| TimeOfImpact3D::new(t, p + velocity * t, normal, velocity))
+        });
+        let edges = aabb_edges(rhs)
+            .into_iter()
+            .filter_map(|edge| segment_hits_segment(self, velocity, &edge));
+        TimeOfImpact3D::first(endpoints.chain(edges))
+    }
+}
+
+impl NeoTimeOfImpact<NeoSurface> for LineSegment3D {
+    type Velocity = Vec3;
+    type Output = TimeOfImpact3D;
+
+    fn time_of_impact(&self, velocity: Vec3, rhs: &NeoSurface) -> Option<Self::Output> {
+        let start = NeoDistance::distance(self, rhs);
+        if start.distance < NEO_LINE_SEGMENT_3D_EPS {
+            return Some(TimeOfImpact3D::new(
+                0.0,
+                start.lhs_point,
+                rhs.coordinate_system.plane.normal,
+                velocity,
+            ));
+        }
+        if velocity == Vec3::ZERO {
+            return None;
+        }
+        // either an endpoint hits the inside of the surface or the segment hits its boundary
+        let endpoints = self.array().into_iter().filter_map(|p| {
+            point_hits_surface(p, velocity, rhs)
+                .map(|(t, normal)| TimeOfImpact3D::new(t, p + velocity * t, normal, velocity))
+        });
+        let polygon = rhs.as_polygon_3d();
+        let boundary = polygon
+            .iter_all_lines()
+            .filter_map(|edge| segment_hits_segment(self, velocity, &edge));
+        TimeOfImpact3D::first(endpoints.chain(boundary))
+    }
+}
+
+/// first contact of the moving segment with the static one in the time step
+pub(crate) fn segment_hits_segment(
+    moving: &LineSegment3D,
+    velocity: Vec3,
+    segment: &LineSegment3D,
+) -> Option<TimeOfImpact3D> {
+    // solve `moving.src + t * velocity + u * da = segment.src + s * db` with Cramer's rule
+    let (da, db) = (moving.direction(), segment.direction());
+    let w = segment.src - moving.src;
+    let det = velocity.dot(da.cross(-db));
+    let scale = velocity.length() * da.length() * db.length();
+    if det.abs() > f32::EPSILON * scale {
+        let t = w.dot(da.cross(-db)) / det;
+        let u = velocity.dot(w.cross(-db)) / det;
+        let s = velocity.dot(da.cross(w)) / det;
+        let in_segment = |x: f32, length: f32| {
+            let eps = NEO_LINE_SEGMENT_3D_EPS / length;
+            (-eps..=1.0 + eps).contains(&x)
+        };
+        return ((0.0..=1.0).contains(&t)
+            && in_segment(u, da.length())
+            && in_segment(s, db.length()))
+        .then(|| TimeOfImpact3D::new(t, segment.inject_scalar(s), da.cross(db), velocity));
+    }
+    coplanar_segment_hits_segment(moving, velocity, segment)
+}
+
+/// the segments and the movement are located in a common plane, so this is a 2D problem
+fn coplanar_segment_hits_segment(
+    moving: &LineSegment3D,
+    velocity: Vec3,
+    segment: &LineSegment3D,
+) -> Option<TimeOfImpact3D> {
+    let (da, db) = (moving.direction(), segment.direction());
+    let normal = [da.cross(db), da.cross(velocity), db.cross(velocity)]
+        .into_iter()
+        .find(|n| n.length_squared() > f32::EPSILON)
+        .unwrap_or_else(|| velocity.any_orthogonal_vector())
+        .normalize();
+    let origin = moving.src;
+    if (segment.src - origin).dot(normal).abs() > NEO_LINE_SEGMENT_3D_EPS {
+        return None;
+    }
+    let (x, y) = normal.any_orthonormal_pair();
+    let project = |p: Vec3| Vec2::new((p - origin).dot(x), (p - origin).dot(y));
+    let to_2d = |l: &LineSegment3D| LineSegment2D::new(project(l.src), project(l.dst));
+    let toi = to_2d(moving)
+        .time_of_impact(Vec2::new(velocity.dot(x), velocity.dot(y)), &to_2d(segment))?;
+    Some(TimeOfImpact3D::new(
+        toi.t,
+        origin + toi.point.x * x + toi.point.y * y,
+        toi.normal.x * x + toi.normal.y * y,
+        velocity,
+    ))
+}
+
+/// first time `t` in `[0, 1]` at which `point + t * velocity` crosses the inside of the surface
+/// together with the surface normal. Movements within the plane of the surface don't cross it
+pub(crate) fn point_hits_surface(
+    point: Vec3,
+    velocity: Vec3,
+    surface: &NeoSurface,
+) -> Option<(f32, Vec3)> {
+    let coord_sys = &surface.coordinate_system;
+    let [d_start, d_end] = [point, point + velocity].map(|p| signed_plane_distance(coord_sys, p));
+    if d_start * d_end > 0.0 || d_start == d_end {
+        return None;
+    }
+    let t = d_start / (d_start - d_end);
+    surface
+        .is_point_in_surface(point + velocity * t)
+        .then_some((t, coord_sys.plane.normal))
+}
+
+/// first time `t` in `[0, 1]` at which `point + t * velocity` enters the box together with the
+/// normal of the entered face. Points starting inside the box don't have a normal, it's zero then
+pub(crate) fn point_enters_aabb(point: Vec3, velocity: Vec3, aabb: &AABB3D) -> Option<(f32, Vec3)> {
+    let (mut t_enter, mut t_exit) = (0.0_f32, 1.0_f32);
+    let mut normal = Vec3::ZERO;
+    for axis in 0..3 {
+        let (o, d) = (point[axis], velocity[axis]);
+        if d.abs() < f32::EPSILON {
+            if o < aabb.min[axis] || o > aabb.max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let [a, b] = [aabb.min[axis], aabb.max[axis]].map(|bound| (bound - o) / d);
+        if a.min(b) > t_enter {
+            t_enter = a.min(b);
+            normal = Vec3::ZERO;
+            normal[axis] = -d.signum();
+        }
+        t_exit = t_exit.min(a.max(b));
+    }
+    (t_enter <= t_exit).then_some((t_enter, normal))
+}
+
+#[cfg(test)]
+mod line3d_time_of_impact {
+    use glam::{Vec2, Vec3};
+    use neo_aabb::d3::def::AABB3D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+
+    use crate::trait_def::NeoTimeOfImpact;
+
+    const EPS: f32 = 0.000_1;
+
+    fn assert_vec_eq(a: Vec3, b: Vec3) {
+        assert!(a.distance(b) < EPS, "{a:?} != {b:?}");
+    }
+
+    /// square with side length 2 in the plane `z = 0` with a square hole of side length 1
+    fn holed_floor() -> NeoSurface {
+        let c = CoordinateSystem::from_origin_and_plane(
+            Vec3::ZERO,
+            Plane::from_local_axis(Vec3::X, Vec3::Y),
+        );
+        let shape = geo::Polygon::new(
+            geo::Rect::new(Vec2::splat(-1.0).to_geo(), Vec2::ONE.to_geo())
+                .to_polygon()
+                .exterior()
+                .clone(),
+            vec![
+                geo::Rect::new(Vec2::splat(-0.5).to_geo(), Vec2::splat(0.5).to_geo())
+                    .to_polygon()
+                    .exterior()
+                    .clone(),
+            ],
+        );
+        NeoSurface::new(c, geo::Coord::zero(), shape)
+    }
+
+    #[test]
+    fn crossing_segments() {
+        let moving = LineSegment3D::new(Vec3::new(-1.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+        let rope = LineSegment3D::new(Vec3::new(0.0, 2.0, -1.0), Vec3::new(0.0, 2.0, 1.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(0.0, 4.0, 0.0), &rope)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec3::new(0.0, 2.0, 0.0));
+        assert_vec_eq(toi.normal, Vec3::NEG_Y);
+        // the same movement at a different height passes below the rope
+        let below = LineSegment3D::new(Vec3::new(-1.0, 0.0, -2.0), Vec3::new(1.0, 0.0, -2.0));
+        assert_eq!(below.time_of_impact(Vec3::new(0.0, 4.0, 0.0), &rope), None);
+    }
+
+    #[test]
+    fn coplanar_segments() {
+        let moving = LineSegment3D::new(Vec3::ZERO, Vec3::Z);
+        let rail = LineSegment3D::new(Vec3::new(2.0, 2.0, 0.5), Vec3::new(2.0, 2.0, 3.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(4.0, 4.0, 0.0), &rail)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!(toi.point.truncate().distance(Vec2::splat(2.0)) < EPS);
+        assert!((0.5..=1.0).contains(&toi.point.z));
+        assert!(toi.normal.dot(Vec3::new(1.0, 1.0, 0.0)) < 0.0);
+        assert!(toi.normal.z.abs() < EPS);
+    }
+
+    #[test]
+    fn segment_hits_box_face_and_edge() {
+        let aabb = AABB3D::new(Vec3::new(2.0, -1.0, -1.0), Vec3::new(3.0, 1.0, 1.0));
+        let moving = LineSegment3D::new(Vec3::new(0.0, 0.0, 0.0), Vec3::new(-1.0, 0.0, 0.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(4.0, 0.0, 0.0), &aabb)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec3::new(2.0, 0.0, 0.0));
+        assert_vec_eq(toi.normal, Vec3::NEG_X);
+
+        // a vertical segment crossing the height of the box hits its front edge at y = 1
+        let moving = LineSegment3D::new(Vec3::new(2.5, 3.0, -5.0), Vec3::new(2.5, 3.0, 5.0));
+        let toi = moving
+            .time_of_impact(Vec3::new(0.0, -4.0, 0.0), &aabb)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!((toi.point.y - 1.0).abs() < EPS);
+        assert!((toi.point.x - 2.5).abs() < EPS);
+        assert_vec_eq(toi.normal, Vec3::Y);
+
+        assert_eq!(moving.time_of_impact(Vec3::new(0.0, 4.0, 0.0), &aabb), None);
+    }
+
+    #[test]
+    fn segment_hits_surface_inside_and_boundary() {
+        let floor = holed_floor();
+        let falling = LineSegment3D::new(Vec3::new(0.75, 0.0, 1.0), Vec3::new(0.75, 0.0, 2.0));
+        let toi = falling
+            .time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert_vec_eq(toi.point, Vec3::new(0.75, 0.0, 0.0));
+        assert_vec_eq(toi.normal, Vec3::Z);
+
+        // a horizontal bar mostly above the hole catches on the rim of the hole
+        let bar = LineSegment3D::new(Vec3::new(-0.4, 0.0, 1.0), Vec3::new(0.6, 0.0, 1.0));
+        let toi = bar
+            .time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor)
+            .expect("hit");
+        assert!((toi.t - 0.5).abs() < EPS);
+        assert!((0.5..=0.6).contains(&toi.point.x));
+        assert!(toi.point.z.abs() < EPS);
+        assert_vec_eq(toi.normal, Vec3::Z);
+
+        // a short bar falls through the hole
+        let short_bar = LineSegment3D::new(Vec3::new(-0.4, 0.0, 1.0), Vec3::new(0.4, 0.0, 1.0));
+        assert_eq!(
+            short_bar.time_of_impact(Vec3::new(0.0, 0.0, -2.0), &floor),
+            None
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod aabb3d;
+pub mod line2d;
+pub mod line3d;
+pub mod result;
@@ -0,0 +1,77 @@
+use glam::{Vec2, Vec3};
+
+use crate::float_ord_cmp;
+
+/// first contact of a moving 2D geometry with a static one. The moving geometry travels by its
+/// velocity during one time step, so `t` is located in `[0, 1]`. The contact `point` is given at
+/// time `t` and the `normal` is normalized and points against the movement, away from the static
+/// geometry. Geometries which already touch at the start have a contact at `t = 0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfImpact2D {
+    pub t: f32,
+    pub point: Vec2,
+    pub normal: Vec2,
+}
+
+impl TimeOfImpact2D {
+    /// orients the `normal` against the `velocity`
+    pub(crate) fn new(t: f32, point: Vec2, normal: Vec2, velocity: Vec2) -> Self {
+        let normal = normal.normalize_or_zero();
+        let normal = if normal == Vec2::ZERO {
+            -velocity.normalize_or_zero()
+        } else if normal.dot(velocity) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        Self { t, point, normal }
+    }
+
+    pub(crate) fn first(candidates: impl IntoIterator<Item = Self>) -> Option<Self> {
+        candidates
+            .into_iter()
+            .min_by(|a, b| float_ord_cmp(&a.t, &b.t))
+    }
+}
+
+/// first contact of a moving 3D geometry with a static one. The moving geometry travels by its
+/// velocity during one time step, so `t` is located in `[0, 1]`. The contact `point` is given at
+/// time `t` and the `normal` is normalized and points against the movement, away from the static
+/// geometry. Geometries which already touch at the start have a contact at `t = 0`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeOfImpact3D {
+    pub t: f32,
+    pub point: Vec3,
+    pub normal: Vec3,
+}
+
+impl TimeOfImpact3D {
+    /// orients the `normal` against the `velocity`
+    pub(crate) fn new(t: f32, point: Vec3, normal: Vec3, velocity: Vec3) -> Self {
+        let normal = normal.normalize_or_zero();
+        let normal = if normal == Vec3::ZERO {
+            -velocity.normalize_or_zero()
+        } else if normal.dot(velocity) > 0.0 {
+            -normal
+        } else {
+            normal
+        };
+        Self { t, point, normal }
+    }
+
+    /// converts a contact, which was found by moving the static geometry with the negated
+    /// `velocity`, back to the frame in which the other geometry moves with `velocity`
+    pub(crate) fn swapped(self, velocity: Vec3) -> Self {
+        Self {
+            point: self.point + velocity * self.t,
+            normal: -self.normal,
+            ..self
+        }
+    }
+
+    pub(crate) fn first(candidates: impl IntoIterator<Item = Self>) -> Option<Self> {
+        candidates
+            .into_iter()
+            .min_by(|a, b| float_ord_cmp(&a.t, &b.t))
+    }
+}
@@ -1,5 +1,526 @@
-pub trait NeoIntersectable<Rhs = Self> {
+use neo_error::NeoGeometryError;
+
+use crate::intersection_set::{IntersectionDimension, IntersectionSet};
+
+/// intersection of two geometries. It never panics: input which can't be intersected, like non
+/// finite or degenerate shapes, gives the empty result. Use [`NeoTryIntersectable`] to tell such
+/// input apart from a miss
+pub trait NeoIntersectable<Rhs: ?Sized = Self> {
     type Output;
 
     fn intersection(&self, rhs: &Rhs) -> Self::Output;
 }
+
+/// cheap check whether two geometries intersect, without building the intersection. It agrees
+/// with [`NeoIntersectable::intersection`] returning a non empty result, but exits as early as
+/// possible. Like the intersection it never panics and reports `false` for input which can't be
+/// intersected, see [`NeoTryIntersects`]
+pub trait NeoIntersects<Rhs: ?Sized = Self> {
+    fn intersects(&self, rhs: &Rhs) -> bool;
+}
+
+/// fallible variant of [`NeoIntersectable`] for intersections which can fail on bad input. It
+/// reports non finite or degenerate input and numerical inconsistencies instead of panicking
+pub trait NeoTryIntersectable<Rhs = Self> {
+    type Output;
+
+    fn try_intersection(&self, rhs: &Rhs) -> Result<Self::Output, NeoGeometryError>;
+}
+
+/// fallible variant of [`NeoIntersects`], agrees with [`NeoTryIntersectable::try_intersection`]
+/// returning a non empty result
+pub trait NeoTryIntersects<Rhs = Self> {
+    fn try_intersects(&self, rhs: &Rhs) -> Result<bool, NeoGeometryError>;
+}
+
+/// implements the mirrored direction `Rhs: NeoIntersectable<Lhs>` and `Rhs: NeoIntersects<Lhs>`
+/// for every listed `Rhs` by forwarding to the `Lhs` implementations. The result is the same in
+/// both operand orders, so call sites don't need to know which direction is implemented natively.
+///
+/// ```ignore
+/// mirror_intersectable!(Ray3D => Sphere3D, Cylinder3D);
+/// ```
+macro_rules! mirror_intersectable {
+    ($lhs:ty => $($rhs:ty),+ $(,)?) => {
+        $(
+            impl $crate::trait_def::NeoIntersectable<$lhs> for $rhs {
+                type Output = <$lhs as $crate::trait_def::NeoIntersectable<$rhs>>::Output;
+
+                fn intersection(&self, rhs: &$lhs) -> Self::Output {
+                    rhs.intersection(self)
+                }
+            }
+
+            impl $crate::trait_def::NeoIntersects<$lhs> for $rhs {
+                fn intersects(&self, rhs: &$lhs) -> bool {
+                    $crate::trait_def::NeoIntersects::intersects(rhs, self)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use mirror_intersectable;
+
+/// like [`mirror_intersectable`] for [`NeoTryIntersectable`] and [`NeoTryIntersects`]
+macro_rules! mirror_try_intersectable {
+    ($lhs:ty => $($rhs:ty),+ $(,)?) => {
+        $(
+            impl $crate::trait_def::NeoTryIntersectable<$lhs> for $rhs {
+                type Output = <$lhs as $crate::trait_def::NeoTryIntersectable<$rhs>>::Output;
+
+                fn try_intersection(
+                    &self,
+                    rhs: &$lhs,
+                ) -> Result<Self::Output, neo_error::NeoGeometryError> {
+                    rhs.try_intersection(self)
+                }
+            }
+
+            impl $crate::trait_def::NeoTryIntersects<$lhs> for $rhs {
+                fn try_intersects(&self, rhs: &$lhs) -> Result<bool, neo_error::NeoGeometryError> {
+                    $crate::trait_def::NeoTryIntersects::try_intersects(rhs, self)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use mirror_try_intersectable;
+
+/// minimum distance between two geometries together with the pair of points which realizes it.
+/// Rays are treated as unbounded lines, so points behind the origin count too
+pub trait NeoDistance<Rhs = Self> {
+    type Output;
+
+    fn distance(&self, rhs: &Rhs) -> Self::Output;
+}
+
+/// like [`mirror_intersectable`], but swaps the closest points so that `lhs_point` still belongs
+/// to the left hand side operand
+macro_rules! mirror_distance {
+    ($lhs:ty => $($rhs:ty),+ $(,)?) => {
+        $(
+            impl $crate::trait_def::NeoDistance<$lhs> for $rhs {
+                type Output = <$lhs as $crate::trait_def::NeoDistance<$rhs>>::Output;
+
+                fn distance(&self, rhs: &$lhs) -> Self::Output {
+                    rhs.distance(self).swapped()
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use mirror_distance;
+
+/// first contact of `self` moving by `velocity` during one time step with the static `rhs`
+pub trait NeoTimeOfImpact<Rhs = Self> {
+    type Velocity;
+    type Output;
+
+    fn time_of_impact(&self, velocity: Self::Velocity, rhs: &Rhs) -> Option<Self::Output>;
+}
+
+/// uniform view on the different intersection results, so generic code can consume any of them.
+/// Results without bounded geometry, like the ray where two planes meet or a polygon containing
+/// the other one, report their dimension but don't list that geometry
+pub trait NeoIntersectionResult {
+    type Point;
+    type Segment;
+    type Surface;
+
+    /// dimension of the intersection, `None` if nothing intersects
+    fn dimension(&self) -> Option<IntersectionDimension>;
+
+    fn points(&self) -> Vec<Self::Point> {
+        vec![]
+    }
+
+    fn segments(&self) -> Vec<Self::Segment> {
+        vec![]
+    }
+
+    fn surfaces(&self) -> Vec<Self::Surface> {
+        vec![]
+    }
+
+    fn is_empty(&self) -> bool {
+        self.dimension().is_none()
+    }
+
+    fn into_set(self) -> IntersectionSet<Self::Point, Self::Segment, Self::Surface>
+    where
+        Self: Sized,
+    {
+        IntersectionSet {
+            dimension: self.dimension(),
+            points: self.points(),
+            segments: self.segments(),
+            surfaces: self.surfaces(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod mirror {
+    use glam::{Vec2, Vec3};
+    use neo_aabb::d2::def::AABB2D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_primitive::d3::sphere::Sphere3D;
+    use neo_ray::d2::def::Ray2D;
+    use neo_ray::d3::def::Ray3D;
+
+    use crate::trait_def::NeoIntersectable;
+
+    #[test]
+    fn mirrored_2d_results_agree() {
+        let ray = Ray2D::new(Vec2::NEG_ONE, Vec2::ONE);
+        let line = LineSegment2D::new(Vec2::new(0.0, 1.0), Vec2::new(1.0, 0.0));
+        assert_eq!(ray.intersection(&line), line.intersection(&ray));
+
+        let aabb = AABB2D::new(Vec2::ZERO, Vec2::ONE);
+        assert_eq!(ray.intersection(&aabb), aabb.intersection(&ray));
+
+        let polygon = geo::Rect::<f32>::new((0.0, 0.0), (1.0, 1.0)).to_polygon();
+        assert_eq!(ray.intersection(&polygon), polygon.intersection(&ray));
+        assert_eq!(line.intersection(&polygon), polygon.intersection(&line));
+    }
+
+    #[test]
+    fn mirrored_3d_results_agree() {
+        let ray = Ray3D::new(Vec3::NEG_ONE, Vec3::ONE);
+        let coord_sys = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        assert_eq!(ray.intersection(&coord_sys), coord_sys.intersection(&ray));
+
+        let sphere = Sphere3D::new(Vec3::ZERO, 1.0);
+        assert_eq!(ray.intersection(&sphere), sphere.intersection(&ray));
+
+        let line = LineSegment3D::new(Vec3::NEG_ONE, Vec3::ONE);
+        assert_eq!(line.intersection(&coord_sys), coord_sys.intersection(&line));
+    }
+}
+
+#[cfg(test)]
+mod intersects {
+    use glam::{Quat, Vec2, Vec3};
+    use neo_aabb::d2::def::AABB2D;
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_primitive::d2::capsule::Capsule2D;
+    use neo_primitive::d2::circle::Circle2D;
+    use neo_primitive::d3::capsule::Capsule3D;
+    use neo_primitive::d3::cone::Cone3D;
+    use neo_primitive::d3::cylinder::Cylinder3D;
+    use neo_primitive::d3::sphere::Sphere3D;
+    use neo_ray::d2::def::Ray2D;
+    use neo_ray::d3::def::Ray3D;
+    use neo_surface::polygon3d::def::NeoPolygon3D;
+    use neo_surface::triangle3d::def::Triangle3D;
+
+    use crate::bvh::TriangleBvh3D;
+    use crate::culling::{CulledTriangle3D, TriangleCulling};
+    use crate::line2d::polygon::LinePolygon2DIntersection;
+    use crate::ray2d::aabb::RayAABB2DIntersection;
+    use crate::ray2d::polygon::RayPolygon2DIntersection;
+    use crate::results::*;
+    use crate::surface::coord_sys::SurfaceCoordSys3DIntersection;
+    use crate::test_surfaces::unit_square;
+    use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
+
+    /// checks both operand orders of `intersects` against `intersection`
+    fn assert_agrees<A, B>(a: &A, b: &B, is_non_empty: impl Fn(&A::Output) -> bool)
+    where
+        A: NeoIntersectable<B> + NeoIntersects<B> + std::fmt::Debug,
+        B: ?Sized,
+        B: NeoIntersectable<A, Output = A::Output> + NeoIntersects<A> + std::fmt::Debug,
+    {
+        assert_eq!(
+            a.intersects(b),
+            is_non_empty(&a.intersection(b)),
+            "{a:?}\n{b:?}"
+        );
+        assert_eq!(
+            b.intersects(a),
+            is_non_empty(&b.intersection(a)),
+            "{b:?}\n{a:?}"
+        );
+    }
+
+    fn rect_with_hole() -> geo::Polygon<f32> {
+        geo::Polygon::new(
+            geo::LineString::from(vec![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0)]),
+            vec![geo::LineString::from(vec![
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (2.0, 2.0),
+                (1.0, 2.0),
+            ])],
+        )
+    }
+
+    /// points on a grid around the unit square scaled by 3
+    fn grid_2d() -> impl Iterator<Item = Vec2> {
+        (-2..=8).flat_map(|x| (-2..=8).map(move |y| Vec2::new(x as f32, y as f32) * 0.5))
+    }
+
+    fn segments_2d() -> Vec<LineSegment2D> {
+        let offsets = [Vec2::X, Vec2::ONE, Vec2::new(0.3, -0.7), Vec2::Y * 0.25];
+        grid_2d()
+            .flat_map(|p| offsets.map(|o| LineSegment2D::new(p, p + o)))
+            .collect()
+    }
+
+    #[test]
+    fn line_2d_agrees() {
+        let polygon = rect_with_hole();
+        let linestring = polygon.exterior().clone();
+        let multi_linestring = geo::MultiLineString::new(polygon.interiors().to_vec());
+        let multi_polygon = geo::MultiPolygon::new(vec![polygon.clone()]);
+        let rect = geo::Rect::<f32>::new((0.5, 0.5), (2.5, 1.5));
+        let triangle = geo::Triangle::<f32>::from([(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)]);
+        for line in segments_2d() {
+            assert_agrees(&line, &polygon, |i| *i != LinePolygon2DIntersection::None);
+            assert_agrees(&line, &linestring, |i| !i.is_empty());
+            assert_agrees(&line, &multi_linestring, |i| !i.is_empty());
+            assert_agrees(&line, &multi_polygon, |i| !i.is_empty());
+            assert_agrees(&line, &rect, |i| !i.is_empty());
+            assert_agrees(&line, &triangle, |i| !i.is_empty());
+            for other in [
+                LineSegment2D::new(Vec2::new(1.0, 0.25), Vec2::new(2.0, 1.75)),
+                LineSegment2D::new(Vec2::new(0.5, 1.0), Vec2::new(2.5, 1.0)),
+            ] {
+                assert_agrees(&line, &other, |i| {
+                    !matches!(
+                        i,
+                        LineLine2DIntersection::None
+                            | LineLine2DIntersection::Parallel
+                            | LineLine2DIntersection::CollinearNoOverlap
+                    )
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn ray_2d_agrees() {
+        let polygon = rect_with_hole();
+        let linestring = polygon.exterior().clone();
+        let multi_linestring = geo::MultiLineString::new(polygon.interiors().to_vec());
+        let multi_polygon = geo::MultiPolygon::new(vec![polygon.clone()]);
+        let rect = geo::Rect::<f32>::new((0.5, 0.5), (2.5, 1.5));
+        let triangle = geo::Triangle::<f32>::from([(0.0, 0.0), (3.0, 0.0), (0.0, 3.0)]);
+        let aabb = AABB2D::new(Vec2::splat(1.0), Vec2::splat(2.0));
+        let circle = Circle2D::new(Vec2::splat(1.5), 0.5);
+        let capsule = Capsule2D::new(LineSegment2D::new(Vec2::ONE, Vec2::new(2.0, 1.5)), 0.25);
+        let segment = LineSegment2D::new(Vec2::new(0.5, 1.0), Vec2::new(2.5, 1.0));
+        let others = [
+            Ray2D::new(Vec2::ZERO, Vec2::X),
+            Ray2D::new(Vec2::Y, Vec2::X),
+            Ray2D::new(Vec2::ONE, Vec2::new(0.3, -0.7)),
+        ];
+        for ray in segments_2d().into_iter().map(|l| l.ray()) {
+            assert_agrees(&ray, &polygon, |i| *i != RayPolygon2DIntersection::None);
+            assert_agrees(&ray, &linestring, |i| !i.is_empty());
+            assert_agrees(&ray, &multi_linestring, |i| !i.is_empty());
+            assert_agrees(&ray, &multi_polygon, |i| !i.is_empty());
+            assert_agrees(&ray, &rect, |i| !i.is_empty());
+            assert_agrees(&ray, &triangle, |i| !i.is_empty());
+            assert_agrees(&ray, &aabb, |i| *i != RayAABB2DIntersection::None);
+            assert_agrees(&ray, &circle, |i| *i != RaySolid2DIntersection::None);
+            assert_agrees(&ray, &capsule, |i| *i != RaySolid2DIntersection::None);
+            assert_agrees(&ray, &segment, |i| {
+                !matches!(
+                    i,
+                    RayLine2DIntersection::None | RayLine2DIntersection::Parallel
+                )
+            });
+            for other in &others {
+                assert_agrees(&ray, other, |i| *i != RayRay2DIntersection::Parallel);
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_polygon_agrees() {
+        let polygon = rect_with_hole();
+        for p in grid_2d() {
+            let other = geo::Rect::<f32>::new(p.to_geo(), (p + Vec2::splat(0.5)).to_geo());
+            assert_agrees(&polygon, &other.to_polygon(), |i| {
+                *i != PolygonPolygon2DIntersection::Disjoint
+            });
+        }
+    }
+
+    fn grid_3d() -> impl Iterator<Item = Vec3> {
+        (-2..=4).flat_map(|x| {
+            (-2..=4).flat_map(move |y| {
+                (-2..=4).map(move |z| Vec3::new(x as f32, y as f32, z as f32) * 0.5)
+            })
+        })
+    }
+
+    #[test]
+    fn ray_3d_agrees() {
+        let surface = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let sphere = Sphere3D::new(Vec3::splat(0.5), 0.5);
+        let cylinder = Cylinder3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::Z), 0.5);
+        let cone = Cone3D::new(Vec3::Z, Vec3::ZERO, 0.5);
+        let triangles = vec![
+            Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y),
+            Triangle3D::new(Vec3::X, Vec3::ONE, Vec3::Y),
+        ];
+        let bvh = TriangleBvh3D::new(triangles.clone());
+        let capsule = Capsule3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::ONE), 0.25);
+        let plane = Plane::from_normal(Vec3::ONE);
+        let coord_sys = CoordinateSystem::from_origin_and_normal(Vec3::Z * 0.5, Vec3::Z);
+        let others = [
+            Ray3D::new(Vec3::ZERO, Vec3::X),
+            Ray3D::new(Vec3::Y, Vec3::ONE),
+            Ray3D::new(Vec3::new(0.5, 0.5, 1.0), Vec3::new(0.3, -0.7, 0.2)),
+        ];
+        let directions = [Vec3::X, Vec3::ONE, Vec3::new(0.3, -0.7, 0.2), Vec3::Z];
+        for ray in grid_3d().flat_map(|p| directions.map(|d| Ray3D::new(p, d))) {
+            assert_agrees(&ray, &surface, |i| {
+                matches!(
+                    i,
+                    SurfaceRay3DIntersection::Point(_) | SurfaceRay3DIntersection::Parts(_)
+                )
+            });
+            assert_agrees(&ray, &sphere, |i| *i != RaySolid3DIntersection::None);
+            assert_agrees(&ray, &cylinder, |i| *i != RaySolid3DIntersection::None);
+            assert_agrees(&ray, &cone, |i| *i != RaySolid3DIntersection::None);
+            assert_agrees(&ray, &capsule, |i| *i != RaySolid3DIntersection::None);
+            assert_agrees(&ray, triangles.as_slice(), |i| !i.is_empty());
+            assert_agrees(&ray, &bvh, |i| !i.is_empty());
+            for culling in [TriangleCulling::None, TriangleCulling::BackFace] {
+                let culled = CulledTriangle3D::new(triangles[0], culling);
+                assert_agrees(&ray, &triangles[0], |i| {
+                    matches!(i, RayTriangle3DIntersection::Hit(_))
+                });
+                assert_agrees(&ray, &culled, |i| {
+                    matches!(i, RayTriangle3DIntersection::Hit(_))
+                });
+            }
+            assert_agrees(&ray, &plane, |i| *i != RayCoordSys3DIntersection::Parallel);
+            assert_agrees(&ray, &coord_sys, |i| {
+                *i != RayCoordSys3DIntersection::Parallel
+            });
+            for other in &others {
+                assert_agrees(&ray, other, |i| {
+                    matches!(
+                        i,
+                        RayRay3DIntersection::Collinear | RayRay3DIntersection::Intersection(_)
+                    )
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn line_3d_agrees() {
+        let surface = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let coord_sys = surface.coordinate_system;
+        let polygon = NeoPolygon3D::from_outline_and_normal(
+            vec![Vec3::ZERO, Vec3::X, Vec3::Y + Vec3::Z],
+            Vec3::new(0.0, -1.0, 1.0),
+        );
+        let plane = Plane::from_normal(Vec3::new(1.0, 0.0, 1.0));
+        let others = [
+            LineSegment3D::new(Vec3::ZERO, Vec3::ONE),
+            LineSegment3D::new(Vec3::new(0.5, 0.0, 0.0), Vec3::new(0.5, 2.0, 0.0)),
+        ];
+        let offsets = [
+            Vec3::X,
+            Vec3::ONE,
+            Vec3::new(0.3, -0.7, -1.2),
+            Vec3::Y * 0.25,
+        ];
+        for line in grid_3d().flat_map(|p| offsets.map(|o| LineSegment3D::new(p, p + o))) {
+            assert_agrees(&line, &surface, |i| *i != LineSurface3DIntersection::None);
+            assert_agrees(&line, &polygon, |i| *i != LineSurface3DIntersection::None);
+            assert_agrees(&line, &coord_sys, |i| {
+                *i != LineCoordSys3DIntersection::None
+            });
+            assert_agrees(&line, &plane, |i| *i != LineCoordSys3DIntersection::None);
+            for other in &others {
+                assert_agrees(&line, other, |i| {
+                    !matches!(
+                        i,
+                        LineLine3DIntersection::None
+                            | LineLine3DIntersection::Parallel
+                            | LineLine3DIntersection::CollinearNoOverlap
+                    )
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn surface_surface_agrees() {
+        let surface = unit_square(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let rotations = [
+            Quat::IDENTITY,
+            Quat::from_rotation_z(0.7),
+            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            Quat::from_rotation_y(1.1),
+        ];
+        for other in
+            grid_3d().flat_map(|p| rotations.map(|r| unit_square(p, r * Vec3::X, r * Vec3::Y)))
+        {
+            assert_agrees(&surface, &other, |i| {
+                *i != SurfaceSurface3DIntersection::None
+            });
+            assert_agrees(&surface, &other.coordinate_system, |i| {
+                *i != SurfaceCoordSys3DIntersection::None
+            });
+            assert_agrees(&other, &Plane::from_normal(Vec3::ONE), |i| {
+                *i != SurfacePlane3DIntersection::None
+            });
+        }
+    }
+
+    #[test]
+    fn triangle_triangle_agrees() {
+        let triangle = Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y);
+        let rotations = [
+            Quat::IDENTITY,
+            Quat::from_rotation_z(0.7),
+            Quat::from_rotation_x(std::f32::consts::FRAC_PI_2),
+            Quat::from_rotation_y(1.1),
+        ];
+        for p in grid_3d() {
+            for r in rotations {
+                let other = Triangle3D::new(p, p + r * Vec3::X, p + r * Vec3::Y);
+                assert_agrees(&triangle, &other, |i| {
+                    *i != TriangleTriangle3DIntersection::None
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn planes_and_coordinate_systems_agree() {
+        let normals = [Vec3::X, Vec3::Z, Vec3::ONE, Vec3::new(0.3, -0.7, 0.2)];
+        let coord_sys = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        for normal in normals {
+            let plane = Plane::from_normal(normal);
+            for other in normals {
+                assert_agrees(&plane, &Plane::from_normal(other), |i| {
+                    i.dimension().is_some()
+                });
+            }
+            for p in grid_3d() {
+                let other = CoordinateSystem::from_origin_and_normal(p, normal);
+                assert_agrees(&coord_sys, &other, |i| {
+                    !matches!(i, CoordSysCoordSysIntersection::Parallel { .. })
+                });
+            }
+        }
+    }
+}
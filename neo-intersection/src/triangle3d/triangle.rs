@@ -0,0 +1,474 @@
+use glam::{Vec2, Vec3};
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_line_segment::d2::def::LineSegment2D;
+use neo_line_segment::d3::constants::NEO_LINE_SEGMENT_3D_EPS;
+use neo_line_segment::d3::def::LineSegment3D;
+use neo_surface::surface::def::NeoSurface;
+use neo_surface::triangle3d::def::Triangle3D;
+
+use crate::float_ord_cmp;
+use crate::intersection_set::IntersectionDimension;
+use crate::trait_def::{NeoIntersectable, NeoIntersectionResult, NeoIntersects};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriangleTriangle3DIntersection {
+    /// the triangles don't touch. Degenerated triangles never intersect anything
+    None,
+    Point(Vec3),
+    Segment(LineSegment3D),
+    /// the triangles are coplanar and overlap in this convex surface. It's located in the
+    /// [`Triangle3D::coordinate_system`] of the left hand side triangle
+    Coplanar(NeoSurface),
+}
+
+impl NeoIntersectionResult for TriangleTriangle3DIntersection {
+    type Point = Vec3;
+    type Segment = LineSegment3D;
+    type Surface = NeoSurface;
+
+    fn dimension(&self) -> Option<IntersectionDimension> {
+        match self {
+            TriangleTriangle3DIntersection::None => None,
+            TriangleTriangle3DIntersection::Point(_) => Some(IntersectionDimension::Point),
+            TriangleTriangle3DIntersection::Segment(_) => Some(IntersectionDimension::Line),
+            TriangleTriangle3DIntersection::Coplanar(_) => Some(IntersectionDimension::Surface),
+        }
+    }
+
+    fn points(&self) -> Vec<Vec3> {
+        match self {
+            TriangleTriangle3DIntersection::Point(p) => vec![*p],
+            _ => vec![],
+        }
+    }
+
+    fn segments(&self) -> Vec<LineSegment3D> {
+        match self {
+            TriangleTriangle3DIntersection::Segment(l) => vec![*l],
+            _ => vec![],
+        }
+    }
+
+    fn surfaces(&self) -> Vec<NeoSurface> {
+        match self {
+            TriangleTriangle3DIntersection::Coplanar(s) => vec![s.clone()],
+            _ => vec![],
+        }
+    }
+}
+
+impl NeoIntersectable for Triangle3D {
+    type Output = TriangleTriangle3DIntersection;
+
+    fn intersection(&self, rhs: &Self) -> Self::Output {
+        let (start, end) = match classify_crossing(self, rhs) {
+            TriangleCrossing::Separated => return TriangleTriangle3DIntersection::None,
+            TriangleCrossing::Coplanar => return coplanar_intersection(self, rhs),
+            TriangleCrossing::Interval { start, end } => (start, end),
+        };
+        let line_direction = self.normal().cross(rhs.normal()).normalize();
+        if start.dot(line_direction) > end.dot(line_direction) + NEO_LINE_SEGMENT_3D_EPS {
+            TriangleTriangle3DIntersection::None
+        } else if start.distance(end) < NEO_LINE_SEGMENT_3D_EPS {
+            TriangleTriangle3DIntersection::Point(start)
+        } else {
+            TriangleTriangle3DIntersection::Segment(LineSegment3D::new(start, end))
+        }
+    }
+}
+
+/// coplanar triangles are only clipped against each other, without building the surface
+impl NeoIntersects for Triangle3D {
+    fn intersects(&self, rhs: &Self) -> bool {
+        match classify_crossing(self, rhs) {
+            TriangleCrossing::Separated => false,
+            TriangleCrossing::Coplanar => !coplanar_overlap(self, rhs).is_empty(),
+            TriangleCrossing::Interval { start, end } => {
+                let line_direction = self.normal().cross(rhs.normal()).normalize();
+                start.dot(line_direction) <= end.dot(line_direction) + NEO_LINE_SEGMENT_3D_EPS
+            }
+        }
+    }
+}
+
+/// how two triangles cross the planes of each other
+enum TriangleCrossing {
+    /// one triangle is located on one side of the plane of the other one, or degenerated
+    Separated,
+    Coplanar,
+    /// the overlap of the parts of both triangles on the intersection line of the planes. It's
+    /// empty if `start` comes after `end` along the line
+    Interval {
+        start: Vec3,
+        end: Vec3,
+    },
+}
+
+fn classify_crossing(lhs: &Triangle3D, rhs: &Triangle3D) -> TriangleCrossing {
+    if lhs.is_degenerated() || rhs.is_degenerated() {
+        return TriangleCrossing::Separated;
+    }
+    // Möller: both triangles have to cross the plane of the other triangle
+    let rhs_distances = plane_distances(lhs, rhs);
+    if is_on_one_side(rhs_distances) {
+        return TriangleCrossing::Separated;
+    }
+    if rhs_distances.iter().all(|d| *d == 0.0) {
+        return TriangleCrossing::Coplanar;
+    }
+    let lhs_distances = plane_distances(rhs, lhs);
+    if is_on_one_side(lhs_distances) {
+        return TriangleCrossing::Separated;
+    }
+    // the parts of both triangles in the plane of the other one are located on the
+    // intersection line of the planes, so they intersect if their intervals overlap
+    let line_direction = lhs.normal().cross(rhs.normal()).normalize();
+    let [lhs_part, rhs_part] = [(lhs, lhs_distances), (rhs, rhs_distances)]
+        .map(|(tri, distances)| plane_crossing(tri, distances, line_direction));
+    let parameter = |p: Vec3| p.dot(line_direction);
+    let start = if parameter(lhs_part.src) > parameter(rhs_part.src) {
+        lhs_part.src
+    } else {
+        rhs_part.src
+    };
+    let end = if parameter(lhs_part.dst) < parameter(rhs_part.dst) {
+        lhs_part.dst
+    } else {
+        rhs_part.dst
+    };
+    TriangleCrossing::Interval { start, end }
+}
+
+/// signed distances of the corners of `tri` to the plane of `plane_tri`. Distances within the
+/// epsilon are snapped to zero
+fn plane_distances(plane_tri: &Triangle3D, tri: &Triangle3D) -> [f32; 3] {
+    let normal = plane_tri.normal();
+    tri.array().map(|p| {
+        let d = (p - plane_tri.a).dot(normal);
+        if d.abs() < NEO_LINE_SEGMENT_3D_EPS {
+            0.0
+        } else {
+            d
+        }
+    })
+}
+
+fn is_on_one_side(distances: [f32; 3]) -> bool {
+    distances.iter().all(|d| *d > 0.0) || distances.iter().all(|d| *d < 0.0)
+}
+
+/// part of the triangle located in the plane the distances are measured to, sorted along the
+/// direction of the line
+fn plane_crossing(tri: &Triangle3D, distances: [f32; 3], direction: Vec3) -> LineSegment3D {
+    let corners = tri.array();
+    let mut points = (0..3)
+        .filter(|i| distances[*i] == 0.0)
+        .map(|i| corners[i])
+        .chain((0..3).filter_map(|i| {
+            let j = (i + 1) % 3;
+            (distances[i] * distances[j] < 0.0).then(|| {
+                let ratio = distances[i] / (distances[i] - distances[j]);
+                corners[i] + (corners[j] - corners[i]) * ratio
+            })
+        }))
+        .collect::<Vec<_>>();
+    points.sort_by(|a, b| float_ord_cmp(&a.dot(direction), &b.dot(direction)));
+    // the triangle crosses the plane, so there is at least one point
+    LineSegment3D::new(points[0], points[points.len() - 1])
+}
+
+/// corners of the overlap of coplanar triangles, in the coordinate system of `lhs`
+fn coplanar_overlap(lhs: &Triangle3D, rhs: &Triangle3D) -> Vec<Vec2> {
+    let coord_sys = lhs.coordinate_system();
+    let [lhs_2d, rhs_2d] =
+        [lhs, rhs].map(|tri| ccw(tri.array().map(|p| coord_sys.project_point(p))));
+    clip_convex(rhs_2d.to_vec(), lhs_2d)
+}
+
+fn coplanar_intersection(lhs: &Triangle3D, rhs: &Triangle3D) -> TriangleTriangle3DIntersection {
+    let coord_sys = lhs.coordinate_system();
+    let mut points = coplanar_overlap(lhs, rhs);
+    points.dedup_by(|a, b| a.distance(*b) < NEO_LINE_SEGMENT_3D_EPS);
+    if points.len() > 1 && points[0].distance(points[points.len() - 1]) < NEO_LINE_SEGMENT_3D_EPS {
+        points.pop();
+    }
+
+    let Some(first) = points.first().copied() else {
+        return TriangleTriangle3DIntersection::None;
+    };
+    let farthest_from = |p: Vec2| {
+        points
+            .iter()
+            .copied()
+            .max_by(|a, b| float_ord_cmp(&a.distance(p), &b.distance(p)))
+            .unwrap_or(p)
+    };
+    let src = farthest_from(first);
+    let dst = farthest_from(src);
+    let extent = LineSegment2D::new(src, dst);
+    if src.distance(dst) < NEO_LINE_SEGMENT_3D_EPS {
+        TriangleTriangle3DIntersection::Point(coord_sys.inject_point(src))
+    } else if points
+        .iter()
+        .all(|p| extent.distance_to_point(*p) < NEO_LINE_SEGMENT_3D_EPS)
+    {
+        TriangleTriangle3DIntersection::Segment(LineSegment3D::new(
+            coord_sys.inject_point(src),
+            coord_sys.inject_point(dst),
+        ))
+    } else {
+        let exterior = points.iter().map(|p| p.to_geo()).collect::<Vec<_>>();
+        TriangleTriangle3DIntersection::Coplanar(NeoSurface::new(
+            coord_sys,
+            geo::Coord::zero(),
+            geo::Polygon::new(exterior.into(), vec![]),
+        ))
+    }
+}
+
+fn ccw([a, b, c]: [Vec2; 3]) -> [Vec2; 3] {
+    if (b - a).perp_dot(c - a) < 0.0 {
+        [a, c, b]
+    } else {
+        [a, b, c]
+    }
+}
+
+/// Sutherland-Hodgman clipping of the `subject` polygon with the counter clockwise `clip`
+/// triangle. Points on the boundary of `clip` count as inside, so touching polygons result in
+/// degenerated polygons with repeated points
+fn clip_convex(subject: Vec<Vec2>, clip: [Vec2; 3]) -> Vec<Vec2> {
+    (0..3).fold(subject, |input, i| {
+        let (c0, c1) = (clip[i], clip[(i + 1) % 3]);
+        let edge = (c1 - c0).normalize();
+        let side = |p: Vec2| edge.perp_dot(p - c0);
+        let is_inside = |p: Vec2| side(p) >= -NEO_LINE_SEGMENT_3D_EPS;
+        let crossing = |p: Vec2, q: Vec2| {
+            let ratio = (side(p) / (side(p) - side(q))).clamp(0.0, 1.0);
+            p + (q - p) * ratio
+        };
+        let n = input.len();
+        (0..n)
+            .flat_map(|j| {
+                let (prev, cur) = (input[(j + n - 1) % n], input[j]);
+                match (is_inside(prev), is_inside(cur)) {
+                    (true, true) => vec![cur],
+                    (false, true) => vec![crossing(prev, cur), cur],
+                    (true, false) => vec![crossing(prev, cur)],
+                    (false, false) => vec![],
+                }
+            })
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod triangle_triangle {
+    use geo::Area;
+    use glam::{Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d3::def::LineSegment3D;
+    use neo_plane::Plane;
+    use neo_surface::surface::def::NeoSurface;
+    use neo_surface::triangle3d::def::Triangle3D;
+
+    use crate::batch::batch_intersection_3d;
+    use crate::trait_def::NeoIntersectable;
+    use crate::triangle3d::triangle::TriangleTriangle3DIntersection;
+
+    const EPS: f32 = 0.000_1;
+
+    fn floor_triangle() -> Triangle3D {
+        Triangle3D::new(
+            Vec3::ZERO,
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        )
+    }
+
+    fn assert_segment(inter: TriangleTriangle3DIntersection, a: Vec3, b: Vec3) {
+        let TriangleTriangle3DIntersection::Segment(segment) = inter else {
+            panic!("expected a segment, got {inter:?}");
+        };
+        let (src, dst) = segment.tuple();
+        let matches = |x: Vec3, y: Vec3| src.distance(x) < EPS && dst.distance(y) < EPS;
+        assert!(matches(a, b) || matches(b, a), "{segment:?}");
+    }
+
+    #[test]
+    fn piercing_triangles() {
+        let wall = Triangle3D::new(
+            Vec3::new(1.0, -1.0, -1.0),
+            Vec3::new(1.0, 3.0, -1.0),
+            Vec3::new(1.0, 1.0, 1.0),
+        );
+        let expected = (Vec3::new(1.0, 0.0, 0.0), Vec3::new(1.0, 2.0, 0.0));
+        assert_segment(floor_triangle().intersection(&wall), expected.0, expected.1);
+        assert_segment(wall.intersection(&floor_triangle()), expected.0, expected.1);
+    }
+
+    #[test]
+    fn corner_touches_inside() {
+        let spike = Triangle3D::new(
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(1.0, 0.0, 2.0),
+            Vec3::new(2.0, 1.0, 2.0),
+        );
+        assert_eq!(
+            floor_triangle().intersection(&spike),
+            TriangleTriangle3DIntersection::Point(Vec3::new(1.0, 1.0, 0.0))
+        );
+    }
+
+    #[test]
+    fn edge_lies_in_the_plane() {
+        let fin = Triangle3D::new(
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(1.5, 1.0, 2.0),
+        );
+        assert_segment(
+            floor_triangle().intersection(&fin),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn separated_triangles() {
+        let above = floor_triangle();
+        let above = Triangle3D::new(above.a + Vec3::Z, above.b + Vec3::Z, above.c + Vec3::Z);
+        assert_eq!(
+            floor_triangle().intersection(&above),
+            TriangleTriangle3DIntersection::None
+        );
+        // the planes cross, but not within the triangles
+        let far_wall = Triangle3D::new(
+            Vec3::new(5.0, -1.0, -1.0),
+            Vec3::new(5.0, 3.0, -1.0),
+            Vec3::new(5.0, 1.0, 1.0),
+        );
+        assert_eq!(
+            floor_triangle().intersection(&far_wall),
+            TriangleTriangle3DIntersection::None
+        );
+        // the wall crosses the plane of the floor within the floor triangle but passes above it
+        let floating_wall = Triangle3D::new(
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(1.0, 2.0, 1.0),
+            Vec3::new(1.0, 8.0, -1.0),
+        );
+        assert_eq!(
+            floor_triangle().intersection(&floating_wall),
+            TriangleTriangle3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn coplanar_overlap() {
+        let shifted = Triangle3D::new(
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(5.0, 1.0, 0.0),
+            Vec3::new(1.0, 5.0, 0.0),
+        );
+        let TriangleTriangle3DIntersection::Coplanar(surface) =
+            floor_triangle().intersection(&shifted)
+        else {
+            panic!("expected a coplanar overlap");
+        };
+        assert!((surface.shape.unsigned_area() - 2.0).abs() < EPS);
+        // the surface lives in the coordinate system of the left hand side triangle
+        for p in surface.as_polygon_3d().exterior {
+            assert!(
+                p.z.abs() < EPS && p.x > 1.0 - EPS && p.y > 1.0 - EPS,
+                "{p:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn coplanar_containment_with_flipped_winding() {
+        let inner = Triangle3D::new(
+            Vec3::new(0.5, 0.5, 0.0),
+            Vec3::new(0.5, 1.5, 0.0),
+            Vec3::new(1.5, 0.5, 0.0),
+        );
+        let TriangleTriangle3DIntersection::Coplanar(surface) =
+            floor_triangle().intersection(&inner)
+        else {
+            panic!("expected a coplanar overlap");
+        };
+        assert!((surface.shape.unsigned_area() - inner.area()).abs() < EPS);
+    }
+
+    #[test]
+    fn coplanar_touching() {
+        let neighbor = Triangle3D::new(
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(4.0, 4.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        );
+        assert_segment(
+            floor_triangle().intersection(&neighbor),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        );
+        let corner = Triangle3D::new(
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(6.0, 0.0, 0.0),
+            Vec3::new(6.0, -2.0, 0.0),
+        );
+        let TriangleTriangle3DIntersection::Point(p) = floor_triangle().intersection(&corner)
+        else {
+            panic!("expected a point");
+        };
+        assert!(p.distance(Vec3::new(4.0, 0.0, 0.0)) < EPS);
+        let apart = Triangle3D::new(
+            Vec3::new(5.0, 0.0, 0.0),
+            Vec3::new(6.0, 0.0, 0.0),
+            Vec3::new(6.0, 2.0, 0.0),
+        );
+        assert_eq!(
+            floor_triangle().intersection(&apart),
+            TriangleTriangle3DIntersection::None
+        );
+    }
+
+    #[test]
+    fn tessellated_surfaces_clash() {
+        let square = |origin: Vec3, local_x: Vec3, local_y: Vec3| {
+            let c = CoordinateSystem::from_origin_and_plane(
+                origin,
+                Plane::from_local_axis(local_x, local_y),
+            );
+            let shape = geo::Rect::new(Vec2::splat(-1.0).to_geo(), Vec2::ONE.to_geo()).to_polygon();
+            NeoSurface::new(c, geo::Coord::zero(), shape)
+        };
+        let floor = square(Vec3::ZERO, Vec3::X, Vec3::Y).tessellate();
+        let wall = square(Vec3::new(0.25, 0.0, 0.0), Vec3::Y, Vec3::Z).tessellate();
+        let mut covered = batch_intersection_3d(&floor, &wall)
+            .into_iter()
+            .map(|clash| match clash.intersection {
+                TriangleTriangle3DIntersection::Segment(segment) => segment,
+                inter => panic!("expected a segment, got {inter:?}"),
+            })
+            .map(|segment: LineSegment3D| {
+                for p in segment.array() {
+                    assert!((p.x - 0.25).abs() < EPS && p.z.abs() < EPS, "{p:?}");
+                }
+                (
+                    segment.src.y.min(segment.dst.y),
+                    segment.src.y.max(segment.dst.y),
+                )
+            })
+            .collect::<Vec<_>>();
+        covered.sort_by(|a, b| a.0.total_cmp(&b.0));
+        // the clash covers the whole line where the wall crosses the floor
+        let end = covered.iter().fold(-1.0_f32, |end, (min, max)| {
+            assert!(*min <= end + EPS, "gap before {min}");
+            end.max(*max)
+        });
+        assert!((end - 1.0).abs() < EPS);
+    }
+}
@@ -0,0 +1,67 @@
+use glam::{Vec2, Vec3};
+use neo_aabb::d2::def::AABB2D;
+use neo_coordinate_system::CoordinateSystem;
+use neo_error::NeoGeometryError;
+use neo_ray::d2::def::Ray2D;
+use neo_surface::surface::def::NeoSurface;
+
+/// rejects planar input with `NAN` or infinite coordinates before any of it reaches the math
+pub(crate) fn ensure_finite_2d(
+    points: impl IntoIterator<Item = Vec2>,
+) -> Result<(), NeoGeometryError> {
+    if points.into_iter().all(|p| p.is_finite()) {
+        Ok(())
+    } else {
+        Err(NeoGeometryError::NonFinite)
+    }
+}
+
+/// spatial counterpart of [`ensure_finite_2d`]
+pub(crate) fn ensure_finite_3d(
+    points: impl IntoIterator<Item = Vec3>,
+) -> Result<(), NeoGeometryError> {
+    if points.into_iter().all(|p| p.is_finite()) {
+        Ok(())
+    } else {
+        Err(NeoGeometryError::NonFinite)
+    }
+}
+
+pub(crate) fn ensure_valid_ray_2d(ray: &Ray2D) -> Result<(), NeoGeometryError> {
+    ensure_finite_2d([ray.origin, ray.direction])?;
+    if ray.direction == Vec2::ZERO {
+        return Err(NeoGeometryError::Degenerate("ray without direction"));
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_valid_aabb_2d(aabb: &AABB2D) -> Result<(), NeoGeometryError> {
+    ensure_finite_2d([aabb.min, aabb.max])
+}
+
+pub(crate) fn ensure_valid_coordinate_system(
+    coord_sys: &CoordinateSystem,
+) -> Result<(), NeoGeometryError> {
+    let plane = coord_sys.plane;
+    ensure_finite_3d([coord_sys.origin, plane.normal, plane.local_x, plane.local_y])?;
+    if plane.normal.length_squared() < f32::EPSILON {
+        return Err(NeoGeometryError::Degenerate("plane without normal"));
+    }
+    Ok(())
+}
+
+pub(crate) fn ensure_valid_polygon_2d(polygon: &geo::Polygon<f32>) -> Result<(), NeoGeometryError> {
+    let points = polygon
+        .exterior()
+        .coords()
+        .chain(polygon.interiors().iter().flat_map(|ring| ring.coords()))
+        .map(|c| Vec2::new(c.x, c.y));
+    ensure_finite_2d(points)
+}
+
+pub(crate) fn ensure_valid_surface(surface: &NeoSurface) -> Result<(), NeoGeometryError> {
+    ensure_valid_coordinate_system(&surface.coordinate_system)?;
+    ensure_valid_polygon_2d(&surface.shape)?;
+    let origin = surface.shape_origin;
+    ensure_finite_2d([Vec2::new(origin.x, origin.y)])
+}
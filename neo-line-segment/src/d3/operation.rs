@@ -1,4 +1,4 @@
-use glam::Vec3;
+use glam::{Affine3A, Vec3};
 
 use crate::d3::def::LineSegment3D;
 
@@ -27,4 +27,37 @@ impl LineSegment3D {
     pub fn scale_src_by(&self, factor: f32) -> Self {
         Self::from((self.dst - factor * self.direction(), self.dst))
     }
+
+    /// scales along the world axes, keeping `pivot` in place. Negative factors mirror the line
+    pub fn scale_around(&self, pivot: Vec3, factor: Vec3) -> Self {
+        Self::from(self.array().map(|v| pivot + factor * (v - pivot)))
+    }
+
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        Self::from(self.array().map(|v| transform.transform_point3(v)))
+    }
+}
+
+#[test]
+fn scale_around_works() {
+    let l = LineSegment3D::new(Vec3::ONE, Vec3::new(2.0, 3.0, 1.0));
+    assert_eq!(
+        l.scale_around(Vec3::ONE, Vec3::new(2.0, -1.0, 1.0)),
+        LineSegment3D::new(Vec3::ONE, Vec3::new(3.0, -1.0, 1.0)),
+    );
+}
+
+#[test]
+fn transform_works() {
+    let l = LineSegment3D::UNIT_X;
+    let t = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(2.0),
+        glam::Quat::from_rotation_z(90.0_f32.to_radians()),
+        Vec3::Z,
+    );
+    let transformed = l.transform(&t);
+    assert!(transformed.src.abs_diff_eq(Vec3::Z, 0.000_1));
+    assert!(transformed
+        .dst
+        .abs_diff_eq(Vec3::Z + Vec3::Y * 2.0, 0.000_1));
 }
@@ -1,4 +1,4 @@
-use glam::{Quat, Vec3};
+use glam::{Affine3A, Mat3, Quat, Vec3};
 
 const PLANE_EPS: f32 = 0.000_1;
 
@@ -54,11 +54,14 @@ impl Plane {
         }
     }
 
+    /// rotation which maps `X` onto `local_x` and `Z` onto the normal
     pub fn injection_rotation(&self) -> Quat {
-        let normal_align = Quat::from_rotation_arc(Vec3::Z, self.normal);
-        let rotated_x = normal_align * Vec3::X;
-        let local_x_align = Quat::from_rotation_arc(rotated_x, self.local_x);
-        local_x_align * normal_align
+        let local_x = self.local_x.reject_from_normalized(self.normal).normalize();
+        Quat::from_mat3(&Mat3::from_cols(
+            local_x,
+            self.normal.cross(local_x),
+            self.normal,
+        ))
     }
 
     pub fn xy_projection_rotation(&self) -> Quat {
@@ -80,6 +83,42 @@ impl Plane {
     }
 }
 
+impl Plane {
+    /// applies the linear part of the transform. The translation is ignored, since planes only
+    /// describe directions
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        Self::from_axis_and_normal(
+            transform.transform_vector3(self.local_x),
+            transform_normal(transform, self.normal),
+        )
+    }
+
+    pub fn rotate(&self, quat: Quat) -> Self {
+        self.transform(&Affine3A::from_quat(quat))
+    }
+
+    /// scales the plane along the world axes. Negative factors mirror it
+    pub fn scale(&self, factor: Vec3) -> Self {
+        self.transform(&Affine3A::from_scale(factor))
+    }
+}
+
+/// maps a normal with the inverse transpose of the linear part of the transform, which keeps it
+/// orthogonal to the transformed surface under non uniform scaling
+pub fn transform_normal(transform: &Affine3A, normal: Vec3) -> Vec3 {
+    (transform.matrix3.inverse().transpose() * normal).normalize()
+}
+
+/// the transform applied with `pivot` as the fix point
+pub fn transform_around(pivot: Vec3, transform: Affine3A) -> Affine3A {
+    Affine3A::from_translation(pivot) * transform * Affine3A::from_translation(-pivot)
+}
+
+/// whether the transform turns right handed geometry into left handed geometry
+pub fn is_mirroring(transform: &Affine3A) -> bool {
+    transform.matrix3.determinant() < 0.0
+}
+
 #[test]
 fn partial_eq_works() {
     use glam::Quat;
@@ -186,6 +225,20 @@ fn injection_tilted_plane() {
     );
 }
 
+#[test]
+fn injection_rotation_of_flipped_plane_works() {
+    for p in [
+        Plane::from_local_axis(Vec3::X, Vec3::Y),
+        Plane::from_local_axis(Vec3::Y, Vec3::X),
+        Plane::from_normal(Vec3::ONE),
+    ] {
+        let flipped = p.flip();
+        let rot = flipped.injection_rotation();
+        assert!(flipped.normal.abs_diff_eq(rot * Vec3::Z, PLANE_EPS));
+        assert!(flipped.local_x.abs_diff_eq(rot * Vec3::X, PLANE_EPS));
+    }
+}
+
 #[test]
 fn projection_rotation_local_x_works() {
     let p = Plane::from_normal(Vec3::ONE);
@@ -219,3 +272,37 @@ fn projection_tilted_plane() {
     assert!(Vec3::Y.abs_diff_eq(rot * p.local_y, PLANE_EPS));
     assert!(Vec3::Z.abs_diff_eq(rot * p.normal, PLANE_EPS));
 }
+
+#[test]
+fn transform_keeps_normal_orthogonal() {
+    let p = Plane::from_local_axis(Vec3::X + Vec3::Y, Vec3::Z);
+    let t = Affine3A::from_scale(Vec3::new(3.0, 1.0, 2.0));
+    let transformed = p.transform(&t);
+    // vectors in the original plane stay in the transformed plane
+    for v in [
+        Vec3::X + Vec3::Y,
+        Vec3::Z,
+        Vec3::X + Vec3::Y - Vec3::Z * 4.0,
+    ] {
+        assert!(transformed.is_point_in_plane(t.transform_vector3(v)));
+    }
+    assert!(transformed.local_x.dot(transformed.normal).abs() < PLANE_EPS);
+    assert!(transformed.local_y.dot(transformed.normal).abs() < PLANE_EPS);
+    assert!((transformed.normal.length() - 1.0).abs() < PLANE_EPS);
+}
+
+#[test]
+fn rotate_and_scale_work() {
+    let p = Plane::from_normal(Vec3::Z);
+    let rotated = p.rotate(Quat::from_rotation_x(90.0_f32.to_radians()));
+    assert!(rotated.normal.abs_diff_eq(-Vec3::Y, PLANE_EPS));
+
+    let mirrored = p.scale(Vec3::new(1.0, 1.0, -1.0));
+    assert!(mirrored.normal.abs_diff_eq(-Vec3::Z, PLANE_EPS));
+    assert!(is_mirroring(&Affine3A::from_scale(Vec3::new(
+        1.0, 1.0, -1.0
+    ))));
+    assert!(!is_mirroring(&Affine3A::from_scale(Vec3::new(
+        -1.0, 1.0, -1.0
+    ))));
+}
@@ -0,0 +1,31 @@
+use glam::Vec2;
+use neo_line_segment::d2::def::LineSegment2D;
+
+/// All points within `radius` of the `axis` segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule2D {
+    pub axis: LineSegment2D,
+    pub radius: f32,
+}
+
+impl Capsule2D {
+    pub const fn new(axis: LineSegment2D, radius: f32) -> Self {
+        Self { axis, radius }
+    }
+
+    pub fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius + 2.0 * self.radius * self.axis.length()
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        self.axis.distance_to_point(point) <= self.radius
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let capsule = Capsule2D::new(LineSegment2D::new(Vec2::ZERO, Vec2::X * 2.0), 0.5);
+    assert!(capsule.contains_point(Vec2::new(1.0, 0.5)));
+    assert!(capsule.contains_point(Vec2::new(-0.5, 0.0)));
+    assert!(!capsule.contains_point(Vec2::new(-0.5, 0.5)));
+}
@@ -0,0 +1,29 @@
+use glam::Vec2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Circle2D {
+    pub center: Vec2,
+    pub radius: f32,
+}
+
+impl Circle2D {
+    pub const fn new(center: Vec2, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn area(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius
+    }
+
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        point.distance_squared(self.center) <= self.radius * self.radius
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let circle = Circle2D::new(Vec2::ONE, 1.0);
+    assert!(circle.contains_point(Vec2::ONE));
+    assert!(circle.contains_point(Vec2::X));
+    assert!(!circle.contains_point(Vec2::ZERO));
+}
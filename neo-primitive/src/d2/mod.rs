@@ -0,0 +1,2 @@
+pub mod capsule;
+pub mod circle;
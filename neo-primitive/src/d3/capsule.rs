@@ -0,0 +1,32 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+
+/// All points within `radius` of the `axis` segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Capsule3D {
+    pub axis: LineSegment3D,
+    pub radius: f32,
+}
+
+impl Capsule3D {
+    pub const fn new(axis: LineSegment3D, radius: f32) -> Self {
+        Self { axis, radius }
+    }
+
+    pub fn volume(&self) -> f32 {
+        let r2 = self.radius * self.radius;
+        std::f32::consts::PI * r2 * (4.0 / 3.0 * self.radius + self.axis.length())
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        self.axis.distance_to_point(point) <= self.radius
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let capsule = Capsule3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::Z * 2.0), 0.5);
+    assert!(capsule.contains_point(Vec3::new(0.5, 0.0, 1.0)));
+    assert!(capsule.contains_point(Vec3::new(0.0, 0.0, 2.5)));
+    assert!(!capsule.contains_point(Vec3::new(0.5, 0.0, 2.5)));
+}
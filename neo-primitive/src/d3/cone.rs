@@ -0,0 +1,56 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::d3::cylinder::disk_extent;
+
+/// Finite cone with a flat base cap
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cone3D {
+    pub apex: Vec3,
+    pub base_center: Vec3,
+    pub radius: f32,
+}
+
+impl Cone3D {
+    pub const fn new(apex: Vec3, base_center: Vec3, radius: f32) -> Self {
+        Self {
+            apex,
+            base_center,
+            radius,
+        }
+    }
+
+    /// Axis from the apex to the center of the base
+    pub fn axis(&self) -> LineSegment3D {
+        LineSegment3D::new(self.apex, self.base_center)
+    }
+
+    pub fn height(&self) -> f32 {
+        self.apex.distance(self.base_center)
+    }
+
+    pub fn volume(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius * self.height() / 3.0
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let axis = self.axis();
+        let h = (point - self.apex).dot(axis.direction_normalized());
+        let height = self.height();
+        (0.0..=height).contains(&h) && axis.distance_to_point(point) <= self.radius * h / height
+    }
+
+    /// Extent of the base disk along each world axis
+    pub fn base_extent(&self) -> Vec3 {
+        disk_extent(self.axis().direction_normalized(), self.radius)
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let cone = Cone3D::new(Vec3::Z * 2.0, Vec3::ZERO, 1.0);
+    assert!(cone.contains_point(Vec3::new(0.5, 0.0, 1.0)));
+    assert!(cone.contains_point(Vec3::new(1.0, 0.0, 0.0)));
+    assert!(!cone.contains_point(Vec3::new(0.6, 0.0, 1.0)));
+    assert!(!cone.contains_point(Vec3::new(0.0, 0.0, 2.5)));
+}
@@ -0,0 +1,54 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+
+/// Finite cylinder with flat caps at both ends of the `axis` segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cylinder3D {
+    pub axis: LineSegment3D,
+    pub radius: f32,
+}
+
+impl Cylinder3D {
+    pub const fn new(axis: LineSegment3D, radius: f32) -> Self {
+        Self { axis, radius }
+    }
+
+    pub fn height(&self) -> f32 {
+        self.axis.length()
+    }
+
+    pub fn volume(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius * self.height()
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let h = (point - self.axis.src).dot(self.axis.direction_normalized());
+        (0.0..=self.height()).contains(&h) && self.axis.distance_to_point(point) <= self.radius
+    }
+
+    /// Extent of the cap disks along each world axis
+    pub fn cap_extent(&self) -> Vec3 {
+        disk_extent(self.axis.direction_normalized(), self.radius)
+    }
+}
+
+/// Half extents of the bounding box of a disk with the given normal and radius
+pub(crate) fn disk_extent(normal: Vec3, radius: f32) -> Vec3 {
+    (Vec3::ONE - normal * normal).max(Vec3::ZERO).powf(0.5) * radius
+}
+
+#[test]
+fn contains_point_works() {
+    let cylinder = Cylinder3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::Z * 2.0), 0.5);
+    assert!(cylinder.contains_point(Vec3::new(0.5, 0.0, 1.0)));
+    assert!(cylinder.contains_point(Vec3::new(0.0, 0.5, 2.0)));
+    assert!(!cylinder.contains_point(Vec3::new(0.0, 0.0, 2.5)));
+}
+
+#[test]
+fn cap_extent_works() {
+    let cylinder = Cylinder3D::new(LineSegment3D::new(Vec3::ZERO, Vec3::Z * 2.0), 0.5);
+    assert!(cylinder
+        .cap_extent()
+        .abs_diff_eq(Vec3::new(0.5, 0.5, 0.0), 0.000_1));
+}
@@ -0,0 +1,5 @@
+pub mod capsule;
+pub mod cone;
+pub mod cylinder;
+pub mod obb;
+pub mod sphere;
@@ -0,0 +1,63 @@
+use glam::{Quat, Vec3};
+
+/// Oriented box, i.e. a box with the given half extents which is rotated by `rotation` around its
+/// `center`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OBB3D {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+    pub rotation: Quat,
+}
+
+impl OBB3D {
+    pub const fn new(center: Vec3, half_extents: Vec3, rotation: Quat) -> Self {
+        Self {
+            center,
+            half_extents,
+            rotation,
+        }
+    }
+
+    pub fn volume(&self) -> f32 {
+        8.0 * self.half_extents.x * self.half_extents.y * self.half_extents.z
+    }
+
+    /// Corners of the box. Bit `k` of the index selects the positive side along the `k`-th local
+    /// axis
+    pub fn corners(&self) -> [Vec3; 8] {
+        std::array::from_fn(|i| {
+            let sign = |bit: usize| if i & (1 << bit) == 0 { -1.0 } else { 1.0 };
+            let local = Vec3::new(sign(0), sign(1), sign(2)) * self.half_extents;
+            self.center + self.rotation * local
+        })
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let local = self.rotation.inverse() * (point - self.center);
+        local.abs().cmple(self.half_extents).all()
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let obb = OBB3D::new(
+        Vec3::ONE,
+        Vec3::new(2.0, 0.5, 0.5),
+        Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+    );
+    assert!(obb.contains_point(Vec3::new(1.0, 2.5, 1.0)));
+    assert!(!obb.contains_point(Vec3::new(2.5, 1.0, 1.0)));
+}
+
+#[test]
+fn corners_work() {
+    let obb = OBB3D::new(
+        Vec3::ZERO,
+        Vec3::new(1.0, 2.0, 3.0),
+        Quat::from_rotation_z(std::f32::consts::FRAC_PI_2),
+    );
+    let corners = obb.corners();
+    assert!(corners[0].abs_diff_eq(Vec3::new(2.0, -1.0, -3.0), 0.000_1));
+    assert!(corners[7].abs_diff_eq(Vec3::new(-2.0, 1.0, 3.0), 0.000_1));
+    assert!((obb.volume() - 48.0).abs() < 0.000_1);
+}
@@ -0,0 +1,29 @@
+use glam::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sphere3D {
+    pub center: Vec3,
+    pub radius: f32,
+}
+
+impl Sphere3D {
+    pub const fn new(center: Vec3, radius: f32) -> Self {
+        Self { center, radius }
+    }
+
+    pub fn volume(&self) -> f32 {
+        4.0 / 3.0 * std::f32::consts::PI * self.radius.powi(3)
+    }
+
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        point.distance_squared(self.center) <= self.radius * self.radius
+    }
+}
+
+#[test]
+fn contains_point_works() {
+    let sphere = Sphere3D::new(Vec3::ONE, 1.0);
+    assert!(sphere.contains_point(Vec3::ONE));
+    assert!(sphere.contains_point(Vec3::ONE - Vec3::Z));
+    assert!(!sphere.contains_point(Vec3::ZERO));
+}
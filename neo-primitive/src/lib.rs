@@ -0,0 +1,2 @@
+pub mod d2;
+pub mod d3;
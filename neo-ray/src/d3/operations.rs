@@ -1,4 +1,4 @@
-use glam::Vec3;
+use glam::{Affine3A, Vec3};
 
 use crate::d3::def::Ray3D;
 
@@ -10,4 +10,45 @@ impl Ray3D {
     pub fn offset_direction_by(&self, offset: Vec3) -> Self {
         Self::from((self.origin, self.direction + offset))
     }
+
+    /// scales along the world axes, keeping `pivot` in place. Negative factors mirror the ray
+    pub fn scale_around(&self, pivot: Vec3, factor: Vec3) -> Self {
+        Self::from((
+            pivot + factor * (self.origin - pivot),
+            factor * self.direction,
+        ))
+    }
+
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        Self::from((
+            transform.transform_point3(self.origin),
+            transform.transform_vector3(self.direction),
+        ))
+    }
+}
+
+#[test]
+fn transform_works() {
+    let ray = Ray3D::new(Vec3::ONE, Vec3::X);
+    let t = Affine3A::from_scale_rotation_translation(
+        Vec3::new(1.0, 3.0, 1.0),
+        glam::Quat::from_rotation_z(90.0_f32.to_radians()),
+        Vec3::Z,
+    );
+    let transformed = ray.transform(&t);
+    // the translation doesn't move the direction
+    assert!(transformed
+        .origin
+        .abs_diff_eq(Vec3::new(-3.0, 1.0, 2.0), 0.000_1));
+    assert!(transformed.direction.abs_diff_eq(Vec3::Y, 0.000_1));
+}
+
+#[test]
+fn scale_around_works() {
+    let ray = Ray3D::new(Vec3::ONE, Vec3::X + Vec3::Y);
+    let scaled = ray.scale_around(Vec3::ZERO, Vec3::new(-1.0, 2.0, 1.0));
+    assert_eq!(
+        scaled,
+        Ray3D::new(Vec3::new(-1.0, 2.0, 1.0), Vec3::new(-1.0, 2.0, 0.0))
+    );
 }
@@ -1,4 +1,4 @@
-use glam::Quat;
+use glam::{Quat, Vec3};
 
 use crate::d3::def::Ray3D;
 
@@ -31,6 +31,10 @@ impl Ray3D {
             self.origin + quat * (self.direction - self.origin),
         ))
     }
+
+    pub fn rotate_around(&self, pivot: Vec3, quat: Quat) -> Self {
+        Self::from((pivot + quat * (self.origin - pivot), quat * self.direction))
+    }
 }
 
 #[test]
@@ -53,3 +57,16 @@ fn rotation_works() {
         "rotated: {rotated:?}"
     );
 }
+
+#[test]
+fn rotation_around_works() {
+    use crate::d3::constants::NEO_LINE_RAY_3D_EPS;
+
+    let ray = Ray3D::new(Vec3::ONE + Vec3::X, Vec3::X);
+    let quat = Quat::from_rotation_z(90.0_f32.to_radians());
+    let rotated = ray.rotate_around(Vec3::ONE, quat);
+    assert!(rotated
+        .origin
+        .abs_diff_eq(Vec3::ONE + Vec3::Y, NEO_LINE_RAY_3D_EPS));
+    assert!(rotated.direction.abs_diff_eq(Vec3::Y, NEO_LINE_RAY_3D_EPS));
+}
@@ -0,0 +1,57 @@
+pub const NEO_STROKE_EPS: f32 = 0.000_1;
+
+/// Number of segments used to approximate a full circle for round joins and caps
+pub const NEO_STROKE_DEFAULT_ARC_SEGMENTS: usize = 32;
+
+/// How two consecutive segments of a stroked polyline are connected on the outer side of the
+/// turn
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin {
+    /// Extends the outer edges until they meet. If the ratio of the miter length to the stroke
+    /// width exceeds `limit`, the join falls back to [`LineJoin::Bevel`]
+    Miter {
+        limit: f32,
+    },
+    Round,
+    Bevel,
+}
+
+/// How the open ends of a stroked polyline are finished
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineCap {
+    /// The stroke ends exactly at the endpoint
+    Butt,
+    /// The stroke is extended by half of the width beyond the endpoint
+    Square,
+    Round,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    pub arc_segments: usize,
+}
+
+impl StrokeStyle {
+    pub const fn new(width: f32, join: LineJoin, cap: LineCap) -> Self {
+        Self {
+            width,
+            join,
+            cap,
+            arc_segments: NEO_STROKE_DEFAULT_ARC_SEGMENTS,
+        }
+    }
+
+    pub const fn with_arc_segments(self, arc_segments: usize) -> Self {
+        Self {
+            arc_segments,
+            ..self
+        }
+    }
+
+    pub fn half_width(&self) -> f32 {
+        self.width * 0.5
+    }
+}
@@ -0,0 +1,5 @@
+pub mod def;
+pub(crate) mod line2d;
+pub(crate) mod linestring;
+pub(crate) mod pieces;
+pub mod traits;
@@ -0,0 +1,73 @@
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::def::StrokeStyle;
+use crate::pieces::stroke_points;
+use crate::traits::NeoStroke;
+
+impl NeoStroke for LineSegment2D {
+    fn stroke(&self, style: &StrokeStyle) -> Option<geo::Polygon<f32>> {
+        stroke_points(&self.array(), false, style)
+    }
+}
+
+#[cfg(test)]
+mod line_stroke {
+    use geo::{Area, BoundingRect};
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::def::{LineCap, LineJoin, StrokeStyle};
+    use crate::traits::NeoStroke;
+
+    const EPS: f32 = 0.000_1;
+
+    #[test]
+    fn butt_cap_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Bevel, LineCap::Butt);
+        let stroke = LineSegment2D::UNIT_X.stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 1.0).abs() < EPS);
+        let rect = stroke.bounding_rect().unwrap();
+        assert_eq!(rect.min(), geo::Coord { x: 0.0, y: -0.5 });
+        assert_eq!(rect.max(), geo::Coord { x: 1.0, y: 0.5 });
+    }
+
+    #[test]
+    fn square_cap_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Bevel, LineCap::Square);
+        let stroke = LineSegment2D::UNIT_X.stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 2.0).abs() < EPS);
+        let rect = stroke.bounding_rect().unwrap();
+        assert_eq!(rect.min(), geo::Coord { x: -0.5, y: -0.5 });
+        assert_eq!(rect.max(), geo::Coord { x: 1.5, y: 0.5 });
+    }
+
+    #[test]
+    fn round_cap_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Bevel, LineCap::Round);
+        let stroke = LineSegment2D::UNIT_X.stroke(&style).unwrap();
+        let expected = 1.0 + std::f32::consts::PI * 0.25;
+        assert!((stroke.unsigned_area() - expected).abs() < 0.01);
+        assert!(stroke.interiors().is_empty());
+    }
+
+    #[test]
+    fn tilted_line_works() {
+        let style = StrokeStyle::new(0.5, LineJoin::Bevel, LineCap::Butt);
+        let line = LineSegment2D::new(Vec2::ONE, Vec2::new(4.0, 5.0));
+        let stroke = line.stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 2.5).abs() < EPS);
+    }
+
+    #[test]
+    fn degenerated_line_has_no_stroke() {
+        let style = StrokeStyle::new(1.0, LineJoin::Bevel, LineCap::Round);
+        let line = LineSegment2D::new(Vec2::ONE, Vec2::ONE);
+        assert_eq!(line.stroke(&style), None);
+    }
+
+    #[test]
+    fn zero_width_has_no_stroke() {
+        let style = StrokeStyle::new(0.0, LineJoin::Bevel, LineCap::Butt);
+        assert_eq!(LineSegment2D::UNIT_X.stroke(&style), None);
+    }
+}
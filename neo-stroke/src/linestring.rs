@@ -0,0 +1,92 @@
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+
+use crate::def::StrokeStyle;
+use crate::pieces::stroke_points;
+use crate::traits::NeoStroke;
+
+impl NeoStroke for geo::LineString<f32> {
+    fn stroke(&self, style: &StrokeStyle) -> Option<geo::Polygon<f32>> {
+        stroke_points(&self.to_glam(), self.is_closed(), style)
+    }
+}
+
+#[cfg(test)]
+mod linestring_stroke {
+    use geo::Area;
+
+    use crate::def::{LineCap, LineJoin, StrokeStyle};
+    use crate::traits::NeoStroke;
+
+    const EPS: f32 = 0.000_1;
+
+    fn l_shape() -> geo::LineString<f32> {
+        geo::LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0)])
+    }
+
+    #[test]
+    fn miter_join_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Miter { limit: 4.0 }, LineCap::Butt);
+        let stroke = l_shape().stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn bevel_join_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Bevel, LineCap::Butt);
+        let stroke = l_shape().stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 3.875).abs() < EPS);
+    }
+
+    #[test]
+    fn miter_limit_falls_back_to_bevel() {
+        let style = StrokeStyle::new(1.0, LineJoin::Miter { limit: 1.0 }, LineCap::Butt);
+        let stroke = l_shape().stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 3.875).abs() < EPS);
+    }
+
+    #[test]
+    fn round_join_works() {
+        let style = StrokeStyle::new(1.0, LineJoin::Round, LineCap::Butt);
+        let stroke = l_shape().stroke(&style).unwrap();
+        let expected = 3.75 + std::f32::consts::PI * 0.25 * 0.25;
+        assert!((stroke.unsigned_area() - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn right_turn_works_like_left_turn() {
+        let style = StrokeStyle::new(1.0, LineJoin::Miter { limit: 4.0 }, LineCap::Butt);
+        let right_turn = geo::LineString::from(vec![(0.0, 0.0), (2.0, 0.0), (2.0, -2.0)]);
+        let stroke = right_turn.stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn collinear_points_are_ignored() {
+        let style = StrokeStyle::new(1.0, LineJoin::Miter { limit: 4.0 }, LineCap::Butt);
+        let line = geo::LineString::from(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+        let stroke = line.stroke(&style).unwrap();
+        assert!((stroke.unsigned_area() - 2.0).abs() < EPS);
+    }
+
+    #[test]
+    fn closed_linestring_has_hole() {
+        let style = StrokeStyle::new(1.0, LineJoin::Miter { limit: 4.0 }, LineCap::Round);
+        let square = geo::LineString::from(vec![
+            (0.0, 0.0),
+            (4.0, 0.0),
+            (4.0, 4.0),
+            (0.0, 4.0),
+            (0.0, 0.0),
+        ]);
+        let stroke = square.stroke(&style).unwrap();
+        assert_eq!(stroke.interiors().len(), 1);
+        assert!((stroke.unsigned_area() - (25.0 - 9.0)).abs() < EPS);
+    }
+
+    #[test]
+    fn single_point_has_no_stroke() {
+        let style = StrokeStyle::new(1.0, LineJoin::Round, LineCap::Round);
+        let line = geo::LineString::from(vec![(1.0, 1.0)]);
+        assert_eq!(line.stroke(&style), None);
+    }
+}
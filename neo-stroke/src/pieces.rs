@@ -0,0 +1,171 @@
+use std::f32::consts::TAU;
+
+use geo::Area;
+use glam::Vec2;
+use neo_geo_boolops::NeoGeoBoolops;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_line_segment::d2::def::LineSegment2D;
+
+use crate::def::{LineCap, LineJoin, StrokeStyle, NEO_STROKE_EPS};
+
+/// strokes the polyline described by `points` by creating a piece for every segment, join and cap
+/// and merging them afterwards
+pub(crate) fn stroke_points(
+    points: &[Vec2],
+    closed: bool,
+    style: &StrokeStyle,
+) -> Option<geo::Polygon<f32>> {
+    let half_width = style.half_width();
+    if half_width <= NEO_STROKE_EPS {
+        return None;
+    }
+
+    let mut points = points.to_vec();
+    points.dedup_by(|a, b| a.abs_diff_eq(*b, NEO_STROKE_EPS));
+    if closed && points.len() > 1 && points[0].abs_diff_eq(points[points.len() - 1], NEO_STROKE_EPS)
+    {
+        points.pop();
+    }
+    let closed = closed && points.len() > 2;
+
+    let mut segments = points
+        .windows(2)
+        .map(|w| LineSegment2D::new(w[0], w[1]))
+        .collect::<Vec<_>>();
+    if closed {
+        segments.push(LineSegment2D::new(points[points.len() - 1], points[0]));
+    }
+    if segments.is_empty() {
+        return None;
+    }
+
+    let segment_pieces = segments.iter().map(|l| segment_piece(l, half_width));
+
+    let joined_segments = if closed {
+        segments
+            .iter()
+            .zip(segments.iter().cycle().skip(1))
+            .collect::<Vec<_>>()
+    } else {
+        segments.iter().zip(segments.iter().skip(1)).collect()
+    };
+    let join_pieces = joined_segments
+        .into_iter()
+        .filter_map(|(prev, next)| join_piece(prev, next, half_width, style));
+
+    let cap_pieces = (!closed)
+        .then(|| {
+            let first = segments[0];
+            let last = segments[segments.len() - 1];
+            [first.flip(), last]
+                .into_iter()
+                .filter_map(|l| cap_piece(&l, half_width, style))
+        })
+        .into_iter()
+        .flatten();
+
+    let pieces = segment_pieces
+        .chain(join_pieces)
+        .chain(cap_pieces)
+        .collect::<Vec<_>>();
+
+    union_pieces(pieces)
+}
+
+/// rectangle around the line segment with the given half width
+fn segment_piece(line: &LineSegment2D, half_width: f32) -> geo::Polygon<f32> {
+    let offset = line.normal() * half_width;
+    polygon_from_points(vec![
+        line.src - offset,
+        line.dst - offset,
+        line.dst + offset,
+        line.src + offset,
+    ])
+}
+
+/// fills the gap on the outer side of the turn between two consecutive segments. `prev.dst` is
+/// expected to be equal to `next.src`
+fn join_piece(
+    prev: &LineSegment2D,
+    next: &LineSegment2D,
+    half_width: f32,
+    style: &StrokeStyle,
+) -> Option<geo::Polygon<f32>> {
+    let point = prev.dst;
+    let turn = prev
+        .direction_normalized()
+        .perp_dot(next.direction_normalized());
+    if turn.abs() < NEO_STROKE_EPS {
+        // straight continuation or full reversal, only round joins add something here
+        return matches!(style.join, LineJoin::Round)
+            .then(|| circle_piece(point, half_width, style.arc_segments));
+    }
+
+    // the outer side of a left turn is on the right and vice versa
+    let side = -turn.signum();
+    let outer_prev = point + prev.normal() * half_width * side;
+    let outer_next = point + next.normal() * half_width * side;
+    let bevel = || polygon_from_points(vec![point, outer_prev, outer_next]);
+
+    let join = match style.join {
+        LineJoin::Miter { limit } => {
+            let bisector = (prev.normal() + next.normal()).normalize_or_zero();
+            let cos_half_angle = bisector.dot(prev.normal());
+            let miter_ratio = 1.0 / cos_half_angle;
+            if cos_half_angle > NEO_STROKE_EPS && miter_ratio <= limit {
+                let miter_point = point + bisector * side * half_width * miter_ratio;
+                polygon_from_points(vec![point, outer_prev, miter_point, outer_next])
+            } else {
+                bevel()
+            }
+        }
+        LineJoin::Round => circle_piece(point, half_width, style.arc_segments),
+        LineJoin::Bevel => bevel(),
+    };
+    Some(join)
+}
+
+/// cap at the `dst` end of the line segment
+fn cap_piece(
+    line: &LineSegment2D,
+    half_width: f32,
+    style: &StrokeStyle,
+) -> Option<geo::Polygon<f32>> {
+    match style.cap {
+        LineCap::Butt => None,
+        LineCap::Square => {
+            let extension = line.direction_normalized() * half_width;
+            let cap_line = LineSegment2D::new(line.dst, line.dst + extension);
+            Some(segment_piece(&cap_line, half_width))
+        }
+        LineCap::Round => Some(circle_piece(line.dst, half_width, style.arc_segments)),
+    }
+}
+
+fn circle_piece(center: Vec2, radius: f32, arc_segments: usize) -> geo::Polygon<f32> {
+    let arc_segments = arc_segments.max(3);
+    let points = (0..arc_segments)
+        .map(|i| i as f32 / arc_segments as f32 * TAU)
+        .map(|angle| center + Vec2::from_angle(angle) * radius)
+        .collect::<Vec<_>>();
+    polygon_from_points(points)
+}
+
+fn polygon_from_points(points: Vec<Vec2>) -> geo::Polygon<f32> {
+    geo::Polygon::new(points.to_geo(), vec![])
+}
+
+fn union_pieces(pieces: Vec<geo::Polygon<f32>>) -> Option<geo::Polygon<f32>> {
+    let union = pieces
+        .into_iter()
+        .map(|piece| geo::MultiPolygon::new(vec![piece]))
+        .try_fold(geo::MultiPolygon::new(vec![]), |acc, piece| {
+            acc.neo_union(&piece)
+        })?;
+
+    // all pieces are connected, so the union should only consist of one polygon. Numerical
+    // artifacts might produce tiny slivers though, which we drop here
+    union
+        .into_iter()
+        .max_by(|a, b| a.unsigned_area().total_cmp(&b.unsigned_area()))
+}
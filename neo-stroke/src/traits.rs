@@ -0,0 +1,10 @@
+use crate::def::StrokeStyle;
+
+pub trait NeoStroke {
+    /// Turns the centreline into its outline with the given style. Overlapping parts of the
+    /// stroke are merged, so the result is a single polygon which may contain holes (e.g. for
+    /// closed linestrings).
+    ///
+    /// Returns `None` for degenerated input or if the union of the stroke pieces failed
+    fn stroke(&self, style: &StrokeStyle) -> Option<geo::Polygon<f32>>;
+}
@@ -1,3 +1,6 @@
+pub mod lifting;
+pub mod opening;
 pub mod polygon3d;
+pub mod polyline3d;
 pub mod surface;
-
+pub mod triangle3d;
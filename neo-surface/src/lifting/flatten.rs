@@ -0,0 +1,129 @@
+use glam::Vec3;
+use neo_coordinate_system::CoordinateSystem;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::lifting::traits::Flattenable;
+use crate::polygon3d::def::NeoPolygon3D;
+use crate::polyline3d::def::Polyline3D;
+use crate::triangle3d::def::Triangle3D;
+
+impl Flattenable for Vec3 {
+    type Flattened = geo::Point<f32>;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened {
+        geo::Point::from(coordinate_system.project_point(*self).to_geo())
+    }
+}
+
+impl Flattenable for LineSegment3D {
+    type Flattened = geo::Line<f32>;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened {
+        geo::Line::new(
+            self.src.flatten(coordinate_system),
+            self.dst.flatten(coordinate_system),
+        )
+    }
+}
+
+impl Flattenable for Polyline3D {
+    type Flattened = geo::LineString<f32>;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened {
+        flatten_points(&self.points, coordinate_system)
+    }
+}
+
+impl Flattenable for NeoPolygon3D {
+    type Flattened = geo::Polygon<f32>;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened {
+        geo::Polygon::new(
+            flatten_points(&self.exterior, coordinate_system),
+            self.interiors
+                .iter()
+                .map(|int| flatten_points(int, coordinate_system))
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+impl Flattenable for Triangle3D {
+    type Flattened = geo::Triangle<f32>;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened {
+        let [a, b, c] = self
+            .array()
+            .map(|p| coordinate_system.project_point(p).to_geo());
+        geo::Triangle::new(a, b, c)
+    }
+}
+
+fn flatten_points(points: &[Vec3], coordinate_system: &CoordinateSystem) -> geo::LineString<f32> {
+    points
+        .iter()
+        .map(|p| coordinate_system.project_point(*p))
+        .collect::<Vec<_>>()
+        .to_geo()
+}
+
+#[cfg(test)]
+mod flatten_tests {
+    use geo::Area;
+    use glam::Vec3;
+    use neo_coordinate_system::CoordinateSystem;
+
+    use crate::lifting::traits::{Flattenable, Liftable};
+    use crate::polygon3d::def::NeoPolygon3D;
+    use crate::surface::def::SURFACE_EPS;
+
+    fn tilted_coordinate_system() -> CoordinateSystem {
+        CoordinateSystem::from_origin_and_normal(
+            Vec3::new(-4.0, 2.0, 7.5),
+            Vec3::new(1.0, 2.0, 3.0),
+        )
+    }
+
+    #[test]
+    fn point_roundtrip_works() {
+        let c = tilted_coordinate_system();
+        let point = geo::Point::new(3.5_f32, -1.25);
+        let roundtrip = point.lift(&c).flatten(&c);
+        assert!((roundtrip.x() - point.x()).abs() < SURFACE_EPS);
+        assert!((roundtrip.y() - point.y()).abs() < SURFACE_EPS);
+    }
+
+    #[test]
+    fn point_outside_is_projected() {
+        let c = tilted_coordinate_system();
+        let point = geo::Point::new(3.5_f32, -1.25);
+        let lifted = point.lift(&c) + c.plane.normal * 4.0;
+        let flattened = lifted.flatten(&c);
+        assert!((flattened.x() - point.x()).abs() < SURFACE_EPS);
+        assert!((flattened.y() - point.y()).abs() < SURFACE_EPS);
+    }
+
+    #[test]
+    fn polygon_roundtrip_keeps_area() {
+        let c = tilted_coordinate_system();
+        let ext = geo::Rect::new((0.0_f32, 0.0), (4.0, 4.0)).to_polygon();
+        let int = geo::Rect::new((1.0_f32, 1.0), (2.0, 2.0)).to_polygon();
+        let poly = geo::Polygon::new(ext.exterior().clone(), vec![int.exterior().clone()]);
+
+        let roundtrip = poly.lift(&c).flatten(&c);
+        assert!((roundtrip.unsigned_area() - poly.unsigned_area()).abs() < 0.001);
+        assert_eq!(roundtrip.interiors().len(), 1);
+    }
+
+    #[test]
+    fn polygon3d_flatten_works() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::Z, Vec3::Z);
+        let poly3d = NeoPolygon3D::from_outline_and_normal(
+            vec![Vec3::Z, Vec3::X + Vec3::Z, Vec3::ONE, Vec3::Y + Vec3::Z],
+            Vec3::Z,
+        );
+        let flattened = poly3d.flatten(&c);
+        assert!((flattened.unsigned_area() - 1.0).abs() < SURFACE_EPS);
+    }
+}
@@ -0,0 +1,157 @@
+use glam::Vec3;
+use neo_coordinate_system::CoordinateSystem;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::lifting::traits::Liftable;
+use crate::polygon3d::def::NeoPolygon3D;
+use crate::polyline3d::def::Polyline3D;
+use crate::triangle3d::def::Triangle3D;
+
+impl Liftable for geo::Coord<f32> {
+    type Lifted = Vec3;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        coordinate_system.inject_point(self.to_glam())
+    }
+}
+
+impl Liftable for geo::Point<f32> {
+    type Lifted = Vec3;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        self.0.lift(coordinate_system)
+    }
+}
+
+impl Liftable for geo::Line<f32> {
+    type Lifted = LineSegment3D;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        LineSegment3D::new(
+            self.start.lift(coordinate_system),
+            self.end.lift(coordinate_system),
+        )
+    }
+}
+
+impl Liftable for geo::LineString<f32> {
+    type Lifted = Polyline3D;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        Polyline3D::new(lift_coords(self, coordinate_system))
+    }
+}
+
+impl Liftable for geo::Polygon<f32> {
+    type Lifted = NeoPolygon3D;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        NeoPolygon3D {
+            normal: coordinate_system.plane.normal,
+            exterior: lift_coords(self.exterior(), coordinate_system),
+            interiors: self
+                .interiors()
+                .iter()
+                .map(|int| lift_coords(int, coordinate_system))
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+impl Liftable for geo::MultiPolygon<f32> {
+    type Lifted = Vec<NeoPolygon3D>;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        self.iter()
+            .map(|poly| poly.lift(coordinate_system))
+            .collect::<Vec<_>>()
+    }
+}
+
+impl Liftable for geo::Triangle<f32> {
+    type Lifted = Triangle3D;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted {
+        Triangle3D::from(self.to_array().map(|c| c.lift(coordinate_system)))
+    }
+}
+
+fn lift_coords(ls: &geo::LineString<f32>, coordinate_system: &CoordinateSystem) -> Vec<Vec3> {
+    ls.coords()
+        .map(|c| c.lift(coordinate_system))
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod lift_tests {
+    use glam::Vec3;
+    use neo_coordinate_system::CoordinateSystem;
+
+    use crate::lifting::traits::Liftable;
+    use crate::surface::def::SURFACE_EPS;
+
+    fn wall_coordinate_system() -> CoordinateSystem {
+        CoordinateSystem::from_origin_and_axis(Vec3::new(10.0, 5.0, 0.0), Vec3::Y, Vec3::Z)
+    }
+
+    #[test]
+    fn point_lift_works() {
+        let c = wall_coordinate_system();
+        let lifted = geo::Point::new(2.0_f32, 1.0).lift(&c);
+        assert!(lifted.abs_diff_eq(Vec3::new(10.0, 7.0, 1.0), SURFACE_EPS));
+    }
+
+    #[test]
+    fn line_lift_works() {
+        let c = wall_coordinate_system();
+        let lifted = geo::Line::new((0.0_f32, 0.0), (0.0, 3.0)).lift(&c);
+        assert!(lifted.src.abs_diff_eq(c.origin, SURFACE_EPS));
+        assert!(lifted
+            .dst
+            .abs_diff_eq(c.origin + Vec3::Z * 3.0, SURFACE_EPS));
+    }
+
+    #[test]
+    fn linestring_lift_keeps_all_points() {
+        let c = wall_coordinate_system();
+        let ls = geo::LineString::from(vec![(0.0_f32, 0.0), (1.0, 0.0), (1.0, 1.0)]);
+        let lifted = ls.lift(&c);
+        assert_eq!(lifted.points.len(), 3);
+        assert!((lifted.length() - 2.0).abs() < SURFACE_EPS);
+    }
+
+    #[test]
+    fn polygon_lift_lies_in_coordinate_system() {
+        let c = wall_coordinate_system();
+        let poly = geo::Rect::new((0.0_f32, 0.0), (4.0, 2.5)).to_polygon();
+        let lifted = poly.lift(&c);
+        assert_eq!(lifted.normal, c.plane.normal);
+        assert_eq!(lifted.exterior.len(), poly.exterior().0.len());
+        assert!(lifted
+            .iter_all_points()
+            .all(|&p| c.is_point_in_coordinate_system(p)));
+    }
+
+    #[test]
+    fn triangle_lift_keeps_orientation() {
+        let c = wall_coordinate_system();
+        let tri = geo::Triangle::new(
+            geo::Coord { x: 0.0_f32, y: 0.0 },
+            geo::Coord { x: 1.0, y: 0.0 },
+            geo::Coord { x: 0.0, y: 1.0 },
+        );
+        let lifted = tri.lift(&c);
+        assert!(lifted.normal().abs_diff_eq(c.plane.normal, SURFACE_EPS));
+    }
+
+    #[test]
+    fn multi_polygon_lift_works() {
+        let c = wall_coordinate_system();
+        let mp = geo::MultiPolygon::new(vec![
+            geo::Rect::new((0.0_f32, 0.0), (1.0, 1.0)).to_polygon(),
+            geo::Rect::new((2.0_f32, 0.0), (3.0, 1.0)).to_polygon(),
+        ]);
+        assert_eq!(mp.lift(&c).len(), 2);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod flatten;
+pub mod lift;
+pub mod traits;
@@ -0,0 +1,18 @@
+use neo_coordinate_system::CoordinateSystem;
+
+/// Maps 2D geometry into the 3D space spanned by a coordinate system. The 2D origin is placed
+/// onto the origin of the coordinate system
+pub trait Liftable {
+    type Lifted;
+
+    fn lift(&self, coordinate_system: &CoordinateSystem) -> Self::Lifted;
+}
+
+/// Maps 3D geometry into the local 2D space of a coordinate system. This is the inverse of
+/// [`Liftable`] for geometry located in the coordinate system. Everything else gets projected onto
+/// the coordinate system along its normal first
+pub trait Flattenable {
+    type Flattened;
+
+    fn flatten(&self, coordinate_system: &CoordinateSystem) -> Self::Flattened;
+}
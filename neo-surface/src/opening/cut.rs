@@ -0,0 +1,423 @@
+use geo::orient::Direction;
+use geo::{Area, BoundingRect, ConvexHull, EuclideanDistance, Orient};
+use glam::Vec3;
+use neo_error::NeoGeometryError;
+use neo_geo_boolops::NeoGeoBoolops;
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_primitive::d3::obb::OBB3D;
+
+use crate::opening::def::{NeoOpening, NeoWallCut};
+use crate::polygon3d::def::NeoPolygon3D;
+use crate::surface::def::{NeoSurface, SURFACE_EPS};
+
+impl NeoSurface {
+    /// cuts the opening out of this wall. The body of the wall is `thickness` thick and lies
+    /// behind the surface, against its facing direction.
+    ///
+    /// The footprint of the opening in the plane of the wall becomes a hole or, if it reaches over
+    /// the border of the wall, a notch in the exterior. Openings which miss the wall leave it
+    /// untouched. Extrusions only cut where the swept profile actually reaches the plane of the
+    /// wall
+    pub fn cut_opening(
+        &self,
+        opening: &NeoOpening,
+        thickness: f32,
+    ) -> Result<NeoWallCut, NeoGeometryError> {
+        if !thickness.is_finite() {
+            return Err(NeoGeometryError::NonFinite);
+        }
+        if thickness <= 0.0 {
+            return Err(NeoGeometryError::Degenerate(
+                "walls need a positive thickness",
+            ));
+        }
+        let Some(footprint) = self
+            .opening_footprint(opening)?
+            .filter(|footprint| footprint.unsigned_area() > SURFACE_EPS)
+        else {
+            return Ok(NeoWallCut {
+                walls: vec![self.clone()],
+                reveals: vec![],
+            });
+        };
+
+        let wall = geo::MultiPolygon::new(vec![self.shape.clone()]);
+        let walls = wall
+            .neo_difference(&footprint)
+            .ok_or(NeoGeometryError::NumericalInconsistency(
+                "difference of wall and opening failed",
+            ))?
+            .into_iter()
+            .map(|shape| NeoSurface {
+                shape,
+                ..self.clone()
+            })
+            .collect();
+        // the part of the opening which actually runs through the wall
+        let lined =
+            wall.neo_intersection(&footprint)
+                .ok_or(NeoGeometryError::NumericalInconsistency(
+                    "intersection of opening and wall failed",
+                ))?;
+        let reveals = lined
+            .into_iter()
+            .flat_map(|shape| self.reveals_of(&shape.orient(Direction::Default), thickness))
+            .collect();
+        Ok(NeoWallCut { walls, reveals })
+    }
+
+    /// the footprint of the opening in the 2D frame of the wall, if the opening crosses the plane
+    /// of the wall
+    fn opening_footprint(
+        &self,
+        opening: &NeoOpening,
+    ) -> Result<Option<geo::MultiPolygon<f32>>, NeoGeometryError> {
+        match opening {
+            NeoOpening::Extrusion { profile, direction } => {
+                self.extrusion_footprint(profile, *direction)
+            }
+            _ => Ok(opening
+                .as_oriented_box()
+                .and_then(|obb| self.box_footprint(&obb))
+                .map(|footprint| geo::MultiPolygon::new(vec![footprint]))),
+        }
+    }
+
+    /// section of the box with the plane of the wall. It's the convex hull of the corners in the
+    /// plane and the points where the edges of the box cross the plane
+    fn box_footprint(&self, obb: &OBB3D) -> Option<geo::Polygon<f32>> {
+        let corners = obb.corners();
+        let distances = corners.map(|corner| self.signed_distance_to(corner));
+        let on_plane = (0..8)
+            .filter(|&i| distances[i].abs() < SURFACE_EPS)
+            .map(|i| corners[i]);
+        let crossing = (0..8)
+            .flat_map(|i| (0..3).map(move |bit| (i, i | (1 << bit))))
+            .filter(|&(i, j)| i != j)
+            .filter(|&(i, j)| {
+                distances[i].abs() >= SURFACE_EPS
+                    && distances[j].abs() >= SURFACE_EPS
+                    && distances[i].signum() != distances[j].signum()
+            })
+            .map(|(i, j)| {
+                corners[i].lerp(corners[j], distances[i] / (distances[i] - distances[j]))
+            });
+        let section = on_plane
+            .chain(crossing)
+            .map(|p| geo::Point::from(self.project_point_xy(p)))
+            .collect::<geo::MultiPoint<f32>>();
+        (section.0.len() >= 3).then(|| section.convex_hull())
+    }
+
+    /// the profile moved along the extrusion direction until it hits the plane of the wall. Only
+    /// the part of it which the sweep actually reaches is kept
+    fn extrusion_footprint(
+        &self,
+        profile: &NeoSurface,
+        direction: Vec3,
+    ) -> Result<Option<geo::MultiPolygon<f32>>, NeoGeometryError> {
+        let profile_3d = profile.as_polygon_3d();
+        if !direction.is_finite() || !profile_3d.iter_all_points().all(|p| p.is_finite()) {
+            return Err(NeoGeometryError::NonFinite);
+        }
+        let approach = self.facing_direction().dot(direction);
+        let sweep = profile.facing_direction().dot(direction);
+        // extrusions parallel to the wall never cross it, extrusions parallel to the profile have
+        // no volume
+        if approach.abs() < SURFACE_EPS || sweep.abs() < SURFACE_EPS {
+            return Ok(None);
+        }
+
+        let hit = |p: &Vec3| {
+            self.project_point_xy(*p - direction * (self.signed_distance_to(*p) / approach))
+        };
+        let NeoPolygon3D {
+            exterior,
+            interiors,
+            ..
+        } = profile_3d;
+        let swept = geo::Polygon::new(
+            exterior.iter().map(hit).collect(),
+            interiors
+                .iter()
+                .map(|ring| ring.iter().map(hit).collect())
+                .collect(),
+        );
+        let Some(bounds) = swept.bounding_rect() else {
+            return Ok(None);
+        };
+
+        // how far the profile has to be moved along the direction to reach a point of the wall.
+        // It's affine in the 2D frame of the wall and the sweep only covers the range `0..=1`
+        let center = bounds.center().to_glam();
+        let sweep_parameter = |h: Vec3| {
+            profile
+                .facing_direction()
+                .dot(h - profile.coordinate_system.origin)
+                / sweep
+        };
+        let t_center = sweep_parameter(self.injection_function()(center));
+        let gradient = self.project_vector_xy(profile.facing_direction()) / sweep;
+        let reach = (bounds.max() - bounds.min()).to_glam().length() + 1.0;
+        let speed = gradient.length();
+        if speed * reach < SURFACE_EPS {
+            return Ok((0.0..=1.0).contains(&t_center).then(|| swept.into()));
+        }
+
+        let along = gradient / speed;
+        let across = along.perp() * reach;
+        let low = (-t_center / speed).max(-reach);
+        let high = ((1.0 - t_center) / speed).min(reach);
+        if low >= high {
+            return Ok(None);
+        }
+        let reached = geo::Polygon::new(
+            vec![
+                center + along * low - across,
+                center + along * high - across,
+                center + along * high + across,
+                center + along * low + across,
+            ]
+            .to_geo(),
+            vec![],
+        );
+        swept
+            .neo_intersection(&reached)
+            .map(Some)
+            .ok_or(NeoGeometryError::NumericalInconsistency(
+                "clipping the extruded opening failed",
+            ))
+    }
+
+    /// one reveal per edge of the lined part which isn't located on the border of the wall. The
+    /// shape needs to be oriented, so that the opening lies left of every edge
+    fn reveals_of(&self, lined: &geo::Polygon<f32>, thickness: f32) -> Vec<NeoSurface> {
+        let inject = self.injection_function();
+        let depth = -self.facing_direction() * thickness;
+        std::iter::once(lined.exterior())
+            .chain(lined.interiors())
+            .flat_map(|ring| ring.lines())
+            .filter(|line| !self.is_on_border(line.start + line.delta() * 0.5))
+            .filter(|line| line.start.to_glam().distance(line.end.to_glam()) > SURFACE_EPS)
+            .map(|line| {
+                let normal = self
+                    .inject_vector(line.delta().to_glam().perp())
+                    .normalize();
+                let src = inject(line.start.to_glam());
+                let dst = inject(line.end.to_glam());
+                let outline = vec![src, dst, dst + depth, src + depth];
+                NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(outline, normal))
+            })
+            .collect()
+    }
+
+    fn signed_distance_to(&self, point: Vec3) -> f32 {
+        self.facing_direction()
+            .dot(point - self.coordinate_system.origin)
+    }
+
+    fn is_on_border(&self, coord: geo::Coord<f32>) -> bool {
+        let point = geo::Point::from(coord);
+        std::iter::once(self.shape.exterior())
+            .chain(self.shape.interiors())
+            .any(|ring| point.euclidean_distance(ring) < SURFACE_EPS)
+    }
+}
+
+#[cfg(test)]
+mod wall_openings {
+    use glam::{Quat, Vec3};
+    use neo_aabb::d3::def::AABB3D;
+    use neo_error::NeoGeometryError;
+    use neo_primitive::d3::obb::OBB3D;
+
+    use crate::opening::def::{NeoOpening, NeoWallCut};
+    use crate::polygon3d::def::NeoPolygon3D;
+    use crate::surface::def::NeoSurface;
+
+    const EPS: f32 = 0.001;
+    const THICKNESS: f32 = 0.2;
+
+    /// wall on `y = 0` which is 4 wide and 3 high. It faces `-Y`, so its body extends to `+Y`
+    fn wall() -> NeoSurface {
+        NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(
+            vec![
+                Vec3::ZERO,
+                Vec3::X * 4.0,
+                Vec3::new(4.0, 0.0, 3.0),
+                Vec3::Z * 3.0,
+            ],
+            Vec3::NEG_Y,
+        ))
+    }
+
+    fn cut(opening: impl Into<NeoOpening>) -> NeoWallCut {
+        wall()
+            .cut_opening(&opening.into(), THICKNESS)
+            .expect("valid opening")
+    }
+
+    fn total_area(surfaces: &[NeoSurface]) -> f32 {
+        surfaces.iter().map(NeoSurface::area).sum()
+    }
+
+    /// every reveal lies in the wall body and faces the given point inside of the opening
+    fn assert_reveals_line_opening(reveals: &[NeoSurface], inside: Vec3) {
+        for reveal in reveals {
+            let polygon = reveal.as_polygon_3d();
+            for p in polygon.iter_all_points() {
+                assert!((-EPS..=THICKNESS + EPS).contains(&p.y), "{p:?}");
+            }
+            let towards_inside = inside - polygon.exterior[0];
+            assert!(
+                reveal.facing_direction().dot(towards_inside) > 0.0,
+                "{:?}",
+                reveal.facing_direction()
+            );
+        }
+    }
+
+    #[test]
+    fn window_becomes_a_hole() {
+        let window = AABB3D::new(Vec3::new(1.0, -1.0, 1.0), Vec3::new(2.0, 1.0, 2.0));
+        let NeoWallCut { walls, reveals } = cut(window);
+        assert_eq!(walls.len(), 1);
+        assert_eq!(walls[0].shape.interiors().len(), 1);
+        assert!((total_area(&walls) - 11.0).abs() < EPS);
+
+        assert_eq!(reveals.len(), 4);
+        assert!((total_area(&reveals) - 4.0 * THICKNESS).abs() < EPS);
+        assert_reveals_line_opening(&reveals, window.center());
+    }
+
+    #[test]
+    fn door_notches_the_exterior() {
+        let door = AABB3D::new(Vec3::new(1.0, -1.0, -1.0), Vec3::new(2.0, 1.0, 2.0));
+        let NeoWallCut { walls, reveals } = cut(door);
+        assert_eq!(walls.len(), 1);
+        assert!(walls[0].shape.interiors().is_empty());
+        assert!((total_area(&walls) - 10.0).abs() < EPS);
+
+        // there is no reveal at the threshold
+        assert_eq!(reveals.len(), 3);
+        assert!((total_area(&reveals) - 5.0 * THICKNESS).abs() < EPS);
+        assert_reveals_line_opening(&reveals, Vec3::new(1.5, 0.0, 1.0));
+    }
+
+    #[test]
+    fn full_height_opening_splits_the_wall() {
+        let gap = AABB3D::new(Vec3::new(1.5, -1.0, -1.0), Vec3::new(2.5, 1.0, 4.0));
+        let NeoWallCut { walls, reveals } = cut(gap);
+        assert_eq!(walls.len(), 2);
+        assert!((total_area(&walls) - 9.0).abs() < EPS);
+        assert_eq!(reveals.len(), 2);
+        assert_reveals_line_opening(&reveals, Vec3::new(2.0, 0.0, 1.5));
+    }
+
+    #[test]
+    fn slanted_box_cuts_a_stretched_hole() {
+        let obb = OBB3D::new(
+            Vec3::new(2.0, 0.0, 1.5),
+            Vec3::new(0.5, 2.0, 0.5),
+            Quat::from_rotation_z(std::f32::consts::FRAC_PI_4),
+        );
+        let NeoWallCut { walls, reveals } = cut(obb);
+        assert_eq!(walls.len(), 1);
+        assert_eq!(walls[0].shape.interiors().len(), 1);
+        assert!((total_area(&walls) - (12.0 - std::f32::consts::SQRT_2)).abs() < EPS);
+        assert_eq!(reveals.len(), 4);
+        assert_reveals_line_opening(&reveals, obb.center);
+    }
+
+    #[test]
+    fn extruded_profile_cuts_its_shape() {
+        let profile = NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(
+            vec![
+                Vec3::new(1.0, -1.0, 1.0),
+                Vec3::new(3.0, -1.0, 1.0),
+                Vec3::new(2.0, -1.0, 2.0),
+            ],
+            Vec3::Y,
+        ));
+        let opening = NeoOpening::Extrusion {
+            profile: profile.clone(),
+            direction: Vec3::Y * 2.0,
+        };
+        let NeoWallCut { walls, reveals } =
+            wall().cut_opening(&opening, THICKNESS).expect("passes");
+        assert!((total_area(&walls) - 11.0).abs() < EPS);
+        assert_eq!(reveals.len(), 3);
+        assert_reveals_line_opening(&reveals, Vec3::new(2.0, 0.0, 1.5));
+
+        // extrusions which miss the wall leave it untouched, like boxes do
+        for direction in [Vec3::Y * 0.5, -Vec3::Y, Vec3::X] {
+            let missing = NeoOpening::Extrusion {
+                profile: profile.clone(),
+                direction,
+            };
+            let NeoWallCut { walls, reveals } =
+                wall().cut_opening(&missing, THICKNESS).expect("misses");
+            assert_eq!(walls.len(), 1);
+            assert!((total_area(&walls) - 12.0).abs() < EPS);
+            assert!(reveals.is_empty());
+        }
+
+        let non_finite = NeoOpening::Extrusion {
+            profile,
+            direction: Vec3::new(0.0, f32::NAN, 0.0),
+        };
+        assert_eq!(
+            wall().cut_opening(&non_finite, THICKNESS),
+            Err(NeoGeometryError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn extrusion_only_cuts_where_it_reaches_the_wall() {
+        // tilted square whose lower half stays in front of the wall when it's moved by one unit
+        let profile = NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(
+            vec![
+                Vec3::new(1.0, -1.5, 1.0),
+                Vec3::new(2.0, -1.5, 1.0),
+                Vec3::new(2.0, -0.5, 2.0),
+                Vec3::new(1.0, -0.5, 2.0),
+            ],
+            Vec3::new(0.0, 1.0, -1.0).normalize(),
+        ));
+        let opening = NeoOpening::Extrusion {
+            profile,
+            direction: Vec3::Y,
+        };
+        let NeoWallCut { walls, reveals } =
+            wall().cut_opening(&opening, THICKNESS).expect("reaches");
+        assert!((total_area(&walls) - 11.5).abs() < EPS);
+        assert_eq!(reveals.len(), 4);
+        assert_reveals_line_opening(&reveals, Vec3::new(1.5, 0.0, 1.75));
+    }
+
+    #[test]
+    fn missing_openings_leave_the_wall_untouched() {
+        let beside = AABB3D::new(Vec3::new(10.0, -1.0, 1.0), Vec3::new(11.0, 1.0, 2.0));
+        let in_front = AABB3D::new(Vec3::new(1.0, -2.0, 1.0), Vec3::new(2.0, -1.0, 2.0));
+        for opening in [beside, in_front] {
+            let NeoWallCut { walls, reveals } = cut(opening);
+            assert_eq!(walls.len(), 1);
+            assert!((total_area(&walls) - 12.0).abs() < EPS);
+            assert!(reveals.is_empty());
+        }
+    }
+
+    #[test]
+    fn invalid_thickness_fails() {
+        let window = NeoOpening::from(AABB3D::new(Vec3::ZERO, Vec3::ONE));
+        assert_eq!(
+            wall().cut_opening(&window, f32::NAN),
+            Err(NeoGeometryError::NonFinite)
+        );
+        assert!(matches!(
+            wall().cut_opening(&window, 0.0),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+    }
+}
@@ -0,0 +1,53 @@
+use glam::{Quat, Vec3};
+use neo_aabb::d3::def::AABB3D;
+use neo_primitive::d3::obb::OBB3D;
+
+use crate::surface::def::NeoSurface;
+
+/// volume which is cut out of a wall, e.g. for doors and windows
+#[derive(Debug, Clone, PartialEq)]
+pub enum NeoOpening {
+    Box(AABB3D),
+    OrientedBox(OBB3D),
+    /// the volume swept by `profile` when it's moved along `direction`
+    Extrusion {
+        profile: NeoSurface,
+        direction: Vec3,
+    },
+}
+
+impl From<AABB3D> for NeoOpening {
+    fn from(aabb: AABB3D) -> Self {
+        Self::Box(aabb)
+    }
+}
+
+impl From<OBB3D> for NeoOpening {
+    fn from(obb: OBB3D) -> Self {
+        Self::OrientedBox(obb)
+    }
+}
+
+impl NeoOpening {
+    /// the box of box shaped openings
+    pub fn as_oriented_box(&self) -> Option<OBB3D> {
+        match self {
+            NeoOpening::Box(aabb) => Some(OBB3D::new(
+                aabb.center(),
+                aabb.half_extends(),
+                Quat::IDENTITY,
+            )),
+            NeoOpening::OrientedBox(obb) => Some(*obb),
+            NeoOpening::Extrusion { .. } => None,
+        }
+    }
+}
+
+/// result of cutting an opening into a wall
+#[derive(Debug, Clone, PartialEq)]
+pub struct NeoWallCut {
+    /// what's left of the wall. Openings which span the whole wall split it into several parts
+    pub walls: Vec<NeoSurface>,
+    /// surfaces lining the opening through the thickness of the wall. They face into the opening
+    pub reveals: Vec<NeoSurface>,
+}
@@ -1,3 +1,4 @@
 pub mod constructor;
 pub mod def;
+pub mod operation;
 pub mod properties;
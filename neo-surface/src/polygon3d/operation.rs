@@ -0,0 +1,87 @@
+use glam::{Affine3A, Quat, Vec3};
+use neo_plane::{is_mirroring, transform_around, transform_normal};
+
+use crate::polygon3d::def::NeoPolygon3D;
+
+impl NeoPolygon3D {
+    /// applies the transform to all points. Mirroring transforms reverse the rings, so that they
+    /// keep their orientation relative to the normal
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        let transform_ring = |ring: &Vec<Vec3>| {
+            let ring = ring.iter().map(|p| transform.transform_point3(*p));
+            if is_mirroring(transform) {
+                ring.rev().collect::<Vec<_>>()
+            } else {
+                ring.collect::<Vec<_>>()
+            }
+        };
+        Self {
+            normal: transform_normal(transform, self.normal),
+            exterior: transform_ring(&self.exterior),
+            interiors: self.interiors.iter().map(transform_ring).collect(),
+        }
+    }
+
+    pub fn rotate_around(&self, pivot: Vec3, quat: Quat) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_quat(quat)))
+    }
+
+    /// scales along the world axes, keeping `pivot` in place. Negative factors mirror the polygon
+    pub fn scale_around(&self, pivot: Vec3, factor: Vec3) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_scale(factor)))
+    }
+}
+
+#[cfg(test)]
+mod polygon3d_operations {
+    use glam::{Affine3A, Quat, Vec3};
+
+    use crate::polygon3d::def::NeoPolygon3D;
+
+    const EPS: f32 = 0.000_1;
+
+    /// unit square on the floor, wound counter clockwise around `+Z`
+    fn square() -> NeoPolygon3D {
+        NeoPolygon3D::from_outline_and_normal(
+            vec![Vec3::ZERO, Vec3::X, Vec3::X + Vec3::Y, Vec3::Y],
+            Vec3::Z,
+        )
+    }
+
+    /// normal derived from the winding of the first three points
+    fn winding_normal(polygon: &NeoPolygon3D) -> Vec3 {
+        let [a, b, c] = [0, 1, 2].map(|i| polygon.exterior[i]);
+        (b - a).cross(c - a).normalize()
+    }
+
+    #[test]
+    fn rotate_around_works() {
+        let rotated = square().rotate_around(Vec3::X, Quat::from_rotation_y(90.0_f32.to_radians()));
+        assert!(rotated.normal.abs_diff_eq(Vec3::X, EPS));
+        assert!(rotated.exterior[0].abs_diff_eq(Vec3::X + Vec3::Z, EPS));
+        assert!(winding_normal(&rotated).abs_diff_eq(rotated.normal, EPS));
+    }
+
+    #[test]
+    fn non_uniform_scale_keeps_normal_orthogonal() {
+        let tilted = square().rotate_around(Vec3::ZERO, Quat::from_rotation_x(0.7));
+        let scaled = tilted.scale_around(Vec3::ONE, Vec3::new(1.0, 3.0, 0.5));
+        for line in scaled.iter_exterior_lines() {
+            assert!(line.direction().dot(scaled.normal).abs() < EPS);
+        }
+        assert!((scaled.normal.length() - 1.0).abs() < EPS);
+        assert!(winding_normal(&scaled).abs_diff_eq(scaled.normal, EPS));
+    }
+
+    #[test]
+    fn mirroring_fixes_winding() {
+        let mirrored = square().transform(&Affine3A::from_scale(Vec3::new(-1.0, 1.0, 1.0)));
+        // the normal of a floor stays upright, the winding is reversed
+        assert!(mirrored.normal.abs_diff_eq(Vec3::Z, EPS));
+        assert!(winding_normal(&mirrored).abs_diff_eq(mirrored.normal, EPS));
+
+        let flipped = square().scale_around(Vec3::ZERO, Vec3::new(1.0, 1.0, -1.0));
+        assert!(flipped.normal.abs_diff_eq(-Vec3::Z, EPS));
+        assert!(winding_normal(&flipped).abs_diff_eq(flipped.normal, EPS));
+    }
+}
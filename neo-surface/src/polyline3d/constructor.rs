@@ -0,0 +1,22 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::polyline3d::def::Polyline3D;
+
+impl Polyline3D {
+    pub fn new(points: Vec<Vec3>) -> Self {
+        Self { points }
+    }
+}
+
+impl From<Vec<Vec3>> for Polyline3D {
+    fn from(points: Vec<Vec3>) -> Self {
+        Self::new(points)
+    }
+}
+
+impl From<LineSegment3D> for Polyline3D {
+    fn from(value: LineSegment3D) -> Self {
+        Self::new(value.array().to_vec())
+    }
+}
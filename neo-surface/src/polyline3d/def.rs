@@ -0,0 +1,6 @@
+use glam::Vec3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polyline3D {
+    pub points: Vec<Vec3>,
+}
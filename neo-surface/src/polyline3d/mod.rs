@@ -0,0 +1,3 @@
+pub mod constructor;
+pub mod def;
+pub mod properties;
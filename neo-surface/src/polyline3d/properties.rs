@@ -0,0 +1,37 @@
+use glam::Vec3;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::polyline3d::def::Polyline3D;
+
+impl Polyline3D {
+    pub fn iter_points(&self) -> impl Iterator<Item = &Vec3> {
+        self.points.iter()
+    }
+
+    pub fn iter_lines(&self) -> impl Iterator<Item = LineSegment3D> + '_ {
+        self.points
+            .windows(2)
+            .map(|w| LineSegment3D::new(w[0], w[1]))
+    }
+
+    pub fn length(&self) -> f32 {
+        self.iter_lines().map(|l| l.length()).sum()
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.points.len() > 1 && self.points.first() == self.points.last()
+    }
+}
+
+#[test]
+fn length_works() {
+    let polyline = Polyline3D::new(vec![Vec3::ZERO, Vec3::X, Vec3::X + Vec3::Y * 2.0]);
+    assert_eq!(polyline.length(), 3.0);
+}
+
+#[test]
+fn lines_of_single_point_are_empty() {
+    let polyline = Polyline3D::new(vec![Vec3::ONE]);
+    assert_eq!(polyline.iter_lines().count(), 0);
+    assert_eq!(polyline.length(), 0.0);
+}
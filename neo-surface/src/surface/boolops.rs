@@ -0,0 +1,182 @@
+use geo::OpType;
+use neo_error::NeoGeometryError;
+use neo_geo_boolops::NeoGeoBoolops;
+
+use crate::surface::def::NeoSurface;
+
+impl NeoSurface {
+    /// whether both surfaces are located in the same plane. The facing direction doesn't matter
+    pub fn is_coplanar_with(&self, other: &NeoSurface) -> bool {
+        self.coordinate_system.plane == other.coordinate_system.plane
+            && self
+                .coordinate_system
+                .is_point_in_coordinate_system(other.coordinate_system.origin)
+    }
+
+    /// merges two coplanar surfaces. The resulting surfaces share the coordinate system of `self`
+    pub fn union(&self, other: &NeoSurface) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        self.coplanar_boolop(other, OpType::Union)
+    }
+
+    /// removes the part of `self` which is covered by the coplanar `other`. The resulting
+    /// surfaces share the coordinate system of `self`
+    pub fn difference(&self, other: &NeoSurface) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        self.coplanar_boolop(other, OpType::Difference)
+    }
+
+    /// the parts which are covered by exactly one of the two coplanar surfaces. The resulting
+    /// surfaces share the coordinate system of `self`
+    pub fn symmetric_difference(
+        &self,
+        other: &NeoSurface,
+    ) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        self.coplanar_boolop(other, OpType::Xor)
+    }
+
+    fn coplanar_boolop(
+        &self,
+        other: &NeoSurface,
+        op: OpType,
+    ) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        if !self.is_coplanar_with(other) {
+            return Err(NeoGeometryError::NotCoplanar);
+        }
+        // we are working in the 2D frame of `self`
+        let other_shape = other.express_in(self);
+        let result = self.shape.neo_boolop(&other_shape, op).ok_or(
+            NeoGeometryError::NumericalInconsistency("boolean operation of the shapes failed"),
+        )?;
+        Ok(result
+            .into_iter()
+            .map(|shape| NeoSurface {
+                shape,
+                ..self.clone()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod surface_boolops {
+    use geo::Area;
+    use glam::{Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_plane::Plane;
+
+    use crate::surface::def::NeoSurface;
+
+    const EPS: f32 = 0.000_1;
+
+    /// rectangle `[min, max]` on the floor `z = 0`, expressed in a coordinate system with the given
+    /// origin and local x axis
+    fn floor_slab(min: Vec2, max: Vec2, origin: Vec3, local_x: Vec3) -> NeoSurface {
+        let p = Plane::from_local_axis(local_x, Vec3::Z.cross(local_x));
+        let c = CoordinateSystem::from_origin_and_plane(origin, p);
+        let world = NeoSurface::new(
+            CoordinateSystem::from_origin_and_plane(
+                Vec3::ZERO,
+                Plane::from_local_axis(Vec3::X, Vec3::Y),
+            ),
+            geo::Coord::zero(),
+            geo::Rect::new(min.to_geo(), max.to_geo()).to_polygon(),
+        );
+        // the world space rectangle expressed in the 2D frame of the coordinate system
+        let surface = NeoSurface::new(c, geo::Coord::zero(), world.shape.clone());
+        NeoSurface {
+            shape: world.express_in(&surface),
+            ..surface
+        }
+    }
+
+    fn total_area(surfaces: &[NeoSurface]) -> f32 {
+        surfaces.iter().map(|s| s.shape.unsigned_area()).sum()
+    }
+
+    #[test]
+    fn union_merges_floor_slabs() {
+        let a = floor_slab(Vec2::ZERO, Vec2::new(2.0, 1.0), Vec3::ZERO, Vec3::X);
+        // the second slab lives in a rotated and shifted coordinate system
+        let b = floor_slab(
+            Vec2::new(1.0, 0.0),
+            Vec2::new(4.0, 1.0),
+            Vec3::new(10.0, -3.0, 0.0),
+            Vec3::Y,
+        );
+        let union = a.union(&b).expect("coplanar");
+        assert_eq!(union.len(), 1);
+        assert!((total_area(&union) - 4.0).abs() < EPS);
+        for p in union[0].as_polygon_3d().iter_all_points() {
+            assert!(
+                p.z.abs() < EPS && (-EPS..=4.0 + EPS).contains(&p.x),
+                "{p:?}"
+            );
+        }
+
+        // disjoint slabs stay separate
+        let far = floor_slab(
+            Vec2::new(5.0, 0.0),
+            Vec2::new(6.0, 1.0),
+            Vec3::ZERO,
+            Vec3::X,
+        );
+        assert_eq!(a.union(&far).expect("coplanar").len(), 2);
+    }
+
+    #[test]
+    fn difference_cuts_an_opening() {
+        let wall = floor_slab(Vec2::ZERO, Vec2::new(4.0, 3.0), Vec3::ZERO, Vec3::X);
+        let opening = floor_slab(
+            Vec2::ONE,
+            Vec2::new(2.0, 2.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::Y,
+        );
+        let cut = wall.difference(&opening).expect("coplanar");
+        assert_eq!(cut.len(), 1);
+        assert_eq!(cut[0].shape.interiors().len(), 1);
+        assert!((total_area(&cut) - 11.0).abs() < EPS);
+
+        // cutting with the whole wall leaves nothing
+        assert!(wall.difference(&wall).expect("coplanar").is_empty());
+    }
+
+    #[test]
+    fn symmetric_difference_keeps_uncovered_parts() {
+        let a = floor_slab(Vec2::ZERO, Vec2::new(2.0, 2.0), Vec3::ZERO, Vec3::X);
+        let b = floor_slab(Vec2::ONE, Vec2::new(3.0, 3.0), Vec3::ZERO, Vec3::X);
+        let xor = a.symmetric_difference(&b).expect("coplanar");
+        assert!((total_area(&xor) - 6.0).abs() < EPS);
+    }
+
+    #[test]
+    fn flipped_surfaces_are_coplanar() {
+        let a = floor_slab(Vec2::ZERO, Vec2::new(2.0, 2.0), Vec3::ZERO, Vec3::X);
+        let flipped = a.clone().flip();
+        assert!(a.is_coplanar_with(&flipped));
+        let union = a.union(&flipped).expect("coplanar");
+        assert!((total_area(&union) - 4.0).abs() < EPS);
+    }
+
+    #[test]
+    fn non_coplanar_surfaces_fail() {
+        let a = floor_slab(Vec2::ZERO, Vec2::new(2.0, 2.0), Vec3::ZERO, Vec3::X);
+        let lifted = a.translate_by(Vec3::Z);
+        assert_eq!(a.union(&lifted), Err(NeoGeometryError::NotCoplanar));
+
+        let wall = NeoSurface::new(
+            CoordinateSystem::from_origin_and_plane(
+                Vec3::ZERO,
+                Plane::from_local_axis(Vec3::X, Vec3::Z),
+            ),
+            geo::Coord::zero(),
+            a.shape.clone(),
+        );
+        assert_eq!(a.difference(&wall), Err(NeoGeometryError::NotCoplanar));
+        assert_eq!(
+            a.symmetric_difference(&wall),
+            Err(NeoGeometryError::NotCoplanar)
+        );
+    }
+}
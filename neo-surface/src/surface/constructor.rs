@@ -1,6 +1,7 @@
-use geo::Centroid;
+use geo::{Centroid, MapCoords};
 use glam::Vec3;
 use neo_coordinate_system::CoordinateSystem;
+use neo_error::NeoGeometryError;
 use neo_geo_glam_interop::to_geo::ConvertToGeo;
 use neo_geo_glam_interop::to_glam::ConvertToGlam;
 use neo_line_segment::d2::def::LineSegment2D;
@@ -21,19 +22,39 @@ impl NeoSurface {
         }
     }
 
+    /// # Panics
+    ///
+    /// if the shape doesn't have a center, see [`NeoSurface::try_new_origin_at_shape_center`]
     pub fn new_origin_at_shape_center(
         coordinate_system: CoordinateSystem,
         shape: geo::Polygon<f32>,
     ) -> Self {
-        let center = shape.centroid().expect("polygon has a center");
-        Self {
+        Self::try_new_origin_at_shape_center(coordinate_system, shape)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// like [`NeoSurface::new_origin_at_shape_center`], but fails for empty shapes and shapes
+    /// with non finite coordinates instead of panicking
+    pub fn try_new_origin_at_shape_center(
+        coordinate_system: CoordinateSystem,
+        shape: geo::Polygon<f32>,
+    ) -> Result<Self, NeoGeometryError> {
+        let center = shape
+            .centroid()
+            .ok_or(NeoGeometryError::Degenerate("polygon without a center"))?;
+        if !center.x().is_finite() || !center.y().is_finite() {
+            return Err(NeoGeometryError::NonFinite);
+        }
+        Ok(Self {
             coordinate_system,
             shape_origin: center.into(),
             shape,
-        }
+        })
     }
 
-    /// inverts the normal of the coordinate system
+    /// inverts the normal of the coordinate system. The shape keeps its 2D coordinates, so non
+    /// symmetric surfaces end up mirrored in 3D. Use [`NeoSurface::flip`] to turn a surface around
+    /// in place
     pub fn invert_facing_direction(self) -> Self {
         Self {
             coordinate_system: self.coordinate_system.flip(),
@@ -59,9 +80,16 @@ impl NeoSurface {
         }
     }
 
-    /// inverts the normal of the coordinate system and the winding of the shape
+    /// turns the surface around in place: the normal of the coordinate system and the winding of
+    /// the shape are inverted, while every point of the surface keeps its 3D position. The shape is
+    /// mirrored along the local y axis for this, since that axis flips together with the normal
     pub fn flip(self) -> Self {
-        self.invert_facing_direction().invert_winding()
+        let mirror = |c: geo::Coord<f32>| geo::Coord { x: c.x, y: -c.y };
+        Self {
+            coordinate_system: self.coordinate_system.flip(),
+            shape_origin: mirror(self.shape_origin),
+            shape: self.shape.map_coords(mirror),
+        }
     }
 
     pub fn empty_with_normal(normal: Vec3) -> Self {
@@ -142,7 +170,10 @@ impl NeoSurface {
 
 #[cfg(test)]
 mod constructor_tests {
-    use glam::Vec2;
+    use glam::{Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
     use neo_line_segment::d2::def::LineSegment2D;
 
     use crate::surface::def::{NeoSurface, SURFACE_EPS};
@@ -195,4 +226,53 @@ mod constructor_tests {
             assert!(original.abs_diff_eq(*reconstruced, SURFACE_EPS));
         }
     }
+
+    #[test]
+    fn flip_keeps_the_surface_in_place() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::new(1.0, -2.0, 0.5));
+        let triangle = geo::Polygon::new(
+            vec![Vec2::ZERO, Vec2::X * 4.0, Vec2::new(4.0, 3.0)].to_geo(),
+            vec![],
+        );
+        let surface = NeoSurface::new(c, geo::Coord { x: 1.0, y: 2.0 }, triangle);
+        let flipped = surface.clone().flip();
+
+        assert!(flipped
+            .facing_direction()
+            .abs_diff_eq(-surface.facing_direction(), SURFACE_EPS));
+        for (original, flipped) in surface
+            .as_polygon_3d()
+            .iter_all_points()
+            .zip(flipped.as_polygon_3d().iter_all_points())
+        {
+            assert!(original.abs_diff_eq(*flipped, 0.001));
+        }
+        // the winding around the facing direction is inverted
+        let winding = |s: &NeoSurface| {
+            let p = s.as_polygon_3d().exterior;
+            (p[1] - p[0]).cross(p[2] - p[0]).dot(s.facing_direction())
+        };
+        assert!(winding(&surface) * winding(&flipped) < 0.0);
+    }
+
+    #[test]
+    fn origin_at_shape_center_fails_for_bad_shapes() {
+        let c = CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z);
+        let empty = geo::Polygon::<f32>::new(geo::LineString::new(vec![]), vec![]);
+        assert_eq!(
+            NeoSurface::try_new_origin_at_shape_center(c, empty),
+            Err(NeoGeometryError::Degenerate("polygon without a center"))
+        );
+        let nan = geo::Polygon::<f32>::new(
+            geo::LineString::from(vec![(0.0, 0.0), (f32::NAN, 0.0), (0.0, 1.0)]),
+            vec![],
+        );
+        assert_eq!(
+            NeoSurface::try_new_origin_at_shape_center(c, nan),
+            Err(NeoGeometryError::NonFinite)
+        );
+        let square = geo::Rect::<f32>::new((0.0, 0.0), (2.0, 2.0)).to_polygon();
+        let surface = NeoSurface::try_new_origin_at_shape_center(c, square).unwrap();
+        assert_eq!(surface.shape_origin, geo::Coord { x: 1.0, y: 1.0 });
+    }
 }
@@ -0,0 +1,191 @@
+use geo::orient::Direction;
+use geo::Orient;
+use glam::Vec3;
+use neo_error::NeoGeometryError;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+
+use crate::polygon3d::def::NeoPolygon3D;
+use crate::surface::def::{NeoSurface, SURFACE_EPS};
+
+impl NeoSurface {
+    /// extrudes the surface along its facing direction, see [`NeoSurface::extrude_along`]
+    pub fn extrude(&self, depth: f32) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        self.extrude_along(self.facing_direction() * depth)
+    }
+
+    /// the faces of the closed prism which the surface sweeps when it's moved along `direction`.
+    /// The bottom comes first, followed by the top and one side per edge of the exterior and the
+    /// interiors. All faces point out of the prism
+    pub fn extrude_along(&self, direction: Vec3) -> Result<Vec<NeoSurface>, NeoGeometryError> {
+        if !direction.is_finite() {
+            return Err(NeoGeometryError::NonFinite);
+        }
+        let approach = self.facing_direction().dot(direction);
+        if approach.abs() < SURFACE_EPS {
+            return Err(NeoGeometryError::Degenerate(
+                "extrusion direction is parallel to the surface",
+            ));
+        }
+        // the base faces into the prism, so that its flipped version is the outward bottom
+        let base = if approach > 0.0 {
+            self.clone()
+        } else {
+            self.clone().flip()
+        };
+        let base = NeoSurface {
+            shape: base.shape.orient(Direction::Default),
+            ..base
+        };
+        let mut faces = vec![base.clone().flip(), base.translate_by(direction)];
+        faces.extend(base.extrusion_sides(direction));
+        Ok(faces)
+    }
+
+    /// the shape needs to be oriented, so that the inside of the surface lies left of every edge.
+    /// Edges winding counter clockwise around the facing direction then make the sides point
+    /// outwards
+    fn extrusion_sides(&self, direction: Vec3) -> Vec<NeoSurface> {
+        let inject = self.injection_function();
+        std::iter::once(self.shape.exterior())
+            .chain(self.shape.interiors())
+            .flat_map(|ring| ring.lines())
+            .map(|line| (inject(line.start.to_glam()), inject(line.end.to_glam())))
+            .filter(|(src, dst)| src.distance(*dst) > SURFACE_EPS)
+            .map(|(src, dst)| {
+                let normal = (dst - src).cross(direction).normalize();
+                let outline = vec![src, dst, dst + direction, src + direction];
+                NeoSurface::from_polygon_3d(NeoPolygon3D::from_outline_and_normal(outline, normal))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod surface_extrusion {
+    use geo::Contains;
+    use glam::{Vec2, Vec3};
+    use neo_coordinate_system::CoordinateSystem;
+    use neo_error::NeoGeometryError;
+    use neo_geo_glam_interop::to_geo::ConvertToGeo;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::surface::def::NeoSurface;
+
+    const EPS: f32 = 0.001;
+
+    fn slab_footprint() -> NeoSurface {
+        NeoSurface::new(
+            CoordinateSystem::from_origin_and_normal(Vec3::ONE, Vec3::Z),
+            geo::Coord::zero(),
+            geo::Polygon::new(
+                vec![
+                    Vec2::ZERO,
+                    Vec2::X * 4.0,
+                    Vec2::new(4.0, 3.0),
+                    Vec2::Y * 3.0,
+                ]
+                .to_geo(),
+                vec![vec![
+                    Vec2::ONE,
+                    Vec2::new(1.0, 2.0),
+                    Vec2::new(2.0, 2.0),
+                    Vec2::new(2.0, 1.0),
+                ]
+                .to_geo()],
+            ),
+        )
+    }
+
+    /// the faces are closed if the area weighted normals cancel out. The sides point outwards if
+    /// points just in front of them are outside of the footprint and points just behind them are
+    /// inside of it
+    fn assert_closed_and_outward(base: &NeoSurface, direction: Vec3, faces: &[NeoSurface]) {
+        let flux = faces
+            .iter()
+            .map(|face| face.facing_direction() * face.area())
+            .sum::<Vec3>();
+        assert!(flux.abs_diff_eq(Vec3::ZERO, EPS), "{flux:?}");
+
+        assert!(faces[0].facing_direction().dot(direction) < 0.0);
+        assert!(faces[1].facing_direction().dot(direction) > 0.0);
+        // moves a point back onto the base along the extrusion direction
+        let on_base = |p: Vec3| {
+            let t = base
+                .facing_direction()
+                .dot(p - base.coordinate_system.origin)
+                / base.facing_direction().dot(direction);
+            geo::Point::from(base.project_point_xy(p - direction * t))
+        };
+        for side in &faces[2..] {
+            let corners = side.as_polygon_3d().exterior;
+            let center = corners[..4].iter().sum::<Vec3>() / 4.0;
+            let normal = side.facing_direction();
+            assert!(!base.shape.contains(&on_base(center + normal * 0.01)));
+            assert!(base.shape.contains(&on_base(center - normal * 0.01)));
+        }
+    }
+
+    #[test]
+    fn square_extrudes_to_a_box() {
+        let square = NeoSurface::new(
+            CoordinateSystem::from_origin_and_normal(Vec3::ZERO, Vec3::Z),
+            geo::Coord::zero(),
+            geo::Rect::new(Vec2::ZERO.to_geo(), Vec2::ONE.to_geo()).to_polygon(),
+        );
+        let faces = square.extrude(2.0).expect("not parallel");
+        assert_eq!(faces.len(), 6);
+        assert!(faces[0].facing_direction().abs_diff_eq(-Vec3::Z, EPS));
+        assert!(faces[1].facing_direction().abs_diff_eq(Vec3::Z, EPS));
+        let area = faces.iter().map(NeoSurface::area).sum::<f32>();
+        assert!((area - 10.0).abs() < EPS);
+        assert_closed_and_outward(&square, Vec3::Z * 2.0, &faces);
+    }
+
+    #[test]
+    fn interiors_get_sides_too() {
+        let slab = slab_footprint();
+        let faces = slab.extrude(0.3).expect("not parallel");
+        assert_eq!(faces.len(), 2 + 4 + 4);
+        assert_closed_and_outward(&slab, slab.facing_direction() * 0.3, &faces);
+    }
+
+    #[test]
+    fn slanted_and_backwards_extrusions_point_outwards() {
+        let slab = slab_footprint();
+        for direction in [
+            Vec3::new(1.0, 0.5, 2.0),
+            -Vec3::Z * 0.3,
+            Vec3::new(-0.2, 0.0, -1.0),
+        ] {
+            let faces = slab.extrude_along(direction).expect("not parallel");
+            assert_eq!(faces.len(), 10);
+            assert_closed_and_outward(&slab, direction, &faces);
+        }
+    }
+
+    #[test]
+    fn clockwise_wall_footprint_extrudes_outwards() {
+        let wall = NeoSurface::from_line_and_heights_vertical(
+            LineSegment2D::new(Vec2::ZERO, Vec2::X * 4.0),
+            0.0,
+            3.0,
+        );
+        let faces = wall.extrude(0.25).expect("not parallel");
+        assert_eq!(faces.len(), 6);
+        assert_closed_and_outward(&wall, wall.facing_direction() * 0.25, &faces);
+    }
+
+    #[test]
+    fn parallel_extrusion_fails() {
+        let slab = slab_footprint();
+        assert!(matches!(
+            slab.extrude_along(Vec3::X),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+        assert!(matches!(
+            slab.extrude(0.0),
+            Err(NeoGeometryError::Degenerate(_))
+        ));
+        assert_eq!(slab.extrude(f32::NAN), Err(NeoGeometryError::NonFinite));
+    }
+}
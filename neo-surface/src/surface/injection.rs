@@ -12,6 +12,12 @@ impl NeoSurface {
         move |pos| rotation * pos.extend(0.0) + translation
     }
 
+    /// maps a 2D direction of the shape frame into 3D. It's the inverse of
+    /// [`NeoSurface::project_vector_xy`] for vectors located in the plane of the surface
+    pub fn inject_vector(&self, vector: Vec2) -> Vec3 {
+        self.coordinate_system.plane.injection_rotation() * vector.extend(0.0)
+    }
+
     pub fn as_polygon_3d(&self) -> NeoPolygon3D {
         let injection_func = self.injection_function();
 
@@ -40,7 +46,7 @@ impl NeoSurface {
 
 #[cfg(test)]
 mod test {
-    use glam::Vec3;
+    use glam::{Vec2, Vec3};
     use neo_coordinate_system::CoordinateSystem;
     use neo_plane::Plane;
 
@@ -92,4 +98,29 @@ mod test {
             );
         }
     }
+
+    #[test]
+    fn inject_vector_roundtrips_far_from_origin() {
+        let surface = create_standard_surface();
+        let far_away = NeoSurface {
+            coordinate_system: surface
+                .coordinate_system
+                .offset_origin_by(Vec3::new(-4000.0, 250.0, 1200.0)),
+            shape_origin: geo::Coord { x: -15.0, y: 40.0 },
+            ..surface
+        };
+        let injection_func = far_away.injection_function();
+        for v in [Vec2::X, Vec2::Y, Vec2::new(3.0, -2.0)] {
+            let injected = far_away.inject_vector(v);
+            // the vector is the difference of the injected points
+            let expected = injection_func(Vec2::ONE + v) - injection_func(Vec2::ONE);
+            assert!(
+                injected.abs_diff_eq(expected, 0.001),
+                "{injected:?} == {expected:?} ?"
+            );
+            assert!(injected.dot(far_away.coordinate_system.plane.normal).abs() < 0.000_1);
+            let back = far_away.project_vector_xy(injected);
+            assert!(back.abs_diff_eq(v, 0.000_1), "{back:?} == {v:?} ?");
+        }
+    }
 }
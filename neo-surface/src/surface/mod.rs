@@ -1,8 +1,11 @@
+pub mod boolops;
 pub mod constructor;
 pub mod def;
+pub mod extrusion;
 pub mod injection;
 pub mod operation;
 pub mod predicates;
 pub mod projection;
 pub mod properties;
 pub mod rotation;
+pub mod tessellation;
@@ -1,4 +1,7 @@
-use glam::Vec3;
+use glam::{Affine3A, Quat, Vec3};
+use neo_geo_glam_interop::to_geo::ConvertToGeo;
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_plane::{is_mirroring, transform_around};
 
 use crate::surface::def::NeoSurface;
 
@@ -10,11 +13,46 @@ impl NeoSurface {
             shape: self.shape.clone(),
         }
     }
+
+    /// applies the transform to the surface. The result lives in the transformed coordinate
+    /// system. Mirroring transforms reverse the winding of the shape, so that it keeps its
+    /// orientation relative to the facing direction
+    pub fn transform(&self, transform: &Affine3A) -> Self {
+        let coordinate_system = self.coordinate_system.transform(transform);
+        let injection_func = self.injection_function();
+        let transform_ring = |ring: &geo::LineString<f32>| {
+            let ring = ring
+                .coords()
+                .map(|c| injection_func(c.to_glam()))
+                .map(|p| coordinate_system.project_point(transform.transform_point3(p)));
+            if is_mirroring(transform) {
+                ring.rev().collect::<Vec<_>>().to_geo()
+            } else {
+                ring.collect::<Vec<_>>().to_geo()
+            }
+        };
+        let exterior = transform_ring(self.shape.exterior());
+        let interiors = self.shape.interiors().iter().map(transform_ring).collect();
+        Self {
+            coordinate_system,
+            shape_origin: geo::Coord::zero(),
+            shape: geo::Polygon::new(exterior, interiors),
+        }
+    }
+
+    pub fn rotate_around(&self, pivot: Vec3, quat: Quat) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_quat(quat)))
+    }
+
+    /// scales along the world axes, keeping `pivot` in place. Negative factors mirror the surface
+    pub fn scale_around(&self, pivot: Vec3, factor: Vec3) -> Self {
+        self.transform(&transform_around(pivot, Affine3A::from_scale(factor)))
+    }
 }
 
 #[cfg(test)]
 mod operation_tests {
-    use glam::{Vec2, Vec3};
+    use glam::{Affine3A, Quat, Vec2, Vec3};
     use neo_line_segment::d2::def::LineSegment2D;
 
     use crate::surface::def::{NeoSurface, SURFACE_EPS};
@@ -40,4 +78,72 @@ mod operation_tests {
             assert!(translated.abs_diff_eq(*normal + Vec3::ONE, SURFACE_EPS));
         }
     }
+
+    fn wall() -> NeoSurface {
+        NeoSurface::from_line_and_heights_vertical(
+            LineSegment2D::new(Vec2::ZERO, Vec2::X),
+            0.0,
+            2.0,
+        )
+    }
+
+    /// whether the exterior winds counter clockwise around the facing direction
+    fn winds_ccw_around_normal(surface: &NeoSurface) -> bool {
+        let poly = surface.as_polygon_3d();
+        let [a, b, c] = [0, 1, 2].map(|i| poly.exterior[i]);
+        (b - a).cross(c - a).dot(surface.facing_direction()) > 0.0
+    }
+
+    #[test]
+    fn transform_maps_injected_points() {
+        let surface = wall();
+        let transform = Affine3A::from_scale_rotation_translation(
+            Vec3::new(2.0, 1.0, 0.5),
+            Quat::from_rotation_z(0.4),
+            Vec3::new(10.0, -5.0, 1.0),
+        );
+        let transformed = surface.transform(&transform);
+        for (original, calculated) in surface
+            .as_polygon_3d()
+            .iter_all_points()
+            .zip(transformed.as_polygon_3d().iter_all_points())
+        {
+            let expected = transform.transform_point3(*original);
+            assert!(
+                calculated.abs_diff_eq(expected, 0.001),
+                "{calculated:?} == {expected:?} ?"
+            );
+        }
+        assert!((transformed.area() - 2.0).abs() < 0.001);
+        assert_eq!(
+            winds_ccw_around_normal(&transformed),
+            winds_ccw_around_normal(&surface)
+        );
+    }
+
+    #[test]
+    fn rotate_around_works() {
+        let surface = wall();
+        let rotated =
+            surface.rotate_around(Vec3::ZERO, Quat::from_rotation_z(90.0_f32.to_radians()));
+        let expected_normal =
+            Quat::from_rotation_z(90.0_f32.to_radians()) * surface.facing_direction();
+        assert!(rotated
+            .facing_direction()
+            .abs_diff_eq(expected_normal, SURFACE_EPS));
+        for p in rotated.as_polygon_3d().iter_all_points() {
+            assert!(p.x.abs() < SURFACE_EPS, "{p:?}");
+        }
+    }
+
+    #[test]
+    fn mirroring_fixes_winding() {
+        let surface = wall();
+        let mirrored = surface.scale_around(Vec3::ZERO, Vec3::new(-1.0, 1.0, 1.0));
+        assert_eq!(
+            winds_ccw_around_normal(&mirrored),
+            winds_ccw_around_normal(&surface)
+        );
+        assert!((mirrored.area() - 2.0).abs() < SURFACE_EPS);
+    }
 }
@@ -1,4 +1,5 @@
-use glam::Vec3;
+use geo::MapCoords;
+use glam::{Vec2, Vec3};
 use neo_geo_glam_interop::to_geo::ConvertToGeo;
 use neo_geo_glam_interop::to_glam::ConvertToGlam;
 
@@ -32,11 +33,31 @@ impl NeoSurface {
     pub fn project_point_xy(&self, point: Vec3) -> geo::Coord<f32> {
         self.rotate_point_xy(point).truncate().to_geo()
     }
+
+    /// maps a 3D direction into the 2D frame of the shape. Unlike [`NeoSurface::project_point_xy`]
+    /// this only rotates, since vectors don't depend on the position of the surface. The part of
+    /// the vector along the normal is dropped
+    pub fn project_vector_xy(&self, vector: Vec3) -> Vec2 {
+        let rotation = self.coordinate_system.plane.xy_projection_rotation();
+        (rotation * vector).truncate()
+    }
+
+    /// maps the shape of this surface into the 2D frame of `other`, so that it can be overlayed
+    /// with `other.shape` directly. The full 3D round trip accounts for different local axes,
+    /// origins and normals of the two coordinate systems.
+    ///
+    /// This is only exact for coplanar surfaces. Otherwise the shape is projected orthogonally
+    /// onto the plane of `other`.
+    pub fn express_in(&self, other: &NeoSurface) -> geo::Polygon<f32> {
+        let injection_func = self.injection_function();
+        self.shape
+            .map_coords(|c| other.project_point_xy(injection_func(c.to_glam())))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use glam::Vec3;
+    use glam::{Vec2, Vec3};
     use neo_coordinate_system::CoordinateSystem;
     use neo_plane::Plane;
 
@@ -82,4 +103,64 @@ mod test {
         let surface = create_standard_surface();
         assert_eq!(surface.matching_translation(), Vec3::ONE);
     }
+
+    #[test]
+    fn express_in_self_is_identity() {
+        let surface = create_standard_surface();
+        let expressed = surface.express_in(&surface);
+        for (a, b) in expressed
+            .exterior()
+            .coords()
+            .zip(surface.shape.exterior().coords())
+        {
+            assert!((a.x - b.x).abs() < 0.000_1 && (a.y - b.y).abs() < 0.000_1);
+        }
+    }
+
+    #[test]
+    fn express_in_roundtrips_over_3d() {
+        let surface = create_standard_surface();
+        let other = NeoSurface::from_polygon_3d(surface.as_polygon_3d());
+        let expressed = surface.express_in(&other);
+        let injection_func = other.injection_function();
+        for (c, expected) in expressed
+            .exterior()
+            .coords()
+            .zip(surface.as_polygon_3d().exterior)
+        {
+            let p = injection_func(glam::Vec2::new(c.x, c.y));
+            assert!(p.abs_diff_eq(expected, 0.000_1), "{p:?} == {expected:?} ?");
+        }
+    }
+
+    #[test]
+    fn project_vector_ignores_the_position_of_the_surface() {
+        let surface = create_standard_surface();
+        let far_away = NeoSurface {
+            coordinate_system: surface
+                .coordinate_system
+                .offset_origin_by(Vec3::new(1000.0, -2000.0, 500.0)),
+            shape_origin: geo::Coord { x: 30.0, y: -70.0 },
+            ..surface.clone()
+        };
+        let local_x = surface.coordinate_system.plane.local_x;
+        let local_y = surface.coordinate_system.plane.local_y;
+        for v in [local_x, local_y, local_x * 2.0 - local_y] {
+            let near = surface.project_vector_xy(v);
+            let far = far_away.project_vector_xy(v);
+            assert!(near.abs_diff_eq(far, 0.000_1), "{near:?} == {far:?} ?");
+
+            // the vector is the difference of the projected points
+            let p = far_away.coordinate_system.origin;
+            let [a, b] = [p, p + v].map(|p| far_away.project_point_xy(p));
+            let expected = Vec2::new(b.x - a.x, b.y - a.y);
+            assert!(
+                far.abs_diff_eq(expected, 0.001),
+                "{far:?} == {expected:?} ?"
+            );
+        }
+        // the part along the normal is dropped
+        let normal = surface.coordinate_system.plane.normal;
+        assert!(surface.project_vector_xy(normal).length() < 0.000_1);
+    }
 }
@@ -0,0 +1,63 @@
+use neo_geo_glam_interop::to_glam::ConvertToGlam;
+use neo_tessellation::simple::delaunay_tessellation;
+
+use crate::surface::def::NeoSurface;
+use crate::triangle3d::def::Triangle3D;
+
+impl NeoSurface {
+    /// splits the surface into triangles which are all facing in the same direction as the
+    /// surface
+    pub fn tessellate(&self) -> Vec<Triangle3D> {
+        let injection_func = self.injection_function();
+        let facing_direction = self.facing_direction();
+        delaunay_tessellation(self.shape.clone())
+            .into_iter()
+            .map(|tri| tri.to_array().map(|c| injection_func(c.to_glam())))
+            .map(Triangle3D::from)
+            .filter(|tri| !tri.is_degenerated())
+            .map(|tri| {
+                if tri.normal().dot(facing_direction) < 0.0 {
+                    tri.flip()
+                } else {
+                    tri
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+}
+
+#[cfg(test)]
+mod tessellation_tests {
+    use glam::Vec2;
+    use neo_line_segment::d2::def::LineSegment2D;
+
+    use crate::surface::def::{NeoSurface, SURFACE_EPS};
+
+    #[test]
+    fn tessellation_keeps_area() {
+        let line = LineSegment2D::new(Vec2::Y, Vec2::X * 3.0);
+        let surface = NeoSurface::from_line_and_heights_vertical(line, 1.0, 4.0);
+
+        let triangles = surface.tessellate();
+        let area = triangles.iter().map(|tri| tri.area()).sum::<f32>();
+
+        assert!((area - surface.area()).abs() < SURFACE_EPS);
+    }
+
+    #[test]
+    fn tessellation_faces_in_surface_direction() {
+        let line = LineSegment2D::new(Vec2::Y, Vec2::X * 3.0);
+        let surface = NeoSurface::from_line_and_heights_vertical(line, 1.0, 4.0).flip();
+
+        let triangles = surface.tessellate();
+
+        assert!(!triangles.is_empty());
+        assert!(triangles.iter().all(|tri| tri
+            .normal()
+            .abs_diff_eq(surface.facing_direction(), SURFACE_EPS)));
+        assert!(triangles.iter().all(|tri| tri
+            .array()
+            .iter()
+            .all(|p| surface.coordinate_system.is_point_in_coordinate_system(*p))));
+    }
+}
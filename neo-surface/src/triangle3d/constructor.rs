@@ -0,0 +1,26 @@
+use glam::Vec3;
+
+use crate::triangle3d::def::Triangle3D;
+
+impl Triangle3D {
+    pub const fn new(a: Vec3, b: Vec3, c: Vec3) -> Self {
+        Self { a, b, c }
+    }
+
+    /// inverts the winding and therefore the normal of the triangle
+    pub const fn flip(&self) -> Self {
+        Self::new(self.a, self.c, self.b)
+    }
+}
+
+impl From<[Vec3; 3]> for Triangle3D {
+    fn from([a, b, c]: [Vec3; 3]) -> Self {
+        Self::new(a, b, c)
+    }
+}
+
+impl From<(Vec3, Vec3, Vec3)> for Triangle3D {
+    fn from((a, b, c): (Vec3, Vec3, Vec3)) -> Self {
+        Self::new(a, b, c)
+    }
+}
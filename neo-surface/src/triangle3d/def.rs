@@ -0,0 +1,8 @@
+use glam::Vec3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Triangle3D {
+    pub a: Vec3,
+    pub b: Vec3,
+    pub c: Vec3,
+}
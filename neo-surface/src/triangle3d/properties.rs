@@ -0,0 +1,116 @@
+use glam::Vec3;
+use neo_coordinate_system::CoordinateSystem;
+use neo_line_segment::d3::def::LineSegment3D;
+
+use crate::surface::def::SURFACE_EPS;
+use crate::triangle3d::def::Triangle3D;
+
+impl Triangle3D {
+    pub fn array(&self) -> [Vec3; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    pub fn lines(&self) -> [LineSegment3D; 3] {
+        [
+            LineSegment3D::new(self.a, self.b),
+            LineSegment3D::new(self.b, self.c),
+            LineSegment3D::new(self.c, self.a),
+        ]
+    }
+
+    /// not normalized normal, its length is twice the area of the triangle
+    pub fn scaled_normal(&self) -> Vec3 {
+        (self.b - self.a).cross(self.c - self.a)
+    }
+
+    /// normal following the right hand rule for the winding `a -> b -> c`
+    pub fn normal(&self) -> Vec3 {
+        self.scaled_normal().normalize_or_zero()
+    }
+
+    pub fn area(&self) -> f32 {
+        self.scaled_normal().length() * 0.5
+    }
+
+    pub fn centroid(&self) -> Vec3 {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// whether the corners are (almost) collinear or coincide. The sine of the angle at `a` is
+    /// compared with [`SURFACE_EPS`], so the check doesn't depend on the size of the triangle
+    pub fn is_degenerated(&self) -> bool {
+        let [ab, ac] = [self.b - self.a, self.c - self.a];
+        self.scaled_normal().length_squared()
+            <= SURFACE_EPS * SURFACE_EPS * ab.length_squared() * ac.length_squared()
+    }
+
+    /// coordinate system in the plane of the triangle with its origin at the corner `a`
+    pub fn coordinate_system(&self) -> CoordinateSystem {
+        CoordinateSystem::from_origin_and_normal(self.a, self.normal())
+    }
+
+    /// point described by the barycentric weights of the corners `a`, `b` and `c`
+    pub fn inject_barycentric(&self, barycentric: Vec3) -> Vec3 {
+        self.a * barycentric.x + self.b * barycentric.y + self.c * barycentric.z
+    }
+}
+
+#[test]
+fn normal_follows_winding() {
+    let tri = Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y);
+    assert_eq!(tri.normal(), Vec3::Z);
+    assert_eq!(tri.flip().normal(), -Vec3::Z);
+}
+
+#[test]
+fn area_works() {
+    let tri = Triangle3D::new(Vec3::ZERO, Vec3::X * 2.0, Vec3::Z * 3.0);
+    assert_eq!(tri.area(), 3.0);
+}
+
+#[test]
+fn collinear_triangle_is_degenerated() {
+    let tri = Triangle3D::new(Vec3::ZERO, Vec3::ONE, Vec3::ONE * 2.0);
+    assert!(tri.is_degenerated());
+}
+
+#[test]
+fn degeneracy_doesnt_depend_on_size() {
+    let tri = Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y);
+    for scale in [0.001, 1.0, 1000.0] {
+        let scaled = Triangle3D::new(tri.a * scale, tri.b * scale, tri.c * scale);
+        assert!(!scaled.is_degenerated(), "{scale}");
+    }
+
+    let sliver = Triangle3D::new(Vec3::ZERO, Vec3::X * 1000.0, Vec3::new(500.0, 0.001, 0.0));
+    assert!(sliver.is_degenerated());
+    let point = Triangle3D::new(Vec3::ONE, Vec3::ONE, Vec3::ONE);
+    assert!(point.is_degenerated());
+    let double_corner = Triangle3D::new(Vec3::ZERO, Vec3::ZERO, Vec3::X);
+    assert!(double_corner.is_degenerated());
+}
+
+#[test]
+fn coordinate_system_contains_corners() {
+    let tri = Triangle3D::new(Vec3::X, Vec3::new(2.0, 1.0, 3.0), Vec3::new(-1.0, 4.0, 0.5));
+    let coord_sys = tri.coordinate_system();
+    for p in tri.array() {
+        assert!(coord_sys.is_point_in_coordinate_system(p));
+        assert!(
+            coord_sys
+                .inject_point(coord_sys.project_point(p))
+                .distance(p)
+                < SURFACE_EPS
+        );
+    }
+}
+
+#[test]
+fn barycentric_injection_works() {
+    let tri = Triangle3D::new(Vec3::ZERO, Vec3::X, Vec3::Y);
+    assert_eq!(tri.inject_barycentric(Vec3::X), tri.a);
+    assert_eq!(
+        tri.inject_barycentric(Vec3::new(0.5, 0.25, 0.25)),
+        Vec3::new(0.25, 0.25, 0.0)
+    );
+}